@@ -0,0 +1,165 @@
+//! Applicative functors with reversed order of effects
+//!
+//! See [`Backwards`].
+
+use super::*;
+
+use std::collections::{LinkedList, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Wrapper that reverses the order of effects of an [`Applicative`] functor
+///
+/// [`Applicative::apply`] on a `Backwards<F>` sequences the effects of the
+/// wrapped argument before the effects of the wrapped functions (whereas
+/// the applicative functors in this crate sequence the functions' effects
+/// first). This allows running a traversal based on `apply` in reverse order
+/// without rewriting it.
+///
+/// `Backwards` is [`Applicative`] for [`Option`], [`Result`], [`Vec`],
+/// [`VecDeque`], [`LinkedList`], and pinned boxed [`Future`]s. For
+/// [`Result`], the first error of the argument takes precedence over an
+/// error of the functions.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Applicative;
+/// use fmap::backwards::Backwards;
+///
+/// fn mappers() -> Vec<Box<dyn Send + FnMut(i32) -> i32>> {
+///     vec![Box::new(|x| x), Box::new(|x| x * 10)]
+/// }
+///
+/// let forwards = vec![1, 2].apply(mappers());
+/// assert_eq!(forwards, vec![1, 2, 10, 20]);
+///
+/// let backwards = Backwards(vec![1, 2]).apply(Backwards(mappers()));
+/// assert_eq!(backwards.0, vec![1, 10, 2, 20]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Backwards<F>(pub F);
+
+impl<'a, F, B> Functor<'a, B> for Backwards<F>
+where
+    F: Functor<'a, B>,
+    B: 'a,
+{
+    type Inner = F::Inner;
+    type Mapped = Backwards<F::Mapped>;
+    fn fmap<M>(self, f: M) -> Self::Mapped
+    where
+        M: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Backwards(self.0.fmap(f))
+    }
+}
+
+impl<'a, F, B> Pure<'a, B> for Backwards<F>
+where
+    F: Pure<'a, B>,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Backwards(F::pure(b))
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Backwards<Option<A>>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: Backwards<Option<BoxMapper<'a, Self, B>>>,
+    ) -> Backwards<Option<B>> {
+        Backwards(self.0.and_then(move |a| f.0.map(|mut f| f(a))))
+    }
+}
+
+impl<'a, A, B, E> Applicative<'a, B> for Backwards<Result<A, E>>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: Backwards<Result<BoxMapper<'a, Self, B>, E>>,
+    ) -> Backwards<Result<B, E>> {
+        Backwards(self.0.and_then(move |a| f.0.map(|mut f| f(a))))
+    }
+}
+
+macro_rules! collection_impl {
+    ($collection:ident, $push:ident) => {
+        impl<'a, A, B> Applicative<'a, B> for Backwards<$collection<A>>
+        where
+            A: 'a + Clone,
+            B: 'a,
+        {
+            fn apply(
+                self,
+                mut f: Backwards<$collection<BoxMapper<'a, Self, B>>>,
+            ) -> Backwards<$collection<B>> {
+                let mut collection = $collection::new();
+                for item in self.0.into_iter() {
+                    for func in f.0.iter_mut() {
+                        collection.$push(func(item.clone()));
+                    }
+                }
+                Backwards(collection)
+            }
+        }
+    };
+}
+
+collection_impl!(Vec, push);
+collection_impl!(VecDeque, push_back);
+collection_impl!(LinkedList, push_back);
+
+impl<'a, A, B> Applicative<'a, B>
+    for Backwards<Pin<Box<dyn 'a + Future<Output = A>>>>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: Backwards<
+            Pin<Box<dyn 'a + Future<Output = BoxMapper<'a, Self, B>>>>,
+        >,
+    ) -> Backwards<Pin<Box<dyn 'a + Future<Output = B>>>> {
+        Backwards(Box::pin(async move {
+            let a = self.0.await;
+            let mut mapper = f.0.await;
+            mapper(a)
+        }))
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B>
+    for Backwards<Pin<Box<dyn 'a + Future<Output = A> + Send>>>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn apply(
+        self,
+        f: Backwards<
+            Pin<
+                Box<
+                    dyn 'a
+                        + Future<Output = BoxMapper<'a, Self, B>>
+                        + Send,
+                >,
+            >,
+        >,
+    ) -> Backwards<Pin<Box<dyn 'a + Future<Output = B> + Send>>> {
+        Backwards(Box::pin(async move {
+            let a = self.0.await;
+            let mut mapper = f.0.await;
+            mapper(a)
+        }))
+    }
+}
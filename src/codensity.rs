@@ -0,0 +1,146 @@
+//! Re-association of [`bind`] calls
+//!
+//! See [`Codensity`].
+//!
+//! [`bind`]: Monad::bind
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Shared continuation passed to a [`Codensity`] computation
+type Continuation<'a, M, A> = Arc<Mutex<dyn 'a + Send + FnMut(A) -> M>>;
+
+/// Wrapper that re-associates [`bind`] calls to the right
+///
+/// Left-nested calls of `bind` (e.g. `m.bind(f).bind(g).bind(h)`) may be
+/// expensive for some monads. The [free monad transformer], for example,
+/// wraps every layer of the functor when `bind` is called, such that each
+/// layer has to pass through all continuations that have been bound so far.
+/// This results in quadratic runtime when binding in a loop.
+///
+/// `Codensity<'a, M, A>` represents a computation in continuation-passing
+/// style, which produces a value of type `A` and eventually results in the
+/// monad `M`. Calling [`bind`] on a `Codensity` only composes continuations.
+/// The underlying monad's `bind` method is called once per [lifted] value
+/// when the computation is [lowered] again, and these calls are nested to
+/// the right.
+///
+/// [`bind`]: Monad::bind
+/// [free monad transformer]: crate::free_t::FreeT
+/// [lifted]: Codensity::lift
+/// [lowered]: Codensity::lower
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Monad;
+/// use fmap::codensity::Codensity;
+/// use fmap::free_t::FreeT;
+/// use fmap::universal::UniversalFunctor;
+///
+/// // functor asking for an input of type `i32`
+/// type Ask<A> = Box<dyn Send + FnOnce(i32) -> A>;
+/// type AskTyCon =
+///     <Ask<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+/// type OptionTyCon =
+///     <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+/// type Program<A> = FreeT<'static, AskTyCon, OptionTyCon, A>;
+///
+/// fn add_input(sum: i32) -> Program<i32> {
+///     FreeT::lift_f(Box::new(move |x| sum + x) as Ask<i32>)
+/// }
+///
+/// // `bind` on `Codensity` doesn't wrap the layers built so far
+/// let mut computation = Codensity::lift(add_input(0));
+/// for _ in 0..999 {
+///     computation = computation.bind(|sum| Codensity::lift(add_input(sum)));
+/// }
+/// let program: Program<i32> = computation.lower();
+///
+/// let result = program.run(|base| base.unwrap(), |layer: Ask<_>| layer(1));
+/// assert_eq!(result, 1000);
+/// ```
+pub struct Codensity<'a, M, A>(
+    Box<dyn 'a + FnOnce(Continuation<'a, M, A>) -> M>,
+);
+
+impl<'a, M, A> Codensity<'a, M, A>
+where
+    M: 'a,
+    A: 'a,
+{
+    /// Lift a [`Monad`] that has `A` as [inner type]
+    ///
+    /// [inner type]: Functor::Inner
+    pub fn lift<N, R>(monad: N) -> Self
+    where
+        N: 'a + Monad<'a, R, Inner = A, Mapped = M>,
+        R: 'a,
+    {
+        Codensity(Box::new(move |k| {
+            monad.bind(move |a| (k.lock().unwrap())(a))
+        }))
+    }
+
+    /// Run computation with a given final continuation
+    pub fn run<K>(self, k: K) -> M
+    where
+        K: 'a + Send + FnMut(A) -> M,
+    {
+        (self.0)(Arc::new(Mutex::new(k)))
+    }
+
+    /// Lower computation back into the underlying [`Monad`]
+    pub fn lower(self) -> M
+    where
+        M: Pure<'a, A, Inner = A, Mapped = M>,
+    {
+        self.run(M::pure)
+    }
+}
+
+impl<'a, M, A, B> Functor<'a, B> for Codensity<'a, M, A>
+where
+    M: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Codensity<'a, M, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Codensity(Box::new(move |k| {
+            self.run(move |a| (k.lock().unwrap())(f(a)))
+        }))
+    }
+}
+
+impl<'a, M, A, B> Pure<'a, B> for Codensity<'a, M, A>
+where
+    M: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Codensity(Box::new(move |k| (k.lock().unwrap())(b)))
+    }
+}
+
+impl<'a, M, A, B> Monad<'a, B> for Codensity<'a, M, A>
+where
+    M: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Codensity(Box::new(move |k| {
+            self.run(move |a| (f(a).0)(k.clone()))
+        }))
+    }
+}
@@ -0,0 +1,218 @@
+//! Cofree comonad
+//!
+//! See [`Cofree`].
+
+use super::*;
+
+use universal::{UniversalFunctor, UniversalFunctorTyCon};
+
+use std::sync::{Arc, Mutex};
+
+/// Cofree comonad over a [`Functor`]
+///
+/// The functor is given as a [type constructor] `T` (see [`Free`] for
+/// details). A `Cofree<'a, T, A>` is a value of type `A` (the head), which
+/// is annotated with a layer of the functor that contains further `Cofree`
+/// values (the tail). Depending on the functor, this describes non-empty
+/// lists ([`Option`]), rose trees ([`Vec`]), or infinite streams.
+///
+/// The tail is computed on demand (each time [`Cofree::tail`] is called),
+/// such that infinite structures can be described. Mapping and
+/// [extending] is applied lazily to the tail as well.
+///
+/// [type constructor]: UniversalFunctorTyCon
+/// [`Free`]: free::Free
+/// [extending]: Comonad::extend
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Extract};
+/// use fmap::cofree::Cofree;
+/// use fmap::universal::UniversalFunctor;
+///
+/// type OptionTyCon =
+///     <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+///
+/// // non-empty list with values 3, 2, 1
+/// let list: Cofree<'static, OptionTyCon, i32> =
+///     Cofree::unfold(3, |n| (n, if n > 1 { Some(n - 1) } else { None }));
+///
+/// fn to_vec(list: Cofree<'static, OptionTyCon, i32>) -> Vec<i32> {
+///     let mut vec = vec![*list.head()];
+///     let mut tail = list.tail();
+///     while let Some(list) = tail {
+///         vec.push(*list.head());
+///         tail = list.tail();
+///     }
+///     vec
+/// }
+///
+/// // annotate each position with the sum of the remaining elements
+/// let sums = list.extend(|list| to_vec(list).into_iter().sum());
+/// assert_eq!(sums.clone().extract(), 6);
+/// assert_eq!(to_vec(sums), vec![6, 3, 1]);
+/// ```
+pub struct Cofree<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    head: A,
+    tail: Arc<dyn 'a + Send + Sync + Fn() -> CofreeLayer<'a, T, A>>,
+}
+
+/// Layer of the functor `T` in a [`Cofree`] comonad, i.e.
+/// `T<Cofree<T, A>>`
+pub type CofreeLayer<'a, T, A> =
+    <T as UniversalFunctorTyCon<'a>>::Functor<
+        Cofree<'a, T, A>,
+        Cofree<'a, T, A>,
+    >;
+
+impl<'a, T, A> Clone for Cofree<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Clone,
+{
+    fn clone(&self) -> Self {
+        Cofree {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+
+impl<'a, T, A> Cofree<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    /// Create cofree comonad from head and a function computing the tail
+    pub fn new<F>(head: A, tail: F) -> Self
+    where
+        F: 'a + Send + Sync + Fn() -> CofreeLayer<'a, T, A>,
+    {
+        Cofree {
+            head,
+            tail: Arc::new(tail),
+        }
+    }
+
+    /// Create cofree comonad from a seed
+    ///
+    /// The function `f` returns the head and a layer of the functor with
+    /// seeds for the tail. Note that `f` is called again for a seed when the
+    /// corresponding tail is computed.
+    pub fn unfold<S, F>(seed: S, f: F) -> Self
+    where
+        S: 'a + Clone + Send + Sync,
+        F: 'a + Send + Sync + Fn(S) -> (A, T::Functor<S, S>),
+    {
+        Self::unfold_shared(seed, Arc::new(f))
+    }
+
+    fn unfold_shared<S, F>(seed: S, f: Arc<F>) -> Self
+    where
+        S: 'a + Clone + Send + Sync,
+        F: 'a + Send + Sync + Fn(S) -> (A, T::Functor<S, S>),
+    {
+        let (head, _) = f(seed.clone());
+        Cofree {
+            head,
+            tail: Arc::new(move || {
+                let (_, layer) = f(seed.clone());
+                let f = f.clone();
+                layer.change_functor_target::<Cofree<'a, T, A>>().fmap(
+                    move |seed| Self::unfold_shared(seed, f.clone()),
+                )
+            }),
+        }
+    }
+
+    /// Head
+    pub fn head(&self) -> &A {
+        &self.head
+    }
+
+    /// Compute tail
+    pub fn tail(&self) -> CofreeLayer<'a, T, A> {
+        (self.tail)()
+    }
+
+    fn fmap_shared<B, F>(self, f: Arc<Mutex<F>>) -> Cofree<'a, T, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        let head = (f.lock().unwrap())(self.head);
+        let tail = self.tail;
+        Cofree {
+            head,
+            tail: Arc::new(move || {
+                let f = f.clone();
+                tail()
+                    .change_functor_target::<Cofree<'a, T, B>>()
+                    .fmap(move |cofree| cofree.fmap_shared(f.clone()))
+            }),
+        }
+    }
+
+    fn extend_shared<B, F>(self, f: Arc<Mutex<F>>) -> Cofree<'a, T, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        let tail = self.tail.clone();
+        let head = (f.lock().unwrap())(self);
+        Cofree {
+            head,
+            tail: Arc::new(move || {
+                let f = f.clone();
+                tail()
+                    .change_functor_target::<Cofree<'a, T, B>>()
+                    .fmap(move |cofree| cofree.extend_shared(f.clone()))
+            }),
+        }
+    }
+}
+
+impl<'a, T, A, B> Functor<'a, B> for Cofree<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Cofree<'a, T, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.fmap_shared(Arc::new(Mutex::new(f)))
+    }
+}
+
+impl<'a, T, A> Extract<'a, A> for Cofree<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    fn extract(self) -> A {
+        self.head
+    }
+}
+
+impl<'a, T, A, B> Comonad<'a, B> for Cofree<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    B: 'a,
+{
+    fn extend<F>(self, f: F) -> <Self as Functor<'a, B>>::Mapped
+    where
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        self.extend_shared(Arc::new(Mutex::new(f)))
+    }
+}
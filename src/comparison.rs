@@ -0,0 +1,169 @@
+//! Comparisons as contravariant functors
+//!
+//! See [`Comparison`].
+
+use super::*;
+
+use std::cmp::Ordering;
+use std::convert::Infallible;
+
+/// Boxed closure comparing two values by reference
+///
+/// A `Comparison<'a, A>` is a [`Contravariant`] functor: a comparison of
+/// `B` can be [adapted] to a comparison of `A` with a closure converting
+/// `A` into `B`. Comparisons are also [`Divisible`], where the parts are
+/// compared lexicographically, and [`Decidable`], where values for which
+/// the closure returns [`Ok`] are ordered before values for which it
+/// returns [`Err`].
+///
+/// Since the closures passed to [`Contravariant::contramap`] and
+/// [`Divisible::divide`] take their argument by value, the compared values
+/// are cloned when using an adapted comparison. Use [`Comparison::by_key`]
+/// to avoid cloning.
+///
+/// [adapted]: Contravariant::contramap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Contravariant;
+/// use fmap::comparison::Comparison;
+///
+/// let mut words = vec!["pear", "fig", "apple", "kiwi"];
+///
+/// let mut by_len = Comparison::by_key(|s: &&str| s.len())
+///     .then(Comparison::natural())
+///     .reverse();
+/// words.sort_by(|a, b| by_len.compare(a, b));
+/// assert_eq!(words, vec!["apple", "pear", "kiwi", "fig"]);
+///
+/// let mut by_last =
+///     Comparison::natural().contramap(|s: &str| s.chars().last());
+/// words.sort_by(|a, b| by_last.compare(a, b));
+/// assert_eq!(words, vec!["apple", "fig", "kiwi", "pear"]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct Comparison<'a, A>(
+    Box<dyn 'a + Send + FnMut(&A, &A) -> Ordering>,
+);
+
+impl<'a, A> Comparison<'a, A>
+where
+    A: 'a,
+{
+    /// Create comparison from a closure
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&A, &A) -> Ordering,
+    {
+        Comparison(Box::new(f))
+    }
+
+    /// Comparison using the [`Ord`] implementation of `A`
+    pub fn natural() -> Self
+    where
+        A: Ord,
+    {
+        Comparison::new(A::cmp)
+    }
+
+    /// Comparison of keys extracted with a closure
+    pub fn by_key<K, F>(mut f: F) -> Self
+    where
+        K: Ord,
+        F: 'a + Send + FnMut(&A) -> K,
+    {
+        Comparison::new(move |a1, a2| f(a1).cmp(&f(a2)))
+    }
+
+    /// Compare two values
+    pub fn compare(&mut self, a1: &A, a2: &A) -> Ordering {
+        (self.0)(a1, a2)
+    }
+
+    /// Reversed comparison
+    pub fn reverse(mut self) -> Self {
+        Comparison::new(move |a1, a2| self.compare(a2, a1))
+    }
+
+    /// Use `other` if `self` considers two values equal
+    pub fn then(mut self, mut other: Self) -> Self {
+        Comparison::new(move |a1, a2| {
+            self.compare(a1, a2).then_with(|| other.compare(a1, a2))
+        })
+    }
+}
+
+impl<'a, A, B> Contravariant<'a, A> for Comparison<'a, B>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = B;
+    type Mapped = Comparison<'a, A>;
+    fn contramap<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Self::Inner,
+    {
+        Comparison::new(move |a1: &A, a2: &A| {
+            self.compare(&f(a1.clone()), &f(a2.clone()))
+        })
+    }
+}
+
+impl<'a, A, B, C> Divisible<'a, A, C> for Comparison<'a, B>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+    C: 'a,
+{
+    type Other = Comparison<'a, C>;
+    fn divide<F>(
+        mut self,
+        mut other: Self::Other,
+        mut f: F,
+    ) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> (Self::Inner, C),
+    {
+        Comparison::new(move |a1: &A, a2: &A| {
+            let (b1, c1) = f(a1.clone());
+            let (b2, c2) = f(a2.clone());
+            self.compare(&b1, &b2).then_with(|| other.compare(&c1, &c2))
+        })
+    }
+    fn conquer() -> Self::Mapped {
+        Comparison::new(|_, _| Ordering::Equal)
+    }
+}
+
+impl<'a, A, B, C> Decidable<'a, A, C> for Comparison<'a, B>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+    C: 'a,
+{
+    fn choose<F>(
+        mut self,
+        mut other: Self::Other,
+        mut f: F,
+    ) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Result<Self::Inner, C>,
+    {
+        Comparison::new(move |a1: &A, a2: &A| {
+            match (f(a1.clone()), f(a2.clone())) {
+                (Ok(b1), Ok(b2)) => self.compare(&b1, &b2),
+                (Err(c1), Err(c2)) => other.compare(&c1, &c2),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+            }
+        })
+    }
+    fn lose<F>(mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Infallible,
+    {
+        Comparison::new(move |a1: &A, _: &A| match f(a1.clone()) {})
+    }
+}
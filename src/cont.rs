@@ -0,0 +1,229 @@
+//! Continuation monad
+//!
+//! See [`Cont`] and [`call_cc`].
+
+use super::*;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Boxed continuation that is passed to a [`Cont`] when it is [run]
+///
+/// [run]: Cont::run
+pub type Continuation<'a, R, A> = Box<dyn 'a + FnOnce(A) -> R>;
+
+/// Continuation monad
+///
+/// A `Cont<'a, R, A>` is a computation in continuation-passing style. It
+/// produces a value of type `A`, which is passed to a [continuation] that
+/// returns the final result of type `R`.
+///
+/// Continuations are one-shot, i.e. they are called at most once.
+///
+/// [continuation]: Continuation
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Monad;
+/// use fmap::cont::Cont;
+///
+/// let a: Cont<'_, String, i32> = Cont::new(|k| k(6));
+/// let b = a.bind(|x| Cont::new(move |k| k(x * 7)));
+/// assert_eq!(b.run(|x| format!("result: {x}")), "result: 42");
+/// ```
+pub struct Cont<'a, R, A>(
+    Box<dyn 'a + FnOnce(Continuation<'a, R, A>) -> R>,
+);
+
+impl<'a, R, A> Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+{
+    /// Create computation from a function that takes a continuation
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + FnOnce(Continuation<'a, R, A>) -> R,
+    {
+        Cont(Box::new(f))
+    }
+    /// Run computation by passing the final continuation
+    pub fn run<K>(self, k: K) -> R
+    where
+        K: 'a + FnOnce(A) -> R,
+    {
+        (self.0)(Box::new(k))
+    }
+}
+
+impl<'a, R> Cont<'a, R, R>
+where
+    R: 'a,
+{
+    /// Run computation with the identity function as continuation
+    pub fn eval(self) -> R {
+        self.run(|r| r)
+    }
+}
+
+/// Escape continuation passed by [`call_cc`]
+///
+/// Calling [`Escape::escape`] creates a computation that aborts the current
+/// continuation and continues with the continuation that was captured by
+/// `call_cc`.
+pub struct Escape<'a, R, A>(Rc<Cell<Option<Continuation<'a, R, A>>>>);
+
+impl<'a, R, A> Clone for Escape<'a, R, A> {
+    fn clone(&self) -> Self {
+        Escape(self.0.clone())
+    }
+}
+
+impl<'a, R, A> Escape<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+{
+    /// Computation that passes `a` to the captured continuation
+    ///
+    /// The returned computation never calls its own continuation, thus the
+    /// type `B` can be freely chosen.
+    ///
+    /// # Panics
+    ///
+    /// Running the returned computation panics if the captured continuation
+    /// has already been called.
+    pub fn escape<B>(&self, a: A) -> Cont<'a, R, B>
+    where
+        B: 'a,
+    {
+        let cell = self.0.clone();
+        Cont::new(move |_| match cell.take() {
+            Some(k) => k(a),
+            None => panic!("continuation has already been called"),
+        })
+    }
+}
+
+/// Call with current continuation
+///
+/// Calls `f` with an [`Escape`] that allows to leave the computation early.
+///
+/// # Panics
+///
+/// Running the returned computation panics if the captured continuation is
+/// called more than once (see [`Escape::escape`]).
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Monad;
+/// use fmap::cont::{call_cc, Cont};
+///
+/// fn check(x: i32) -> Cont<'static, String, i32> {
+///     call_cc(move |exit| {
+///         if x < 0 {
+///             exit.escape(0)
+///         } else {
+///             Cont::new(move |k| k(x))
+///         }
+///         .bind(|x| Cont::new(move |k| k(x * 2)))
+///     })
+/// }
+///
+/// assert_eq!(check(5).run(|x| x.to_string()), "10");
+/// assert_eq!(check(-5).run(|x| x.to_string()), "0");
+/// ```
+pub fn call_cc<'a, R, A, F>(f: F) -> Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+    F: 'a + FnOnce(Escape<'a, R, A>) -> Cont<'a, R, A>,
+{
+    Cont::new(move |k| {
+        let cell = Rc::new(Cell::new(Some(k)));
+        let escape = Escape(cell.clone());
+        f(escape).run(move |a| match cell.take() {
+            Some(k) => k(a),
+            None => panic!("continuation has already been called"),
+        })
+    })
+}
+
+impl<'a, R, A, B> Functor<'a, B> for Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Cont<'a, R, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Cont::new(move |k| (self.0)(Box::new(move |a| k(f(a)))))
+    }
+}
+
+impl<'a, R, A, B> Pure<'a, B> for Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Cont::new(move |k| k(b))
+    }
+}
+
+impl<'a, R, A, B> Monad<'a, B> for Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Cont::new(move |k| (self.0)(Box::new(move |a| f(a).run(k))))
+    }
+}
+
+impl<'a, R, A, B> Applicative<'a, B> for Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: Cont<'a, R, BoxMapper<'a, Self, B>>,
+    ) -> Cont<'a, R, B> {
+        Cont::new(move |k| {
+            (f.0)(Box::new(move |mut mapper| {
+                (self.0)(Box::new(move |a| k(mapper(a))))
+            }))
+        })
+    }
+}
+
+impl<'a, R, A> MonadCont<'a> for Cont<'a, R, A>
+where
+    R: 'a,
+    A: 'a,
+{
+    type Value = A;
+    type Escape = Escape<'a, R, A>;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        call_cc(f)
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        escape.escape(value)
+    }
+}
@@ -0,0 +1,234 @@
+//! Continuation monad transformer
+//!
+//! See [`ContT`] and [`call_cc`].
+
+use super::*;
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// Shared continuation that is passed to a [`ContT`] when it is [run]
+///
+/// Unlike the continuations of [`Cont`](cont::Cont), these continuations
+/// may be called several times (e.g. once for each element when the base
+/// monad is a [`Vec`]).
+///
+/// [run]: ContT::run
+pub type Continuation<'a, M, A> =
+    Arc<dyn 'a + Send + Sync + Fn(A) -> M>;
+
+/// Continuation monad transformer
+///
+/// A `ContT<'a, R, M, A>` is a computation in continuation-passing style,
+/// which passes a value of type `A` to a [continuation] returning the base
+/// monad `M` (with [inner type] `R`). [`call_cc`] allows leaving a
+/// computation early, while computations of the base monad can be
+/// [lifted] into the transformer.
+///
+/// [continuation]: Continuation
+/// [inner type]: Functor::Inner
+/// [lifted]: MonadTrans::lift
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadTrans};
+/// use fmap::cont_t::{call_cc, ContT};
+///
+/// type Calc<A> = ContT<'static, i32, Option<i32>, A>;
+///
+/// fn checked_div(x: i32, y: i32) -> Calc<i32> {
+///     call_cc(move |exit| {
+///         if y == 0 {
+///             exit.escape(-1)
+///         } else {
+///             Calc::lift(Some(x / y))
+///         }
+///         .bind(|q| Calc::lift(Some(q + 1)))
+///     })
+/// }
+///
+/// assert_eq!(checked_div(10, 2).eval(), Some(6));
+/// assert_eq!(checked_div(1, 0).eval(), Some(-1));
+///
+/// let failed = Calc::lift(None).bind(|x| checked_div(x, 1));
+/// assert_eq!(failed.eval(), None);
+/// ```
+pub struct ContT<'a, R, M, A>(
+    Box<dyn 'a + Send + FnOnce(Continuation<'a, M, A>) -> M>,
+    PhantomData<fn() -> R>,
+);
+
+impl<'a, R, M, A> ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+{
+    /// Create computation from a function that takes a continuation
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Continuation<'a, M, A>) -> M,
+    {
+        ContT(Box::new(f), PhantomData)
+    }
+
+    /// Run computation by passing the final continuation
+    pub fn run<K>(self, k: K) -> M
+    where
+        K: 'a + Send + Sync + Fn(A) -> M,
+    {
+        (self.0)(Arc::new(k))
+    }
+}
+
+impl<'a, R, M> ContT<'a, R, M, R>
+where
+    R: 'a,
+    M: 'a + Pure<'a, R, Mapped = M>,
+{
+    /// Run computation with [`Pure::pure`] as continuation
+    pub fn eval(self) -> M {
+        self.run(M::pure)
+    }
+}
+
+/// Escape continuation passed by [`call_cc`]
+///
+/// Calling [`Escape::escape`] creates a computation that aborts the current
+/// continuation and continues with the continuation that was captured by
+/// `call_cc`.
+pub struct Escape<'a, R, M, A>(
+    Continuation<'a, M, A>,
+    PhantomData<fn() -> R>,
+);
+
+impl<'a, R, M, A> Clone for Escape<'a, R, M, A> {
+    fn clone(&self) -> Self {
+        Escape(self.0.clone(), PhantomData)
+    }
+}
+
+impl<'a, R, M, A> Escape<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+{
+    /// Computation that passes `a` to the captured continuation
+    ///
+    /// The returned computation never calls its own continuation, thus the
+    /// type `B` can be freely chosen.
+    pub fn escape<B>(&self, a: A) -> ContT<'a, R, M, B>
+    where
+        A: Send,
+        B: 'a,
+    {
+        let k = self.0.clone();
+        ContT::new(move |_| k(a))
+    }
+}
+
+/// Call with current continuation
+///
+/// Calls `f` with an [`Escape`] that allows to leave the computation early.
+/// See [`ContT`] for an example.
+pub fn call_cc<'a, R, M, A, F>(f: F) -> ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+    F: 'a + Send + FnOnce(Escape<'a, R, M, A>) -> ContT<'a, R, M, A>,
+{
+    ContT::new(move |k| {
+        let escape = Escape(k.clone(), PhantomData);
+        (f(escape).0)(k)
+    })
+}
+
+impl<'a, R, M, A, B> Functor<'a, B> for ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = ContT<'a, R, M, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let f = Mutex::new(f);
+        ContT::new(move |k| {
+            (self.0)(Arc::new(move |a| {
+                let b = (f.lock().unwrap())(a);
+                k(b)
+            }))
+        })
+    }
+}
+
+impl<'a, R, M, A, B> Pure<'a, B> for ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        ContT::new(move |k| k(b))
+    }
+}
+
+impl<'a, R, M, A, B> Monad<'a, B> for ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let f = Mutex::new(f);
+        ContT::new(move |k| {
+            (self.0)(Arc::new(move |a| {
+                let next = (f.lock().unwrap())(a);
+                (next.0)(k.clone())
+            }))
+        })
+    }
+}
+
+impl<'a, R, M, N, A> MonadTrans<'a, N> for ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    N: 'a + Send + Monad<'a, R, Inner = A, Mapped = M>,
+    A: 'a,
+{
+    fn lift(base: N) -> Self {
+        ContT::new(move |k| base.bind(move |a| k(a)))
+    }
+}
+
+impl<'a, R, M, A> MonadCont<'a> for ContT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Escape = Escape<'a, R, M, A>;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        call_cc(f)
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        escape.escape(value)
+    }
+}
@@ -0,0 +1,93 @@
+//! Fusion of successive [`fmap`] calls
+//!
+//! See [`Coyoneda`].
+//!
+//! [`fmap`]: Functor::fmap
+
+use super::*;
+
+/// Boxed mapping function stored in a [`Coyoneda`]
+type Mapper<'a, T, A> =
+    Box<dyn 'a + Send + FnMut(<T as Functor<'a, A>>::Inner) -> A>;
+
+/// Wrapper that accumulates mapping functions and applies them at once
+///
+/// `Coyoneda<'a, T, A>` wraps a [`Functor`] `T` (with an arbitrary
+/// [inner type]) along with a mapping function that converts `T`'s inner
+/// values to `A`. Calling [`fmap`] on a `Coyoneda` doesn't touch the wrapped
+/// functor but only composes mapping functions. When calling
+/// [`Coyoneda::lower`], the composed function is applied to the wrapped functor
+/// in one go, thus avoiding intermediate allocations (e.g. when mapping a
+/// [`Vec`] several times).
+///
+/// [inner type]: Functor::Inner
+/// [`fmap`]: Functor::fmap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::coyoneda::Coyoneda;
+///
+/// let vec = vec![1, 2, 3];
+/// let lowered = Coyoneda::lift(vec)
+///     .fmap(|x| x * 10)
+///     .fmap(|x| x + 1)
+///     .fmap(|x| x.to_string())
+///     .lower();
+/// assert_eq!(lowered, vec!["11", "21", "31"]);
+/// ```
+pub struct Coyoneda<'a, T, A>
+where
+    T: Functor<'a, A>,
+    A: 'a,
+{
+    functor: T,
+    mapper: Mapper<'a, T, A>,
+}
+
+impl<'a, T, A> Coyoneda<'a, T, A>
+where
+    T: Functor<'a, A, Inner = A>,
+    A: 'a,
+{
+    /// Wrap a functor
+    pub fn lift(functor: T) -> Self {
+        Coyoneda {
+            functor,
+            mapper: Box::new(|a| a),
+        }
+    }
+}
+
+impl<'a, T, A> Coyoneda<'a, T, A>
+where
+    T: Functor<'a, A>,
+    A: 'a,
+{
+    /// Apply the accumulated mapping function to the wrapped functor
+    pub fn lower(self) -> T::Mapped {
+        self.functor.fmap(self.mapper)
+    }
+}
+
+impl<'a, T, A, B> Functor<'a, B> for Coyoneda<'a, T, A>
+where
+    T: Functor<'a, A>,
+    T: Functor<'a, B, Inner = <T as Functor<'a, A>>::Inner>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Coyoneda<'a, T, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let mut mapper = self.mapper;
+        Coyoneda {
+            functor: self.functor,
+            mapper: Box::new(move |x| f(mapper(x))),
+        }
+    }
+}
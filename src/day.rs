@@ -0,0 +1,144 @@
+//! Day convolution
+//!
+//! See [`Day`].
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Boxed combining function stored in a [`Day`]
+pub type Combiner<'a, F, G, A> = Box<
+    dyn 'a
+        + Send
+        + FnMut(
+            <F as Functor<'a, A>>::Inner,
+            <G as Functor<'a, A>>::Inner,
+        ) -> A,
+>;
+
+/// Day convolution of two [`Functor`]s
+///
+/// A `Day<'a, F, G, A>` stores two functors `F` and `G` (with arbitrary
+/// [inner types]) along with a function that combines an inner value of `F`
+/// and an inner value of `G` into a value of type `A`. Mapping only composes
+/// the combining function. If `F` and `G` are the same [`Applicative`]
+/// functor, the Day convolution can be [lowered] into that functor.
+///
+/// [inner types]: Functor::Inner
+/// [lowered]: Day::lower
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::day::Day;
+///
+/// let day = Day::new(vec![1, 2], vec![10, 20], |a, b| a + b);
+/// assert_eq!(day.fmap(|x| x * 2).lower(), vec![22, 42, 24, 44]);
+///
+/// let day = Day::new(Some(3), Some("x"), |n, s: &str| s.repeat(n));
+/// let (count, text) = day.elim(|left, right, _| (left, right));
+/// assert_eq!((count, text), (Some(3), Some("x")));
+/// ```
+pub struct Day<'a, F, G, A>
+where
+    F: Functor<'a, A>,
+    G: Functor<'a, A>,
+    A: 'a,
+{
+    left: F,
+    right: G,
+    combiner: Combiner<'a, F, G, A>,
+}
+
+impl<'a, F, G, A> Day<'a, F, G, A>
+where
+    F: Functor<'a, A>,
+    G: Functor<'a, A>,
+    A: 'a,
+{
+    /// Create Day convolution from two functors and a combining function
+    pub fn new<C>(left: F, right: G, combiner: C) -> Self
+    where
+        C: 'a + Send + FnMut(F::Inner, G::Inner) -> A,
+    {
+        Day {
+            left,
+            right,
+            combiner: Box::new(combiner),
+        }
+    }
+
+    /// Consume Day convolution by passing both functors and the combining
+    /// function to a closure
+    pub fn elim<R, E>(self, f: E) -> R
+    where
+        E: FnOnce(F, G, Combiner<'a, F, G, A>) -> R,
+    {
+        f(self.left, self.right, self.combiner)
+    }
+
+    /// Swap both functors
+    pub fn swap(self) -> Day<'a, G, F, A>
+    where
+        F: 'a,
+        G: 'a,
+    {
+        let mut combiner = self.combiner;
+        Day {
+            left: self.right,
+            right: self.left,
+            combiner: Box::new(move |b, a| combiner(a, b)),
+        }
+    }
+
+    /// Combine both functors using [`Applicative::apply`]
+    ///
+    /// This requires `F` and `G` to be the same applicative functor (with
+    /// possibly different inner types).
+    pub fn lower(self) -> <G as Functor<'a, A>>::Mapped
+    where
+        F: Functor<
+            'a,
+            BoxMapper<'a, G, A>,
+            Inner = <F as Functor<'a, A>>::Inner,
+            Mapped = <G as Functor<'a, BoxMapper<'a, G, A>>>::Mapped,
+        >,
+        G: Applicative<'a, A>,
+        <F as Functor<'a, A>>::Inner: Clone + Send,
+    {
+        let combiner = Arc::new(Mutex::new(self.combiner));
+        let mappers =
+            self.left.fmap(move |b: <F as Functor<'a, A>>::Inner| {
+                let combiner = combiner.clone();
+                Box::new(move |c| {
+                    (combiner.lock().unwrap())(b.clone(), c)
+                }) as BoxMapper<'a, G, A>
+            });
+        self.right.apply(mappers)
+    }
+}
+
+impl<'a, F, G, A, B> Functor<'a, B> for Day<'a, F, G, A>
+where
+    F: Functor<'a, A>,
+    F: Functor<'a, B, Inner = <F as Functor<'a, A>>::Inner>,
+    G: Functor<'a, A>,
+    G: Functor<'a, B, Inner = <G as Functor<'a, A>>::Inner>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Day<'a, F, G, B>;
+    fn fmap<M>(self, mut f: M) -> Self::Mapped
+    where
+        M: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let mut combiner = self.combiner;
+        Day {
+            left: self.left,
+            right: self.right,
+            combiner: Box::new(move |b, c| f(combiner(b, c))),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Discrete probability distributions
+//!
+//! See [`Dist`].
+
+use super::*;
+
+/// Discrete probability distribution (non-determinism with weights)
+///
+/// A `Dist<A>` is a list of outcomes along with their probabilities. When
+/// [binding], the probabilities of the outcomes are multiplied. Outcomes are
+/// not merged automatically; use [`Dist::collapse`] to merge equal
+/// outcomes.
+///
+/// [binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad};
+/// use fmap::dist::Dist;
+///
+/// let die = || Dist::uniform(1..=6);
+/// let sum = die().bind(move |a| die().fmap(move |b| a + b));
+/// assert_eq!(sum.outcomes().len(), 36);
+/// assert!((sum.expected_value(|&x| x as f64) - 7.0).abs() < 1e-9);
+///
+/// let sum = sum.collapse();
+/// assert_eq!(sum.outcomes().len(), 11);
+/// assert!((sum.probability(|&x| x == 7) - 1.0 / 6.0).abs() < 1e-9);
+///
+/// let coin = Dist::new([("heads", 3.0), ("tails", 1.0)]);
+/// assert_eq!(coin.sample(0.7), &"heads");
+/// assert_eq!(coin.sample(0.8), &"tails");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Dist<A>(Vec<(A, f64)>);
+
+impl<A> Dist<A> {
+    /// Create distribution from outcomes with (non-negative) weights
+    ///
+    /// The weights are normalized such that they sum up to `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is negative or NaN, if there are no outcomes, or
+    /// if the sum of weights isn't positive.
+    pub fn new<I>(weighted: I) -> Self
+    where
+        I: IntoIterator<Item = (A, f64)>,
+    {
+        let mut outcomes: Vec<(A, f64)> =
+            weighted.into_iter().collect();
+        for (_, p) in outcomes.iter() {
+            assert!(*p >= 0.0, "weights must not be negative or NaN");
+        }
+        let total: f64 = outcomes.iter().map(|(_, p)| p).sum();
+        assert!(total > 0.0, "sum of weights must be positive");
+        for (_, p) in outcomes.iter_mut() {
+            *p /= total;
+        }
+        Dist(outcomes)
+    }
+
+    /// Create uniform distribution
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no outcomes.
+    pub fn uniform<I>(outcomes: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Dist::new(outcomes.into_iter().map(|a| (a, 1.0)))
+    }
+
+    /// Outcomes with their probabilities
+    pub fn outcomes(&self) -> &[(A, f64)] {
+        &self.0
+    }
+
+    /// Convert into outcomes with their probabilities
+    pub fn into_outcomes(self) -> Vec<(A, f64)> {
+        self.0
+    }
+
+    /// Merge equal outcomes by adding their probabilities
+    pub fn collapse(self) -> Self
+    where
+        A: PartialEq,
+    {
+        let mut outcomes: Vec<(A, f64)> = Vec::new();
+        for (a, p) in self.0 {
+            match outcomes.iter_mut().find(|(b, _)| *b == a) {
+                Some((_, q)) => *q += p,
+                None => outcomes.push((a, p)),
+            }
+        }
+        Dist(outcomes)
+    }
+
+    /// Probability that an outcome fulfills a predicate
+    pub fn probability<P>(&self, mut predicate: P) -> f64
+    where
+        P: FnMut(&A) -> bool,
+    {
+        self.0
+            .iter()
+            .filter(|(a, _)| predicate(a))
+            .map(|(_, p)| p)
+            .sum()
+    }
+
+    /// Expected value of a real-valued function of the outcome
+    pub fn expected_value<F>(&self, mut f: F) -> f64
+    where
+        F: FnMut(&A) -> f64,
+    {
+        self.0.iter().map(|(a, p)| f(a) * p).sum()
+    }
+
+    /// Select outcome using a uniformly distributed number `u` in the
+    /// interval `[0, 1)`
+    pub fn sample(&self, u: f64) -> &A {
+        let mut cumulative = 0.0;
+        for (a, p) in self.0.iter() {
+            cumulative += p;
+            if u < cumulative {
+                return a;
+            }
+        }
+        &self.0.last().unwrap().0
+    }
+
+    /// Select an outcome for each uniformly distributed number in the
+    /// interval `[0, 1)`
+    pub fn samples<'b, U>(
+        &'b self,
+        uniform: U,
+    ) -> impl 'b + Iterator<Item = &'b A>
+    where
+        U: 'b + IntoIterator<Item = f64>,
+    {
+        uniform.into_iter().map(move |u| self.sample(u))
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Dist<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Dist<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Dist(self.0.into_iter().map(|(a, p)| (f(a), p)).collect())
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Dist<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for (a, _) in self.0.iter_mut() {
+            f(a);
+        }
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Dist<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Dist(vec![(b, 1.0)])
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Dist<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut outcomes = Vec::new();
+        for (a, p) in self.0 {
+            for (b, q) in f(a).0 {
+                outcomes.push((b, p * q));
+            }
+        }
+        Dist(outcomes)
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Dist<A>
+where
+    A: 'a + Clone,
+    B: 'a,
+{
+    fn apply(self, f: Dist<BoxMapper<'a, Self, B>>) -> Dist<B> {
+        let mut outcomes = Vec::new();
+        for (mut mapper, p) in f.0 {
+            for (a, q) in self.0.iter() {
+                outcomes.push((mapper(a.clone()), p * q));
+            }
+        }
+        Dist(outcomes)
+    }
+}
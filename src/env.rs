@@ -0,0 +1,130 @@
+//! Environment comonad
+//!
+//! See [`Env`].
+
+use super::*;
+
+/// Environment comonad (also known as coreader comonad)
+///
+/// An `Env<E, A>` pairs a value of type `A` with an environment (or context)
+/// of type `E`. Mapping only affects the value, while the environment is
+/// retained. This allows annotating values with metadata that generic code
+/// (which only uses the [`Functor`] or [`Comonad`] traits) can ignore.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Extract, Functor};
+/// use fmap::env::Env;
+///
+/// let length = Env::new("meters", 2.5);
+/// let doubled = length.fmap(|x| x * 2.0);
+/// assert_eq!(*doubled.ask_env(), "meters");
+///
+/// let described = doubled.extend(|env| {
+///     format!("{} {}", env.value(), env.ask_env())
+/// });
+/// assert_eq!(described.extract(), "5 meters");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Env<E, A> {
+    env: E,
+    value: A,
+}
+
+impl<E, A> Env<E, A> {
+    /// Pair value with an environment
+    pub fn new(env: E, value: A) -> Self {
+        Env { env, value }
+    }
+
+    /// Environment
+    pub fn ask_env(&self) -> &E {
+        &self.env
+    }
+
+    /// Value
+    pub fn value(&self) -> &A {
+        &self.value
+    }
+
+    /// Modify environment (and possibly change its type)
+    pub fn local_env<E2, F>(self, f: F) -> Env<E2, A>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        Env {
+            env: f(self.env),
+            value: self.value,
+        }
+    }
+
+    /// Split into environment and value
+    pub fn into_parts(self) -> (E, A) {
+        (self.env, self.value)
+    }
+}
+
+impl<'a, E, A, B> Functor<'a, B> for Env<E, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Env<E, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Env {
+            env: self.env,
+            value: f(self.value),
+        }
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, E, A> FunctorMut<'a, A> for Env<E, A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        f(&mut self.value);
+    }
+}
+
+impl<'a, E, A> Extract<'a, A> for Env<E, A>
+where
+    A: 'a,
+{
+    fn extract(self) -> A {
+        self.value
+    }
+}
+
+impl<'a, E, A, B> Comonad<'a, B> for Env<E, A>
+where
+    E: Clone,
+    A: 'a,
+    B: 'a,
+{
+    fn extend<F>(self, mut f: F) -> Env<E, B>
+    where
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        let env = self.env.clone();
+        Env {
+            env,
+            value: f(self),
+        }
+    }
+}
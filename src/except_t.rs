@@ -0,0 +1,310 @@
+//! Monad transformer for error handling
+//!
+//! See [`ExceptT`].
+
+use super::*;
+
+use std::marker::PhantomData;
+
+/// Monad transformer adding errors (like [`Result`]) to a base monad
+///
+/// An `ExceptT<M, E, A>` wraps a base monad `M` whose [inner type] is
+/// `Result<A, E>`, e.g. `M = Vec<Result<A, E>>` or
+/// `M = Pin<Box<dyn Future<Output = Result<A, E>> + Send>>` (for
+/// asynchronous computations that may fail). [Binding] an `ExceptT` only
+/// calls the closure for [`Ok`] values and short-circuits on [`Err`].
+///
+/// [inner type]: Functor::Inner
+/// [Binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadTrans};
+/// use fmap::except_t::ExceptT;
+/// use futures::executor::block_on;
+/// use futures::future::BoxFuture;
+///
+/// type AsyncResult<A> = ExceptT<BoxFuture<'static, Result<A, String>>, String, A>;
+///
+/// fn parse(s: &'static str) -> AsyncResult<i32> {
+///     ExceptT::new(Box::pin(async move {
+///         s.parse::<i32>().map_err(|e| e.to_string())
+///     }))
+/// }
+///
+/// let sum = parse("3").bind(|a| parse("4").bind(move |b| {
+///     ExceptT::lift(Box::pin(async move { a + b }) as BoxFuture<_>)
+/// }));
+/// assert_eq!(block_on(sum.run()), Ok(7));
+///
+/// let failed = parse("3").bind(|_| parse("x"));
+/// assert!(block_on(failed.run()).is_err());
+///
+/// let recovered = parse("x").catch(|_| ExceptT::new(Box::pin(async { Ok(0) })));
+/// assert_eq!(block_on(recovered.run()), Ok(0));
+/// ```
+pub struct ExceptT<M, E, A> {
+    inner: M,
+    phantom: PhantomData<fn() -> Result<A, E>>,
+}
+
+/// Alias for [`ExceptT`]
+pub type ResultT<M, E, A> = ExceptT<M, E, A>;
+
+impl<M, E, A> ExceptT<M, E, A> {
+    /// Wrap base monad with [inner type] `Result<A, E>`
+    ///
+    /// [inner type]: Functor::Inner
+    pub fn new(inner: M) -> Self {
+        ExceptT {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unwrap base monad
+    pub fn run(self) -> M {
+        self.inner
+    }
+
+    /// Modify the wrapped base monad with a closure
+    pub fn map_t<N, E2, B, F>(self, f: F) -> ExceptT<N, E2, B>
+    where
+        F: FnOnce(M) -> N,
+    {
+        ExceptT::new(f(self.inner))
+    }
+
+    /// Apply a natural transformation to the base monad
+    ///
+    /// Unlike [`map_t`](Self::map_t), the result type `A` is retained.
+    pub fn hoist<N, F>(self, nt: F) -> ExceptT<N, E, A>
+    where
+        F: FnOnce(M) -> N,
+    {
+        ExceptT::new(nt(self.inner))
+    }
+
+    /// Failed computation
+    pub fn throw<'a>(error: E) -> Self
+    where
+        M: Pure<'a, Result<A, E>, Mapped = M>,
+        E: 'a,
+        A: 'a,
+    {
+        ExceptT::new(M::pure(Err(error)))
+    }
+
+    /// Handle errors by calling `handler` with the error
+    ///
+    /// The handler may recover or fail again (with the same error type).
+    pub fn catch<'a, F>(self, mut handler: F) -> Self
+    where
+        M: Monad<'a, Result<A, E>, Inner = Result<A, E>, Mapped = M>,
+        E: 'a,
+        A: 'a,
+        F: 'a + Send + FnMut(E) -> Self,
+    {
+        ExceptT::new(self.inner.bind(move |result| match result {
+            Ok(a) => M::pure(Ok(a)),
+            Err(e) => handler(e).inner,
+        }))
+    }
+
+    /// Convert error with a closure
+    pub fn map_err<'a, E2, F>(
+        self,
+        mut f: F,
+    ) -> ExceptT<<M as Functor<'a, Result<A, E2>>>::Mapped, E2, A>
+    where
+        M: Functor<'a, Result<A, E2>, Inner = Result<A, E>>,
+        E: 'a,
+        E2: 'a,
+        A: 'a,
+        F: 'a + Send + FnMut(E) -> E2,
+    {
+        ExceptT::new(
+            self.inner.fmap(move |result| result.map_err(&mut f)),
+        )
+    }
+}
+
+impl<'a, M, E, A, B> Functor<'a, B> for ExceptT<M, E, A>
+where
+    M: Functor<'a, Result<B, E>, Inner = Result<A, E>>,
+    E: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = ExceptT<M::Mapped, E, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        ExceptT::new(self.inner.fmap(move |result| result.map(&mut f)))
+    }
+}
+
+impl<'a, M, E, A, B> Pure<'a, B> for ExceptT<M, E, A>
+where
+    M: Pure<'a, Result<B, E>, Inner = Result<A, E>>,
+    E: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        ExceptT::new(M::pure(Ok(b)))
+    }
+}
+
+impl<'a, M, E, A, B> Monad<'a, B> for ExceptT<M, E, A>
+where
+    M: Monad<'a, Result<B, E>, Inner = Result<A, E>>,
+    E: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        ExceptT::new(self.inner.bind(move |result| match result {
+            Ok(a) => f(a).inner,
+            Err(e) => M::pure(Err(e)),
+        }))
+    }
+}
+
+impl<'a, M, N, E, A> MonadTrans<'a, N> for ExceptT<M, E, A>
+where
+    N: Functor<'a, Result<A, E>, Inner = A, Mapped = M>,
+    E: 'a,
+    A: 'a,
+{
+    fn lift(base: N) -> Self {
+        ExceptT::new(base.fmap(Ok))
+    }
+}
+
+impl<'a, M, E, A> MonadError<'a, E> for ExceptT<M, E, A>
+where
+    M: Monad<'a, Result<A, E>, Inner = Result<A, E>, Mapped = M>,
+    E: 'a,
+    A: 'a,
+{
+    fn throw_error(error: E) -> Self {
+        ExceptT::throw(error)
+    }
+    fn catch_error<F>(self, handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(E) -> Self,
+    {
+        self.catch(handler)
+    }
+}
+
+impl<'a, S, M, E, A> MonadState<'a, S> for ExceptT<M, E, A>
+where
+    M: MonadState<'a, S, Value = Result<A, E>>,
+    E: 'a,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        ExceptT::new(M::state(move |s| {
+            let (a, s) = f(s);
+            (Ok(a), s)
+        }))
+    }
+}
+
+impl<'a, R, M, E, A> MonadReader<'a, R> for ExceptT<M, E, A>
+where
+    M: MonadReader<'a, R, Value = Result<A, E>>,
+    E: 'a,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        ExceptT::new(M::reader(move |r| Ok(f(r))))
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        ExceptT::new(self.inner.local(f))
+    }
+}
+
+impl<'a, W, M, E, A> MonadWriter<'a, W> for ExceptT<M, E, A>
+where
+    W: 'a,
+    M: MonadWriter<'a, W, Value = Result<A, E>>,
+    M::Listened:
+        Functor<'a, Result<(A, W), E>, Inner = (Result<A, E>, W)>,
+    E: 'a,
+    A: 'a,
+{
+    type Value = A;
+    type Listened = ExceptT<
+        <M::Listened as Functor<'a, Result<(A, W), E>>>::Mapped,
+        E,
+        (A, W),
+    >;
+    fn writer(value: A, log: W) -> Self {
+        ExceptT::new(M::writer(Ok(value), log))
+    }
+    fn listen(self) -> Self::Listened {
+        ExceptT::new(
+            self.inner
+                .listen()
+                .fmap(|(result, w)| result.map(|a| (a, w))),
+        )
+    }
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        ExceptT::new(self.inner.censor(f))
+    }
+}
+
+impl<'a, M, E, A> MonadCont<'a> for ExceptT<M, E, A>
+where
+    M: MonadCont<'a, Value = Result<A, E>>,
+    E: 'a,
+    A: 'a,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        ExceptT::new(M::call_cc(move |escape| f(escape).inner))
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        ExceptT::new(M::escape(escape, Ok(value)))
+    }
+}
+
+impl<'a, M, E, A> MonadIo<'a> for ExceptT<M, E, A>
+where
+    M: MonadIo<'a, Value = Result<A, E>>,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        ExceptT::new(M::lift_io(move || Ok(thunk())))
+    }
+}
@@ -0,0 +1,177 @@
+//! Fixed point of a functor and recursion schemes
+//!
+//! See [`Fix`], [`cata`], [`ana`], and [`hylo`].
+
+use super::*;
+
+use universal::{UniversalFunctor, UniversalFunctorTyCon};
+
+use std::sync::{Arc, Mutex};
+
+/// Fixed point of a [`Functor`]
+///
+/// The functor (called the base functor) is given as a [type constructor]
+/// `T` (see [`Free`] for details). A `Fix<'a, T>` is a layer of the base
+/// functor whose [inner values] are again of type `Fix<'a, T>`. This allows
+/// defining recursive data structures through a non-recursive base functor,
+/// which can then be folded with [`cata`], unfolded with [`ana`], or both
+/// with [`hylo`].
+///
+/// [type constructor]: UniversalFunctorTyCon
+/// [`Free`]: free::Free
+/// [inner values]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::fix::{ana, cata, Fix};
+/// use fmap::universal::UniversalFunctor;
+///
+/// type OptionTyCon =
+///     <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+///
+/// // natural numbers as fixed point of `Option`
+/// type Nat = Fix<'static, OptionTyCon>;
+///
+/// let three: Nat = ana(3, |n| if n == 0 { None } else { Some(n - 1) });
+/// let to_int = |layer: Option<u32>| layer.map_or(0, |n| n + 1);
+/// assert_eq!(cata(three, to_int), 3);
+/// ```
+pub struct Fix<'a, T>(Box<FixLayer<'a, T>>)
+where
+    T: 'a + UniversalFunctorTyCon<'a>;
+
+/// Layer of the base functor `T` in a [`Fix`], i.e. `T<Fix<T>>`
+pub type FixLayer<'a, T> =
+    <T as UniversalFunctorTyCon<'a>>::Functor<Fix<'a, T>, Fix<'a, T>>;
+
+impl<'a, T> Fix<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    /// Wrap a layer of the base functor
+    pub fn new(layer: FixLayer<'a, T>) -> Self {
+        Fix(Box::new(layer))
+    }
+
+    /// Unwrap the outermost layer of the base functor
+    pub fn unfix(self) -> FixLayer<'a, T> {
+        *self.0
+    }
+}
+
+/// Fold a [`Fix`] bottom-up using an algebra (catamorphism)
+///
+/// The algebra `alg` receives a layer of the base functor whose inner
+/// values have already been folded.
+pub fn cata<'a, T, A, F>(fix: Fix<'a, T>, alg: F) -> A
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    F: 'a + Send + FnMut(T::Functor<A, A>) -> A,
+{
+    cata_shared(fix, Arc::new(Mutex::new(alg)))
+}
+
+fn cata_shared<'a, T, A, F>(fix: Fix<'a, T>, alg: Arc<Mutex<F>>) -> A
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    F: 'a + Send + FnMut(T::Functor<A, A>) -> A,
+{
+    let alg2 = alg.clone();
+    let layer = fix
+        .unfix()
+        .change_functor_target::<A>()
+        .fmap(move |fix| cata_shared(fix, alg2.clone()));
+    let result = (alg.lock().unwrap())(layer);
+    result
+}
+
+/// Build a [`Fix`] top-down from a seed using a coalgebra (anamorphism)
+///
+/// The coalgebra `coalg` returns a layer of the base functor containing
+/// the seeds for the inner values.
+pub fn ana<'a, T, S, F>(seed: S, coalg: F) -> Fix<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    S: 'a,
+    F: 'a + Send + FnMut(S) -> T::Functor<S, S>,
+{
+    ana_shared(seed, Arc::new(Mutex::new(coalg)))
+}
+
+fn ana_shared<'a, T, S, F>(seed: S, coalg: Arc<Mutex<F>>) -> Fix<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    S: 'a,
+    F: 'a + Send + FnMut(S) -> T::Functor<S, S>,
+{
+    let layer = (coalg.lock().unwrap())(seed);
+    Fix::new(
+        layer
+            .change_functor_target::<Fix<'a, T>>()
+            .fmap(move |seed| ana_shared(seed, coalg.clone())),
+    )
+}
+
+/// Unfold a seed with a coalgebra and fold the result with an algebra
+/// (hylomorphism)
+///
+/// This is equivalent to `cata(ana(seed, coalg), alg)`, but doesn't build
+/// the intermediate [`Fix`].
+///
+/// # Examples
+///
+/// ```
+/// use fmap::fix::hylo;
+/// use fmap::universal::UniversalFunctor;
+///
+/// type VecTyCon =
+///     <Vec<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+///
+/// // Fibonacci numbers through a call tree
+/// let fib = |n: u64| {
+///     hylo::<VecTyCon, _, _, _, _>(
+///         n,
+///         |n| if n < 2 { vec![] } else { vec![n - 1, n - 2] },
+///         |results| results.into_iter().sum::<u64>().max(1),
+///     )
+/// };
+/// assert_eq!(fib(10), 89);
+/// ```
+pub fn hylo<'a, T, S, A, F, G>(seed: S, coalg: F, alg: G) -> A
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    S: 'a,
+    A: 'a,
+    F: 'a + Send + FnMut(S) -> T::Functor<S, S>,
+    G: 'a + Send + FnMut(T::Functor<A, A>) -> A,
+{
+    hylo_shared::<T, S, A, F, G>(
+        seed,
+        Arc::new(Mutex::new(coalg)),
+        Arc::new(Mutex::new(alg)),
+    )
+}
+
+fn hylo_shared<'a, T, S, A, F, G>(
+    seed: S,
+    coalg: Arc<Mutex<F>>,
+    alg: Arc<Mutex<G>>,
+) -> A
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    S: 'a,
+    A: 'a,
+    F: 'a + Send + FnMut(S) -> T::Functor<S, S>,
+    G: 'a + Send + FnMut(T::Functor<A, A>) -> A,
+{
+    let layer = (coalg.lock().unwrap())(seed);
+    let alg2 = alg.clone();
+    let layer = layer.change_functor_target::<A>().fmap(move |seed| {
+        hylo_shared::<T, S, A, F, G>(seed, coalg.clone(), alg2.clone())
+    });
+    let result = (alg.lock().unwrap())(layer);
+    result
+}
@@ -0,0 +1,676 @@
+//! Free monad
+//!
+//! See [`Free`].
+
+use super::*;
+
+use universal::{UniversalFunctor, UniversalFunctorTyCon};
+
+use std::sync::{Arc, Mutex};
+
+type Cell<X> = Arc<Mutex<Option<X>>>;
+
+/// Free monad over a [`Functor`]
+///
+/// The functor is given as a [type constructor] `T`, such that
+/// `T::Functor<A, A>` is the functor with [inner type] `A`. A `Free<'a, T, A>`
+/// is either a [pure] value or a layer of the functor that contains further
+/// `Free` values.
+///
+/// Free monads allow describing effectful computations as plain data
+/// (e.g. a DSL), which may later be interpreted in different ways using
+/// [`Free::run`], [`Free::resume`], or [`fold_free`].
+///
+/// Binding (using [`Monad::bind`] or [`Functor::fmap`]) takes constant time
+/// and merely records the continuation in a [suspended] node, which is
+/// evaluated iteratively by [`Free::resume`]. Thus neither building nor
+/// [running] a computation consumes stack space proportional to the number
+/// of binds, no matter how they are nested. Chains of suspended binds are
+/// also dropped iteratively, while layers of the functor (e.g. created by
+/// [`Free::wrap`]) are dropped by the functor itself, i.e. recursively.
+///
+/// Evaluating suspended binds requires the values and layers to be [`Send`].
+///
+/// [type constructor]: UniversalFunctorTyCon
+/// [inner type]: Functor::Inner
+/// [pure]: Free::Pure
+/// [suspended]: Free::FlatMap
+/// [running]: Free::run
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::free::Free;
+/// use fmap::universal::{UniversalFunctor, UniversalFunctorTyCon};
+///
+/// // Instruction set of a simple DSL
+/// pub enum Cmd<A> {
+///     Say(String, A),
+///     Ask(Box<dyn Send + FnMut(i32) -> A>),
+/// }
+///
+/// impl<A, B> Functor<'static, B> for Cmd<A>
+/// where
+///     A: 'static,
+///     B: 'static,
+/// {
+///     type Inner = A;
+///     type Mapped = Cmd<B>;
+///     fn fmap<F>(self, mut f: F) -> Self::Mapped
+///     where
+///         F: 'static + Send + FnMut(Self::Inner) -> B,
+///     {
+///         match self {
+///             Cmd::Say(s, next) => Cmd::Say(s, f(next)),
+///             Cmd::Ask(mut g) => Cmd::Ask(Box::new(move |x| f(g(x)))),
+///         }
+///     }
+/// }
+///
+/// pub struct Cmd_;
+///
+/// impl UniversalFunctorTyCon<'static> for Cmd_ {
+///     type Functor<A, B> = Cmd<A>
+///     where
+///         A: 'static,
+///         B: 'static;
+/// }
+///
+/// impl<A, B> UniversalFunctor<'static, B> for Cmd<A>
+/// where
+///     A: 'static,
+///     B: 'static,
+/// {
+///     type FunctorTyCon = Cmd_;
+///     fn change_functor_target<T>(self) -> Self {
+///         self
+///     }
+///     fn from_mapped_functor(this: Self) -> Self {
+///         this
+///     }
+/// }
+///
+/// use fmap::Monad;
+///
+/// fn say(s: &str) -> Free<'static, Cmd_, ()> {
+///     Free::lift_f(Cmd::Say(s.to_string(), ()))
+/// }
+/// fn ask() -> Free<'static, Cmd_, i32> {
+///     Free::lift_f(Cmd::Ask(Box::new(|x| x)))
+/// }
+///
+/// let program = say("Hello").bind(|()| ask()).bind(|x| {
+///     say("Got input").fmap(move |()| x * 2)
+/// });
+///
+/// // interpret program with fixed input and a log
+/// let mut log = Vec::new();
+/// let result = program.run(|cmd| match cmd {
+///     Cmd::Say(s, next) => {
+///         log.push(s);
+///         next
+///     }
+///     Cmd::Ask(mut f) => f(21),
+/// });
+/// assert_eq!(result, 42);
+/// assert_eq!(log, vec!["Hello".to_string(), "Got input".to_string()]);
+/// ```
+pub enum Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    /// Pure value
+    Pure(A),
+    /// Layer of the functor wrapping the rest of the computation
+    Roll(Box<FreeLayer<'a, T, A>>),
+    /// Suspended [bind](Monad::bind), evaluated by [`Free::resume`]
+    FlatMap(FlatMap<'a, T, A>),
+}
+
+/// Layer of the functor `T` in a [`Free`] monad, i.e. `T<Free<T, A>>`
+pub type FreeLayer<'a, T, A> =
+    <T as UniversalFunctorTyCon<'a>>::Functor<
+        Free<'a, T, A>,
+        Free<'a, T, A>,
+    >;
+
+/// Opaque suspended computation in a [`Free`] monad (see [`Free::FlatMap`])
+pub struct FlatMap<'a, T, A>(
+    Option<Box<dyn 'a + Send + Node<'a, T, A>>>,
+)
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a;
+
+impl<'a, T, A> Drop for FlatMap<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    fn drop(&mut self) {
+        let mut child =
+            self.0.as_mut().and_then(|node| node.take_child());
+        while let Some(mut free) = child {
+            child = free.take_child();
+        }
+    }
+}
+
+/// Value which can be dismantled before being dropped, to avoid recursion
+trait Droppable<'a> {
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>>;
+}
+
+impl<'a, T, A> Droppable<'a> for Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>> {
+        match self {
+            Free::FlatMap(FlatMap(Some(node))) => node.take_child(),
+            _ => None,
+        }
+    }
+}
+
+/// Suspended computation which writes its result of type `A` to a [`Cell`]
+trait Node<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    fn start(
+        self: Box<Self>,
+        out: Cell<A>,
+        stack: &mut Stack<'a, T>,
+    ) -> Term<'a, T>;
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>>;
+}
+
+/// [`Free`] value with erased type, which is evaluated step by step
+trait Evaluate<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    fn step(self: Box<Self>, stack: &mut Stack<'a, T>) -> Step<'a, T>;
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>>;
+}
+
+type Term<'a, T> = Box<dyn 'a + Send + Evaluate<'a, T>>;
+
+enum Step<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    Continue(Term<'a, T>),
+    Layer(Box<T::Functor<Term<'a, T>, Term<'a, T>>>),
+    Done,
+}
+
+/// Continuation reading its input from a [`Cell`]
+trait Frame<'a, T>: Send + Sync
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    fn call(&self) -> Term<'a, T>;
+}
+
+/// Persistent stack of continuations, shared by branches of the functor
+type Stack<'a, T> = Option<Arc<StackNode<'a, T>>>;
+
+struct StackNode<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    item: Item<'a, T>,
+    next: Stack<'a, T>,
+}
+
+enum Item<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    Frame(Arc<dyn 'a + Frame<'a, T>>),
+    Segment(Stack<'a, T>),
+}
+
+impl<'a, T> Clone for Item<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Item::Frame(frame) => Item::Frame(frame.clone()),
+            Item::Segment(segment) => Item::Segment(segment.clone()),
+        }
+    }
+}
+
+impl<'a, T> StackNode<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    fn dismantle(&mut self, pending: &mut Vec<Arc<Self>>) {
+        pending.extend(self.next.take());
+        if let Item::Segment(segment) = &mut self.item {
+            pending.extend(segment.take());
+        }
+    }
+}
+
+impl<'a, T> Drop for StackNode<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    fn drop(&mut self) {
+        let mut pending = Vec::new();
+        self.dismantle(&mut pending);
+        while let Some(node) = pending.pop() {
+            if let Ok(mut node) = Arc::try_unwrap(node) {
+                node.dismantle(&mut pending);
+            }
+        }
+    }
+}
+
+fn push<'a, T>(stack: Stack<'a, T>, item: Item<'a, T>) -> Stack<'a, T>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    Some(Arc::new(StackNode { item, next: stack }))
+}
+
+fn pop<'a, T>(
+    stack: &mut Stack<'a, T>,
+) -> Option<Arc<dyn 'a + Frame<'a, T>>>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    loop {
+        let node = stack.take()?;
+        match &node.item {
+            Item::Frame(frame) => {
+                *stack = node.next.clone();
+                return Some(frame.clone());
+            }
+            Item::Segment(None) => *stack = node.next.clone(),
+            Item::Segment(Some(segment)) => {
+                let mut rest = node.next.clone();
+                if segment.next.is_some() {
+                    rest =
+                        push(rest, Item::Segment(segment.next.clone()));
+                }
+                *stack = push(rest, segment.item.clone());
+            }
+        }
+    }
+}
+
+struct TypedTerm<'a, T, X>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    X: 'a,
+{
+    free: Free<'a, T, X>,
+    out: Cell<X>,
+}
+
+impl<'a, T, X> Evaluate<'a, T> for TypedTerm<'a, T, X>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    X: 'a + Send,
+    FreeLayer<'a, T, X>: Send,
+{
+    fn step(self: Box<Self>, stack: &mut Stack<'a, T>) -> Step<'a, T> {
+        let TypedTerm { free, out } = *self;
+        match free {
+            Free::Pure(x) => {
+                *out.lock().unwrap() = Some(x);
+                match pop(stack) {
+                    Some(frame) => Step::Continue(frame.call()),
+                    None => Step::Done,
+                }
+            }
+            Free::Roll(layer) => Step::Layer(Box::new(
+                (*layer).change_functor_target::<Term<'a, T>>().fmap(
+                    move |free| -> Term<'a, T> {
+                        Box::new(TypedTerm {
+                            free,
+                            out: out.clone(),
+                        })
+                    },
+                ),
+            )),
+            Free::FlatMap(mut flat_map) => Step::Continue(
+                flat_map.0.take().unwrap().start(out, stack),
+            ),
+        }
+    }
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>> {
+        self.free.take_child()
+    }
+}
+
+struct BindNode<'a, T, X, F>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    X: 'a,
+{
+    free: Option<Free<'a, T, X>>,
+    f: F,
+}
+
+impl<'a, T, X, A, F> Node<'a, T, A> for BindNode<'a, T, X, F>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    X: 'a + Send,
+    A: 'a + Send,
+    FreeLayer<'a, T, X>: Send,
+    FreeLayer<'a, T, A>: Send,
+    F: 'a + Send + FnMut(X) -> Free<'a, T, A>,
+{
+    fn start(
+        self: Box<Self>,
+        out: Cell<A>,
+        stack: &mut Stack<'a, T>,
+    ) -> Term<'a, T> {
+        let input: Cell<X> = Arc::new(Mutex::new(None));
+        let frame = BindFrame {
+            input: input.clone(),
+            f: Mutex::new(self.f),
+            out,
+        };
+        *stack = push(stack.take(), Item::Frame(Arc::new(frame)));
+        Box::new(TypedTerm {
+            free: self.free.unwrap(),
+            out: input,
+        })
+    }
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>> {
+        self.free.take().map(|free| -> Box<dyn 'a + Droppable<'a>> {
+            Box::new(free)
+        })
+    }
+}
+
+struct BindFrame<X, F, A> {
+    input: Cell<X>,
+    f: Mutex<F>,
+    out: Cell<A>,
+}
+
+impl<'a, T, X, A, F> Frame<'a, T> for BindFrame<X, F, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    X: 'a + Send,
+    A: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+    F: 'a + Send + FnMut(X) -> Free<'a, T, A>,
+{
+    fn call(&self) -> Term<'a, T> {
+        let x = self.input.lock().unwrap().take().unwrap();
+        Box::new(TypedTerm {
+            free: (self.f.lock().unwrap())(x),
+            out: self.out.clone(),
+        })
+    }
+}
+
+/// Remainder of a computation after [`Free::resume`] returned a layer
+struct ResumeNode<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+{
+    term: Option<Term<'a, T>>,
+    stack: Stack<'a, T>,
+    result: Cell<A>,
+}
+
+impl<'a, T, A> Node<'a, T, A> for ResumeNode<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+{
+    fn start(
+        self: Box<Self>,
+        out: Cell<A>,
+        stack: &mut Stack<'a, T>,
+    ) -> Term<'a, T> {
+        let ResumeNode {
+            term,
+            stack: saved,
+            result,
+        } = *self;
+        let frame = CopyFrame {
+            from: result,
+            to: out,
+        };
+        *stack = push(stack.take(), Item::Frame(Arc::new(frame)));
+        *stack = push(stack.take(), Item::Segment(saved));
+        term.unwrap()
+    }
+    fn take_child(&mut self) -> Option<Box<dyn 'a + Droppable<'a>>> {
+        self.term.as_mut().and_then(|term| term.take_child())
+    }
+}
+
+struct CopyFrame<A> {
+    from: Cell<A>,
+    to: Cell<A>,
+}
+
+impl<'a, T, A> Frame<'a, T> for CopyFrame<A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+{
+    fn call(&self) -> Term<'a, T> {
+        let a = self.from.lock().unwrap().take().unwrap();
+        Box::new(TypedTerm {
+            free: Free::Pure(a),
+            out: self.to.clone(),
+        })
+    }
+}
+
+impl<'a, T, A> Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    /// Lift a functor into the free monad
+    pub fn lift_f(functor: T::Functor<A, A>) -> Self {
+        Free::Roll(Box::new(
+            functor
+                .change_functor_target::<Free<'a, T, A>>()
+                .fmap(Free::Pure),
+        ))
+    }
+
+    /// Wrap a layer of the functor
+    pub fn wrap(functor: FreeLayer<'a, T, A>) -> Self {
+        Free::Roll(Box::new(functor))
+    }
+}
+
+impl<'a, T, A> Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+{
+    /// Return the pure value or the outermost layer of the functor
+    ///
+    /// Suspended binds are evaluated in a loop until a pure value or a layer
+    /// of the functor is reached.
+    pub fn resume(self) -> Result<A, FreeLayer<'a, T, A>> {
+        let free = match self {
+            Free::Pure(a) => return Ok(a),
+            Free::Roll(functor) => return Err(*functor),
+            free => free,
+        };
+        let result: Cell<A> = Arc::new(Mutex::new(None));
+        let mut stack: Stack<'a, T> = None;
+        let mut term: Term<'a, T> = Box::new(TypedTerm {
+            free,
+            out: result.clone(),
+        });
+        loop {
+            match term.step(&mut stack) {
+                Step::Continue(next) => term = next,
+                Step::Done => {
+                    return Ok(result.lock().unwrap().take().unwrap())
+                }
+                Step::Layer(layer) => {
+                    return Err((*layer)
+                        .change_functor_target::<Free<'a, T, A>>()
+                        .fmap(move |term| {
+                            Free::FlatMap(FlatMap(Some(Box::new(
+                                ResumeNode {
+                                    term: Some(term),
+                                    stack: stack.clone(),
+                                    result: result.clone(),
+                                },
+                            ))))
+                        }))
+                }
+            }
+        }
+    }
+
+    /// Run computation by repeatedly applying a step function to the
+    /// outermost layer of the functor
+    ///
+    /// Evaluation is done in a loop and thus doesn't consume stack space
+    /// proportional to the number of steps or binds.
+    pub fn run<G>(self, mut step: G) -> A
+    where
+        G: FnMut(FreeLayer<'a, T, A>) -> Self,
+    {
+        let mut this = self;
+        loop {
+            match this.resume() {
+                Ok(a) => return a,
+                Err(functor) => this = step(functor),
+            }
+        }
+    }
+}
+
+impl<'a, T, A, B> Functor<'a, B> for Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    B: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+    FreeLayer<'a, T, B>: Send,
+{
+    type Inner = A;
+    type Mapped = Free<'a, T, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.bind(move |a| Free::Pure(f(a)))
+    }
+}
+
+impl<'a, T, A, B> Pure<'a, B> for Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    B: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+    FreeLayer<'a, T, B>: Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Free::Pure(b)
+    }
+}
+
+impl<'a, T, A, B> Monad<'a, B> for Free<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    B: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+    FreeLayer<'a, T, B>: Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Free::FlatMap(FlatMap(Some(Box::new(BindNode {
+            free: Some(self),
+            f,
+        }))))
+    }
+}
+
+/// Interpret a [`Free`] monad in a target [`Monad`] `M`
+///
+/// Each layer of the functor is mapped into the target monad using the
+/// interpreter `nt`. The layer contains the rest of the computation (as
+/// [`Free`] values), which `nt` must pass on unchanged without inspecting
+/// it. The layers are then joined using [`Monad::bind`].
+///
+/// *Note:* Stack usage depends on the target monad. For monads that are
+/// evaluated eagerly (e.g. [`Option`]), stack usage is proportional to the
+/// number of layers. Use [`Free::run`] for a stack-safe interpreter.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::free::{fold_free, Free};
+/// use fmap::universal::UniversalFunctor;
+/// use fmap::Monad;
+///
+/// type OptionTyCon =
+///     <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+///
+/// let free: Free<'static, OptionTyCon, i32> = Free::lift_f(Some(4))
+///     .bind(|x| Free::lift_f(Some(x + 1)));
+/// let result: Option<i32> = fold_free(free, |x| x);
+/// assert_eq!(result, Some(5));
+///
+/// let free: Free<'static, OptionTyCon, i32> = Free::lift_f(Some(4))
+///     .bind(|_| Free::lift_f(None));
+/// let result: Option<i32> = fold_free(free, |x| x);
+/// assert_eq!(result, None);
+/// ```
+pub fn fold_free<'a, T, A, M, N>(free: Free<'a, T, A>, nt: N) -> M
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+    M: Functor<'a, Free<'a, T, A>, Inner = A>,
+    M::Mapped: Monad<'a, A, Inner = Free<'a, T, A>, Mapped = M>,
+    N: 'a + Send + FnMut(FreeLayer<'a, T, A>) -> M::Mapped,
+{
+    fold_free_shared(free, Arc::new(Mutex::new(nt)))
+}
+
+fn fold_free_shared<'a, T, A, M, N>(
+    free: Free<'a, T, A>,
+    nt: Arc<Mutex<N>>,
+) -> M
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a + Send,
+    FreeLayer<'a, T, A>: Send,
+    M: Functor<'a, Free<'a, T, A>, Inner = A>,
+    M::Mapped: Monad<'a, A, Inner = Free<'a, T, A>, Mapped = M>,
+    N: 'a + Send + FnMut(FreeLayer<'a, T, A>) -> M::Mapped,
+{
+    match free.resume() {
+        Ok(a) => M::Mapped::pure(a),
+        Err(functor) => {
+            let layer = (nt.lock().unwrap())(functor);
+            layer.bind(move |free| fold_free_shared(free, nt.clone()))
+        }
+    }
+}
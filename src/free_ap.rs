@@ -0,0 +1,278 @@
+//! Free applicative functor
+//!
+//! See [`FreeAp`].
+
+use super::*;
+
+use universal::{UniversalFunctor, UniversalFunctorTyCon};
+
+use std::sync::{Arc, Mutex};
+
+/// Opaque result of an effect in a [`FreeAp`]
+///
+/// Each effect in a `FreeAp` has its inner type replaced by `Slot`. When
+/// interpreting a `FreeAp` with [`fold_ap`], the interpreter must not inspect
+/// or modify slots but pass them on unchanged (i.e. the interpreter must be a
+/// natural transformation).
+pub struct Slot<'a>(Box<dyn 'a + Send + FnOnce()>);
+
+/// Effect in a [`FreeAp`], i.e. the functor `T` with [`Slot`] as inner type
+pub type FreeApEffect<'a, T> =
+    <T as UniversalFunctorTyCon<'a>>::Functor<Slot<'a>, Slot<'a>>;
+
+type SlotMapper<'a> =
+    Box<dyn 'a + Send + FnMut(Slot<'a>) -> Vec<Slot<'a>>>;
+
+/// Free applicative functor over a [`Functor`]
+///
+/// The functor is given as a [type constructor] `T`, such that
+/// `T::Functor<A, A>` is the functor with [inner type] `A`. A
+/// `FreeAp<'a, T, A>` is a list of effects (which are independent of each
+/// other) together with a function that combines the results of these effects
+/// into a value of type `A`.
+///
+/// Unlike [`Free`], a free applicative can be [analyzed] without running
+/// any effects. It can be interpreted with [`fold_ap`].
+///
+/// [type constructor]: UniversalFunctorTyCon
+/// [inner type]: Functor::Inner
+/// [`Free`]: crate::free::Free
+/// [analyzed]: FreeAp::analyze
+///
+/// # Examples
+///
+/// ```
+/// use fmap::free_ap::{fold_ap, FreeAp};
+/// use fmap::universal::{UniversalFunctor, UniversalFunctorTyCon};
+/// use fmap::{Applicative, Functor};
+/// use std::collections::HashMap;
+///
+/// // Named parameter that is parsed from a string
+/// pub struct Param<A> {
+///     name: &'static str,
+///     parse: Box<dyn Send + FnMut(&str) -> Option<A>>,
+/// }
+///
+/// impl<A, B> Functor<'static, B> for Param<A>
+/// where
+///     A: 'static,
+///     B: 'static,
+/// {
+///     type Inner = A;
+///     type Mapped = Param<B>;
+///     fn fmap<F>(mut self, mut f: F) -> Self::Mapped
+///     where
+///         F: 'static + Send + FnMut(Self::Inner) -> B,
+///     {
+///         Param {
+///             name: self.name,
+///             parse: Box::new(move |s| (self.parse)(s).map(&mut f)),
+///         }
+///     }
+/// }
+///
+/// pub struct Param_;
+///
+/// impl UniversalFunctorTyCon<'static> for Param_ {
+///     type Functor<A, B> = Param<A>
+///     where
+///         A: 'static,
+///         B: 'static;
+/// }
+///
+/// impl<A, B> UniversalFunctor<'static, B> for Param<A>
+/// where
+///     A: 'static,
+///     B: 'static,
+/// {
+///     type FunctorTyCon = Param_;
+///     fn change_functor_target<T>(self) -> Self {
+///         self
+///     }
+///     fn from_mapped_functor(this: Self) -> Self {
+///         this
+///     }
+/// }
+///
+/// fn int_param(name: &'static str) -> FreeAp<'static, Param_, i32> {
+///     FreeAp::lift(Param {
+///         name,
+///         parse: Box::new(|s| s.parse().ok()),
+///     })
+/// }
+///
+/// let width = int_param("width");
+/// let area = int_param("height").apply(width.fmap(|w| {
+///     Box::new(move |h| w * h) as Box<dyn Send + FnMut(i32) -> i32>
+/// }));
+///
+/// // analyze without running
+/// assert_eq!(area.analyze(|param| param.name), vec!["width", "height"]);
+///
+/// // interpret
+/// let input = HashMap::from([("width", "6"), ("height", "7")]);
+/// let result: Option<i32> = fold_ap(area, move |mut param| {
+///     input.get(param.name).and_then(|s| (param.parse)(s))
+/// });
+/// assert_eq!(result, Some(42));
+/// ```
+pub struct FreeAp<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    effects: Vec<FreeApEffect<'a, T>>,
+    build: Box<dyn 'a + Send + FnOnce() -> A>,
+}
+
+impl<'a, T, A> FreeAp<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    /// Lift a functor into the free applicative functor
+    pub fn lift(functor: T::Functor<A, A>) -> Self
+    where
+        A: Send,
+    {
+        let cell: Arc<Mutex<Option<A>>> = Arc::new(Mutex::new(None));
+        let cell2 = cell.clone();
+        let effect = functor.change_functor_target::<Slot<'a>>().fmap(
+            move |a| {
+                let cell = cell2.clone();
+                Slot(Box::new(move || *cell.lock().unwrap() = Some(a)))
+            },
+        );
+        FreeAp {
+            effects: vec![effect],
+            build: Box::new(move || {
+                cell.lock()
+                    .unwrap()
+                    .take()
+                    .expect("slot of FreeAp effect not filled")
+            }),
+        }
+    }
+
+    /// Collect information about all effects without running them
+    ///
+    /// The function `f` is applied to each effect (in order) and the results
+    /// are returned as a [`Vec`].
+    pub fn analyze<R, F>(&self, f: F) -> Vec<R>
+    where
+        F: FnMut(&FreeApEffect<'a, T>) -> R,
+    {
+        self.effects.iter().map(f).collect()
+    }
+}
+
+impl<'a, T, A, B> Functor<'a, B> for FreeAp<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = FreeAp<'a, T, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let build = self.build;
+        FreeAp {
+            effects: self.effects,
+            build: Box::new(move || f(build())),
+        }
+    }
+}
+
+impl<'a, T, A, B> Pure<'a, B> for FreeAp<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        FreeAp {
+            effects: Vec::new(),
+            build: Box::new(move || b),
+        }
+    }
+}
+
+impl<'a, T, A, B> Applicative<'a, B> for FreeAp<'a, T, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn apply(
+        self,
+        f: FreeAp<'a, T, BoxMapper<'a, Self, B>>,
+    ) -> FreeAp<'a, T, B> {
+        let mut effects = f.effects;
+        effects.extend(self.effects);
+        let build_mapper = f.build;
+        let build = self.build;
+        FreeAp {
+            effects,
+            build: Box::new(move || (build_mapper())(build())),
+        }
+    }
+}
+
+/// Interpret a [`FreeAp`] in a target [`Applicative`] functor
+///
+/// Each effect is converted into the target applicative functor `G` (with
+/// [`Slot`] as inner type) using the interpreter `nt`. The results are then
+/// combined using [`Applicative::apply`].
+///
+/// # Panics
+///
+/// [`Slot`]s cannot be cloned. Thus applicative functors that require cloning
+/// of inner values (such as [`Vec`]) cannot be used as target. If the target
+/// applicative functor combines values more than once otherwise, this
+/// function panics.
+///
+/// See [`FreeAp`] for an example.
+pub fn fold_ap<'a, T, A, G, N>(
+    free: FreeAp<'a, T, A>,
+    mut nt: N,
+) -> <<G as Functor<'a, Vec<Slot<'a>>>>::Mapped as Functor<'a, A>>::Mapped
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    G: Applicative<'a, Vec<Slot<'a>>>
+        + Functor<'a, Vec<Slot<'a>>, Inner = Slot<'a>>
+        + Functor<'a, SlotMapper<'a>, Inner = Slot<'a>>
+        + Pure<'a, SlotMapper<'a>>,
+    <G as Functor<'a, Vec<Slot<'a>>>>::Mapped: Functor<
+            'a,
+            SlotMapper<'a>,
+            Inner = Vec<Slot<'a>>,
+            Mapped = <G as Functor<'a, SlotMapper<'a>>>::Mapped,
+        > + Functor<'a, A, Inner = Vec<Slot<'a>>>,
+    N: FnMut(FreeApEffect<'a, T>) -> G,
+{
+    const MULTIPLE_USE: &str =
+        "FreeAp interpreted with non-linear target";
+    let mut acc = G::pure(Vec::new());
+    for effect in free.effects {
+        let mapper = acc.fmap(|slots: Vec<Slot<'a>>| {
+            let mut slots = Some(slots);
+            Box::new(move |slot| {
+                let mut slots = slots.take().expect(MULTIPLE_USE);
+                slots.push(slot);
+                slots
+            }) as SlotMapper<'a>
+        });
+        acc = nt(effect).apply(mapper);
+    }
+    let mut build = Some(free.build);
+    acc.fmap(move |slots: Vec<Slot<'a>>| {
+        for slot in slots {
+            (slot.0)();
+        }
+        (build.take().expect(MULTIPLE_USE))()
+    })
+}
@@ -0,0 +1,376 @@
+//! Free monad transformer
+//!
+//! See [`FreeT`].
+
+use super::*;
+
+use universal::{UniversalFunctor, UniversalFunctorTyCon};
+
+use std::sync::{Arc, Mutex};
+
+/// Result of running one step of a [`FreeT`] in the base monad
+pub enum FreeStep<'a, T, N, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    /// Pure value
+    Pure(A),
+    /// Layer of the functor wrapping the rest of the computation
+    Roll(Box<FreeTLayer<'a, T, N, A>>),
+}
+
+/// Layer of the functor `T` in a [`FreeT`], i.e. `T<FreeT<T, N, A>>`
+pub type FreeTLayer<'a, T, N, A> =
+    <T as UniversalFunctorTyCon<'a>>::Functor<
+        FreeT<'a, T, N, A>,
+        FreeT<'a, T, N, A>,
+    >;
+
+/// Base monad `N` wrapping a [`FreeStep`], i.e. `N<FreeStep<T, N, A>>`
+pub type FreeTBase<'a, T, N, A> =
+    <N as UniversalFunctorTyCon<'a>>::Functor<
+        FreeStep<'a, T, N, A>,
+        FreeStep<'a, T, N, A>,
+    >;
+
+/// Free monad transformer over a [`Functor`] and a base [`Monad`]
+///
+/// Like [`Free`](free::Free), a `FreeT<'a, T, N, A>` describes a computation
+/// as layers of a functor (given as [type constructor] `T`), but each step
+/// is wrapped in a base monad (given as type constructor `N`, such that
+/// `N::Functor<A, A>` is the base monad with [inner type] `A`). This allows
+/// interleaving the instructions of a DSL with effects of the base monad,
+/// e.g. futures.
+///
+/// Computations can be interpreted stack-safely with [`FreeT::run`] (if
+/// the base monad can be evaluated directly), or be folded into the base
+/// monad with [`fold_free_t`].
+///
+/// [type constructor]: UniversalFunctorTyCon
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::free_t::{fold_free_t, FreeT};
+/// use fmap::universal::UniversalFunctor;
+/// use fmap::{Monad, MonadTrans};
+///
+/// // functor asking for an input of type `i32`
+/// type Ask<A> = Box<dyn Send + FnOnce(i32) -> A>;
+/// type AskTyCon = <Ask<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+/// type OptionTyCon = <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+/// type Program<A> = FreeT<'static, AskTyCon, OptionTyCon, A>;
+///
+/// fn ask() -> Program<i32> {
+///     FreeT::lift_f(Box::new(|x| x) as Ask<i32>)
+/// }
+/// fn checked(x: i32) -> Program<i32> {
+///     Program::lift((x >= 0).then_some(x))
+/// }
+///
+/// let program = ask().bind(checked).bind(|a| ask().bind(move |b| {
+///     checked(a + b)
+/// }));
+///
+/// let mut inputs = vec![5, 2].into_iter();
+/// let result = fold_free_t(program, move |layer: Ask<_>| {
+///     Some(layer(inputs.next().unwrap()))
+/// });
+/// assert_eq!(result, Some(7));
+///
+/// let failing = ask().bind(checked).bind(|_| ask());
+/// let result = fold_free_t(failing, |layer: Ask<_>| Some(layer(-1)));
+/// assert_eq!(result, None);
+/// ```
+pub struct FreeT<'a, T, N, A>(FreeTBase<'a, T, N, A>)
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a;
+
+impl<'a, T, N, A> FreeT<'a, T, N, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    /// Create computation from a base monad wrapping a [`FreeStep`]
+    pub fn new(base: FreeTBase<'a, T, N, A>) -> Self {
+        FreeT(base)
+    }
+
+    /// Return base monad wrapping the pure value or the outermost layer of
+    /// the functor
+    pub fn resume(self) -> FreeTBase<'a, T, N, A> {
+        self.0
+    }
+
+    /// Lift a functor into the free monad transformer
+    pub fn lift_f(functor: T::Functor<A, A>) -> Self
+    where
+        FreeTBase<'a, T, N, A>: Pure<'a, FreeStep<'a, T, N, A>>,
+    {
+        FreeT::wrap(
+            functor.change_functor_target::<FreeT<'a, T, N, A>>().fmap(
+                |a| {
+                    FreeT(<FreeTBase<'a, T, N, A>>::pure(
+                        FreeStep::Pure(a),
+                    ))
+                },
+            ),
+        )
+    }
+
+    /// Wrap a layer of the functor
+    pub fn wrap(functor: FreeTLayer<'a, T, N, A>) -> Self
+    where
+        FreeTBase<'a, T, N, A>: Pure<'a, FreeStep<'a, T, N, A>>,
+    {
+        FreeT(<FreeTBase<'a, T, N, A>>::pure(FreeStep::Roll(Box::new(
+            functor,
+        ))))
+    }
+
+    /// Run computation by repeatedly evaluating the base monad with `eval`
+    /// and applying a step function to the outermost layer of the functor
+    ///
+    /// Evaluation is done in a loop and thus doesn't consume stack space
+    /// proportional to the number of steps.
+    pub fn run<E, G>(self, mut eval: E, mut step: G) -> A
+    where
+        E: FnMut(FreeTBase<'a, T, N, A>) -> FreeStep<'a, T, N, A>,
+        G: FnMut(FreeTLayer<'a, T, N, A>) -> Self,
+    {
+        let mut this = self;
+        loop {
+            match eval(this.0) {
+                FreeStep::Pure(a) => return a,
+                FreeStep::Roll(functor) => this = step(*functor),
+            }
+        }
+    }
+
+    /// Apply a natural transformation to the base monad of every step
+    ///
+    /// The base monad is changed from type constructor `N` to `M`.
+    pub fn hoist<M, G>(self, nt: G) -> FreeT<'a, T, M, A>
+    where
+        M: 'a + UniversalFunctorTyCon<'a>,
+        G: 'a
+            + Send
+            + FnMut(
+                N::Functor<FreeStep<'a, T, M, A>, FreeStep<'a, T, M, A>>,
+            ) -> FreeTBase<'a, T, M, A>,
+    {
+        self.hoist_shared(Arc::new(Mutex::new(nt)))
+    }
+
+    fn fmap_shared<B, F>(self, f: Arc<Mutex<F>>) -> FreeT<'a, T, N, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        FreeT(
+            self.0
+                .change_functor_target::<FreeStep<'a, T, N, B>>()
+                .fmap(move |step| match step {
+                    FreeStep::Pure(a) => {
+                        FreeStep::Pure((f.lock().unwrap())(a))
+                    }
+                    FreeStep::Roll(functor) => {
+                        let f = f.clone();
+                        FreeStep::Roll(Box::new(
+                            (*functor)
+                                .change_functor_target::<FreeT<'a, T, N, B>>()
+                                .fmap(move |free| free.fmap_shared(f.clone())),
+                        ))
+                    }
+                }),
+        )
+    }
+
+    fn bind_shared<B, F>(self, f: Arc<Mutex<F>>) -> FreeT<'a, T, N, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnMut(A) -> FreeT<'a, T, N, B>,
+        N::Functor<FreeStep<'a, T, N, A>, FreeStep<'a, T, N, B>>:
+            Monad<'a, FreeStep<'a, T, N, B>>,
+    {
+        FreeT(
+            self.0
+                .change_functor_target::<FreeStep<'a, T, N, B>>()
+                .bind(move |step| match step {
+                    FreeStep::Pure(a) => {
+                        let next = (f.lock().unwrap())(a);
+                        next.0
+                    }
+                    FreeStep::Roll(functor) => {
+                        let f = f.clone();
+                        <N::Functor<
+                            FreeStep<'a, T, N, A>,
+                            FreeStep<'a, T, N, B>,
+                        >>::pure(FreeStep::Roll(Box::new(
+                            (*functor)
+                                .change_functor_target::<FreeT<'a, T, N, B>>()
+                                .fmap(move |free| free.bind_shared(f.clone())),
+                        )))
+                    }
+                }),
+        )
+    }
+
+    fn hoist_shared<M, G>(self, nt: Arc<Mutex<G>>) -> FreeT<'a, T, M, A>
+    where
+        M: 'a + UniversalFunctorTyCon<'a>,
+        G: 'a
+            + Send
+            + FnMut(
+                N::Functor<FreeStep<'a, T, M, A>, FreeStep<'a, T, M, A>>,
+            ) -> FreeTBase<'a, T, M, A>,
+    {
+        let nt_inner = nt.clone();
+        let base = self
+            .0
+            .change_functor_target::<FreeStep<'a, T, M, A>>()
+            .fmap(move |step| match step {
+                FreeStep::Pure(a) => FreeStep::Pure(a),
+                FreeStep::Roll(functor) => {
+                    let nt = nt_inner.clone();
+                    FreeStep::Roll(Box::new(
+                        (*functor)
+                            .change_functor_target::<FreeT<'a, T, M, A>>()
+                            .fmap(move |free| free.hoist_shared(nt.clone())),
+                    ))
+                }
+            });
+        let mut nt = nt.lock().unwrap();
+        FreeT(nt(base))
+    }
+}
+
+impl<'a, T, N, A, B> Functor<'a, B> for FreeT<'a, T, N, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = FreeT<'a, T, N, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.fmap_shared(Arc::new(Mutex::new(f)))
+    }
+}
+
+impl<'a, T, N, A, B> Pure<'a, B> for FreeT<'a, T, N, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    N::Functor<FreeStep<'a, T, N, A>, FreeStep<'a, T, N, B>>:
+        Pure<'a, FreeStep<'a, T, N, B>>,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        FreeT(<N::Functor<
+            FreeStep<'a, T, N, A>,
+            FreeStep<'a, T, N, B>,
+        >>::pure(FreeStep::Pure(b)))
+    }
+}
+
+impl<'a, T, N, A, B> Monad<'a, B> for FreeT<'a, T, N, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    N::Functor<FreeStep<'a, T, N, A>, FreeStep<'a, T, N, B>>:
+        Monad<'a, FreeStep<'a, T, N, B>>,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.bind_shared(Arc::new(Mutex::new(f)))
+    }
+}
+
+impl<'a, T, N, A> MonadTrans<'a, N::Functor<A, FreeStep<'a, T, N, A>>>
+    for FreeT<'a, T, N, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    A: 'a,
+{
+    fn lift(base: N::Functor<A, FreeStep<'a, T, N, A>>) -> Self {
+        FreeT(base.fmap(FreeStep::Pure))
+    }
+}
+
+/// Interpret a [`FreeT`] in its base monad
+///
+/// Each layer of the functor is converted into the base monad using the
+/// interpreter `nt`. The results are joined using [`Monad::bind`].
+///
+/// *Note:* Stack usage depends on the base monad. For monads that are
+/// evaluated eagerly (e.g. [`Option`]), stack usage is proportional to the
+/// number of layers. Use [`FreeT::run`] for a stack-safe interpreter.
+pub fn fold_free_t<'a, T, N, A, G>(
+    free: FreeT<'a, T, N, A>,
+    nt: G,
+) -> N::Functor<A, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    N::Functor<FreeStep<'a, T, N, A>, A>: Monad<'a, A>,
+    N::Functor<FreeT<'a, T, N, A>, A>: Monad<'a, A>,
+    A: 'a,
+    G: 'a
+        + Send
+        + FnMut(
+            FreeTLayer<'a, T, N, A>,
+        )
+            -> N::Functor<FreeT<'a, T, N, A>, FreeT<'a, T, N, A>>,
+{
+    fold_free_t_shared(free, Arc::new(Mutex::new(nt)))
+}
+
+fn fold_free_t_shared<'a, T, N, A, G>(
+    free: FreeT<'a, T, N, A>,
+    nt: Arc<Mutex<G>>,
+) -> N::Functor<A, A>
+where
+    T: 'a + UniversalFunctorTyCon<'a>,
+    N: 'a + UniversalFunctorTyCon<'a>,
+    N::Functor<FreeStep<'a, T, N, A>, A>: Monad<'a, A>,
+    N::Functor<FreeT<'a, T, N, A>, A>: Monad<'a, A>,
+    A: 'a,
+    G: 'a
+        + Send
+        + FnMut(
+            FreeTLayer<'a, T, N, A>,
+        )
+            -> N::Functor<FreeT<'a, T, N, A>, FreeT<'a, T, N, A>>,
+{
+    free.0
+        .change_functor_target::<A>()
+        .bind(move |step| match step {
+            FreeStep::Pure(a) => {
+                <N::Functor<FreeStep<'a, T, N, A>, A>>::pure(a)
+            }
+            FreeStep::Roll(functor) => {
+                let layer = (nt.lock().unwrap())(*functor);
+                let nt = nt.clone();
+                layer.change_functor_target::<A>().bind(move |free| {
+                    fold_free_t_shared(free, nt.clone())
+                })
+            }
+        })
+}
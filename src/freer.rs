@@ -0,0 +1,228 @@
+//! Freer monad
+//!
+//! See [`Freer`].
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Freer (operational) monad over an instruction set
+///
+/// Unlike [`Free`], the instruction set `I` does not need to be a
+/// [`Functor`]. A `Freer<'a, I, R, A>` is either a [pure] value or an
+/// [instruction] along with a continuation that receives the response of
+/// type `R` to that instruction and returns the rest of the computation.
+///
+/// Programs can be interpreted with [`Freer::run`] (which answers each
+/// instruction directly) or with [`interpret`] (which translates each
+/// instruction into a target [`Monad`]).
+///
+/// [`Free`]: free::Free
+/// [pure]: Freer::Pure
+/// [instruction]: Freer::Impure
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Monad;
+/// use fmap::freer::{interpret, Freer};
+/// use std::collections::HashMap;
+///
+/// // Instruction set of a key-value store, where each instruction
+/// // responds with the (previously) stored value
+/// enum Op {
+///     Get(String),
+///     Put(String, i32),
+/// }
+///
+/// type Store<A> = Freer<'static, Op, Option<i32>, A>;
+///
+/// fn get(key: &str) -> Store<Option<i32>> {
+///     Freer::lift(Op::Get(key.to_string()))
+/// }
+/// fn put(key: &str, value: i32) -> Store<Option<i32>> {
+///     Freer::lift(Op::Put(key.to_string(), value))
+/// }
+///
+/// let program = || {
+///     put("x", 20)
+///         .bind(|_| get("x"))
+///         .bind(|x| put("y", x.unwrap() + 1))
+///         .bind(|_| get("y"))
+/// };
+///
+/// let mut map = HashMap::new();
+/// let result = program().run(|op| match op {
+///     Op::Get(key) => map.get(&key).copied(),
+///     Op::Put(key, value) => map.insert(key, value),
+/// });
+/// assert_eq!(result, Some(21));
+///
+/// // read-only interpreter that fails on write access
+/// let result: Option<Option<i32>> = interpret(program(), |op| match op {
+///     Op::Get(_) => Some(None),
+///     Op::Put(_, _) => None,
+/// });
+/// assert_eq!(result, None);
+/// ```
+pub enum Freer<'a, I, R, A> {
+    /// Pure value
+    Pure(A),
+    /// Instruction and continuation receiving the response
+    Impure(I, Box<dyn 'a + Send + FnMut(R) -> Freer<'a, I, R, A>>),
+}
+
+impl<'a, I, R, A> Freer<'a, I, R, A>
+where
+    I: 'a,
+    R: 'a,
+    A: 'a,
+{
+    /// Return the pure value or the next instruction and its continuation
+    #[allow(clippy::type_complexity)]
+    pub fn resume(
+        self,
+    ) -> Result<A, (I, Box<dyn 'a + Send + FnMut(R) -> Self>)> {
+        match self {
+            Freer::Pure(a) => Ok(a),
+            Freer::Impure(instruction, cont) => {
+                Err((instruction, cont))
+            }
+        }
+    }
+
+    /// Run computation by answering each instruction with a response
+    ///
+    /// Evaluation is done in a loop and thus doesn't consume stack space
+    /// proportional to the number of instructions.
+    pub fn run<G>(self, mut step: G) -> A
+    where
+        G: FnMut(I) -> R,
+    {
+        let mut this = self;
+        loop {
+            match this {
+                Freer::Pure(a) => return a,
+                Freer::Impure(instruction, mut cont) => {
+                    this = cont(step(instruction))
+                }
+            }
+        }
+    }
+
+    fn bind_shared<B, F>(self, f: Arc<Mutex<F>>) -> Freer<'a, I, R, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnMut(A) -> Freer<'a, I, R, B>,
+    {
+        match self {
+            Freer::Pure(a) => (f.lock().unwrap())(a),
+            Freer::Impure(instruction, mut cont) => Freer::Impure(
+                instruction,
+                Box::new(move |r| cont(r).bind_shared(f.clone())),
+            ),
+        }
+    }
+}
+
+impl<'a, I, R> Freer<'a, I, R, R>
+where
+    I: 'a,
+    R: 'a,
+{
+    /// Lift a single instruction, returning its response
+    pub fn lift(instruction: I) -> Self {
+        Freer::Impure(instruction, Box::new(Freer::Pure))
+    }
+}
+
+impl<'a, I, R, A, B> Functor<'a, B> for Freer<'a, I, R, A>
+where
+    I: 'a,
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Freer<'a, I, R, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.bind_shared(Arc::new(Mutex::new(move |a| {
+            Freer::Pure(f(a))
+        })))
+    }
+}
+
+impl<'a, I, R, A, B> Pure<'a, B> for Freer<'a, I, R, A>
+where
+    I: 'a,
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Freer::Pure(b)
+    }
+}
+
+impl<'a, I, R, A, B> Monad<'a, B> for Freer<'a, I, R, A>
+where
+    I: 'a,
+    R: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.bind_shared(Arc::new(Mutex::new(f)))
+    }
+}
+
+/// Interpret a [`Freer`] monad in a target [`Monad`] `M`
+///
+/// Each instruction is converted into the target monad (with the response
+/// as [inner type]) using the interpreter `nt`, i.e. a natural transformation
+/// from the instruction set to `M`. The responses are then passed to the
+/// continuations using [`Monad::bind`].
+///
+/// *Note:* Stack usage depends on the target monad (see [`fold_free`]).
+///
+/// [inner type]: Functor::Inner
+/// [`fold_free`]: free::fold_free
+pub fn interpret<'a, I, R, A, M, N>(
+    freer: Freer<'a, I, R, A>,
+    nt: N,
+) -> <M as Functor<'a, A>>::Mapped
+where
+    I: 'a,
+    R: 'a,
+    A: 'a,
+    M: Monad<'a, A, Inner = R>,
+    N: 'a + Send + FnMut(I) -> M,
+{
+    interpret_shared(freer, Arc::new(Mutex::new(nt)))
+}
+
+fn interpret_shared<'a, I, R, A, M, N>(
+    freer: Freer<'a, I, R, A>,
+    nt: Arc<Mutex<N>>,
+) -> <M as Functor<'a, A>>::Mapped
+where
+    I: 'a,
+    R: 'a,
+    A: 'a,
+    M: Monad<'a, A, Inner = R>,
+    N: 'a + Send + FnMut(I) -> M,
+{
+    match freer {
+        Freer::Pure(a) => M::pure(a),
+        Freer::Impure(instruction, mut cont) => {
+            let m = (nt.lock().unwrap())(instruction);
+            m.bind(move |r| interpret_shared(cont(r), nt.clone()))
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Unboxed futures
+//!
+//! See [`Fut`].
+
+#[cfg(doc)]
+use super::*;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// Wrapper for a [`Future`] that is mapped without boxing
+///
+/// Pinned boxed futures implement [`Functor`] and [`Monad`], but each call
+/// of [`fmap`](Functor::fmap) or [`bind`](Monad::bind) allocates a new
+/// box. The methods [`Fut::fmap`] and [`Fut::bind`] instead return the
+/// concrete future types [`Map`] and [`Then`], such that no allocation is
+/// needed.
+///
+/// *Note:* `Fut` doesn't implement the [`Functor`] trait, because the
+/// [mapped type] of a `Functor` can't depend on the type of the closure.
+/// Use [`Fut::boxed`] to convert into a boxed future when generic code
+/// requires a `Functor`.
+///
+/// In order to be polled, the wrapped future (as well as the futures and
+/// closures passed to `fmap` and `bind`) must be [`Unpin`]. Futures that
+/// are not `Unpin` (e.g. `async` blocks) can be pinned with
+/// [`std::pin::pin!`] or [`Box::pin`] once, before building the pipeline.
+///
+/// [mapped type]: Functor::Mapped
+///
+/// # Examples
+///
+/// ```
+/// use fmap::fut::Fut;
+/// use futures::executor::block_on;
+/// use std::future::ready;
+/// use std::pin::pin;
+///
+/// let start = pin!(async { 4 });
+/// let pipeline = Fut(start)
+///     .fmap(|x| x * 10)
+///     .bind(|x| ready(x + 2))
+///     .fmap(|x| x.to_string());
+/// assert_eq!(block_on(pipeline), "42");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Fut<F>(pub F);
+
+impl<F> Fut<F>
+where
+    F: Future,
+{
+    /// Map output, returning a [`Map`] future
+    pub fn fmap<B, G>(self, f: G) -> Fut<Map<F, G>>
+    where
+        G: FnOnce(F::Output) -> B,
+    {
+        Fut(Map {
+            future: self.0,
+            f: Some(f),
+        })
+    }
+
+    /// Map output to another future and await it, returning a [`Then`]
+    /// future
+    pub fn bind<N, G>(self, f: G) -> Fut<Then<F, N, G>>
+    where
+        N: Future,
+        G: FnOnce(F::Output) -> N,
+    {
+        Fut(Then {
+            state: ThenState::First(self.0, Some(f)),
+        })
+    }
+
+    /// Unwrap future
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+
+    /// Convert into a pinned boxed future, which implements [`Functor`] and
+    /// [`Monad`]
+    pub fn boxed<'a>(
+        self,
+    ) -> Pin<Box<dyn 'a + Future<Output = F::Output>>>
+    where
+        F: 'a,
+    {
+        Box::pin(self.0)
+    }
+}
+
+impl<F> Future for Fut<F>
+where
+    F: Future + Unpin,
+{
+    type Output = F::Output;
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+/// Future returned by [`Fut::fmap`]
+#[derive(Debug)]
+pub struct Map<F, G> {
+    future: F,
+    f: Option<G>,
+}
+
+impl<F, G, B> Future for Map<F, G>
+where
+    F: Future + Unpin,
+    G: FnOnce(F::Output) -> B + Unpin,
+{
+    type Output = B;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+        let this = self.get_mut();
+        let a = ready!(Pin::new(&mut this.future).poll(cx));
+        let f = this.f.take().expect("polled after completion");
+        Poll::Ready(f(a))
+    }
+}
+
+/// Future returned by [`Fut::bind`]
+#[derive(Debug)]
+pub struct Then<F, N, G> {
+    state: ThenState<F, N, G>,
+}
+
+#[derive(Debug)]
+enum ThenState<F, N, G> {
+    First(F, Option<G>),
+    Second(N),
+}
+
+impl<F, N, G> Future for Then<F, N, G>
+where
+    F: Future + Unpin,
+    N: Future + Unpin,
+    G: FnOnce(F::Output) -> N + Unpin,
+{
+    type Output = N::Output;
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<N::Output> {
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                ThenState::First(future, f) => {
+                    let a = ready!(Pin::new(future).poll(cx));
+                    let f = f.take().expect("polled after completion");
+                    this.state = ThenState::Second(f(a));
+                }
+                ThenState::Second(next) => {
+                    return Pin::new(next).poll(cx);
+                }
+            }
+        }
+    }
+}
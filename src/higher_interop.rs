@@ -0,0 +1,106 @@
+//! Interoperability with the `higher` crate (requires feature `higher`)
+//!
+//! See [`Higher`].
+
+use super::*;
+
+use std::cell::RefCell;
+
+/// Wrapper that provides [`Functor`], [`Pure`], and [`Monad`] for types
+/// implementing the corresponding traits of the `higher` crate
+///
+/// The type constructor is given by a witness type `W`, such that
+/// `<W as higher::Functor<'a, ()>>::Target<A>` is the wrapped type with
+/// [inner type] `A` (e.g. `W = Option<()>` to wrap an `Option<A>`).
+/// Mapping functions and continuations are passed to `higher` as [`Fn`]
+/// closures that call the [`FnMut`] closures expected by this crate.
+///
+/// Only this direction of conversion is provided: Implementing `higher`'s
+/// traits for this crate's functors is not possible, because `higher` passes
+/// closures which are neither [`Send`] nor guaranteed to outlive `'a`,
+/// while [`Functor::fmap`] requires both.
+///
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::higher_interop::Higher;
+/// use fmap::{Functor, Monad};
+///
+/// type HigherVec<A> = Higher<'static, Vec<()>, A>;
+///
+/// let wrapped: HigherVec<i32> = Higher(vec![1, 2]);
+/// let mapped = wrapped
+///     .fmap(|x| x * 10)
+///     .bind(|x| Higher(vec![x, x + 1]));
+/// assert_eq!(mapped.0, vec![10, 11, 20, 21]);
+/// ```
+pub struct Higher<'a, W, A>(
+    /// Wrapped value
+    pub <W as ::higher::Functor<'a, ()>>::Target<A>,
+)
+where
+    W: ::higher::Functor<'a, ()>;
+
+impl<'a, W, A, B> Functor<'a, B> for Higher<'a, W, A>
+where
+    W: ::higher::Functor<'a, ()>,
+    A: 'a,
+    B: 'a,
+    W::Target<A>: ::higher::Functor<'a, A, Target<A> = W::Target<A>>
+        + ::higher::Functor<'a, A, Target<B> = W::Target<B>>,
+    W::Target<B>: ::higher::Functor<'a, B, Target<A> = W::Target<A>>
+        + ::higher::Functor<'a, B, Target<B> = W::Target<B>>,
+{
+    type Inner = A;
+    type Mapped = Higher<'a, W, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let f = RefCell::new(f);
+        Higher(::higher::Functor::fmap(self.0, move |a| {
+            (f.borrow_mut())(a)
+        }))
+    }
+}
+
+impl<'a, W, A, B> Pure<'a, B> for Higher<'a, W, A>
+where
+    W: ::higher::Functor<'a, ()>,
+    A: 'a,
+    B: 'a,
+    W::Target<A>: ::higher::Functor<'a, A, Target<A> = W::Target<A>>
+        + ::higher::Functor<'a, A, Target<B> = W::Target<B>>,
+    W::Target<B>: ::higher::Functor<'a, B, Target<A> = W::Target<A>>
+        + ::higher::Functor<'a, B, Target<B> = W::Target<B>>
+        + ::higher::Pure<B>,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Higher(::higher::Pure::pure(b))
+    }
+}
+
+impl<'a, W, A, B> Monad<'a, B> for Higher<'a, W, A>
+where
+    W: ::higher::Functor<'a, ()>,
+    A: 'a,
+    B: 'a,
+    W::Target<A>: ::higher::Functor<'a, A, Target<A> = W::Target<A>>
+        + ::higher::Functor<'a, A, Target<B> = W::Target<B>>
+        + ::higher::Bind<'a, A, Target<B> = W::Target<B>>,
+    W::Target<B>: ::higher::Functor<'a, B, Target<A> = W::Target<A>>
+        + ::higher::Functor<'a, B, Target<B> = W::Target<B>>
+        + ::higher::Pure<B>,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let f = RefCell::new(f);
+        Higher(::higher::Bind::bind::<B, _>(self.0, move |a| {
+            (f.borrow_mut())(a).0
+        }))
+    }
+}
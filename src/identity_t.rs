@@ -0,0 +1,212 @@
+//! Identity monad transformer
+//!
+//! See [`IdentityT`].
+
+use super::*;
+
+use std::marker::PhantomData;
+
+/// Monad transformer that doesn't add any effect to the base monad
+///
+/// An `IdentityT<M, A>` wraps a base monad `M` with [inner type] `A` and
+/// behaves exactly like `M`. It can be used as the innermost layer of
+/// generic transformer stacks, or to test code that is generic over
+/// [`MonadTrans`] without introducing real effects.
+///
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadTrans};
+/// use fmap::identity_t::IdentityT;
+///
+/// let lifted: IdentityT<Vec<i32>, i32> = IdentityT::lift(vec![1, 2]);
+/// let doubled = lifted.bind(|x| IdentityT::new(vec![x, x * 10]));
+/// assert_eq!(doubled.run(), vec![1, 10, 2, 20]);
+/// ```
+pub struct IdentityT<M, A> {
+    inner: M,
+    phantom: PhantomData<fn() -> A>,
+}
+
+impl<M, A> IdentityT<M, A> {
+    /// Wrap base monad
+    pub fn new(inner: M) -> Self {
+        IdentityT {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unwrap base monad
+    pub fn run(self) -> M {
+        self.inner
+    }
+
+    /// Modify the wrapped base monad with a closure
+    pub fn map_t<N, B, F>(self, f: F) -> IdentityT<N, B>
+    where
+        F: FnOnce(M) -> N,
+    {
+        IdentityT::new(f(self.inner))
+    }
+
+    /// Apply a natural transformation to the base monad
+    ///
+    /// Unlike [`map_t`](Self::map_t), the result type `A` is retained.
+    pub fn hoist<N, F>(self, nt: F) -> IdentityT<N, A>
+    where
+        F: FnOnce(M) -> N,
+    {
+        IdentityT::new(nt(self.inner))
+    }
+}
+
+impl<'a, M, A, B> Functor<'a, B> for IdentityT<M, A>
+where
+    M: Functor<'a, B, Inner = A>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = IdentityT<M::Mapped, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        IdentityT::new(self.inner.fmap(f))
+    }
+}
+
+impl<'a, M, A, B> Pure<'a, B> for IdentityT<M, A>
+where
+    M: Pure<'a, B, Inner = A>,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        IdentityT::new(M::pure(b))
+    }
+}
+
+impl<'a, M, A, B> Monad<'a, B> for IdentityT<M, A>
+where
+    M: Monad<'a, B, Inner = A>,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        IdentityT::new(self.inner.bind(move |a| f(a).inner))
+    }
+}
+
+impl<'a, M, A, B> Applicative<'a, B> for IdentityT<M, A>
+where
+    M: Applicative<'a, B>,
+    M: Pure<'a, B, Inner = A>,
+    M: Pure<'a, BoxMapper<'a, Self, B>, Inner = A>,
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: IdentityT<
+            <M as Functor<'a, BoxMapper<'a, Self, B>>>::Mapped,
+            BoxMapper<'a, Self, B>,
+        >,
+    ) -> IdentityT<<M as Functor<'a, B>>::Mapped, B> {
+        IdentityT::new(self.inner.apply(f.inner))
+    }
+}
+
+impl<'a, M, A> MonadTrans<'a, M> for IdentityT<M, A> {
+    fn lift(base: M) -> Self {
+        IdentityT::new(base)
+    }
+}
+
+impl<'a, S, M, A> MonadState<'a, S> for IdentityT<M, A>
+where
+    M: MonadState<'a, S, Value = A>,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        IdentityT::new(M::state(f))
+    }
+}
+
+impl<'a, R, M, A> MonadReader<'a, R> for IdentityT<M, A>
+where
+    M: MonadReader<'a, R, Value = A>,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        IdentityT::new(M::reader(f))
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        IdentityT::new(self.inner.local(f))
+    }
+}
+
+impl<'a, W, M, A> MonadWriter<'a, W> for IdentityT<M, A>
+where
+    M: MonadWriter<'a, W, Value = A>,
+{
+    type Value = A;
+    type Listened = IdentityT<M::Listened, (A, W)>;
+    fn writer(value: A, log: W) -> Self {
+        IdentityT::new(M::writer(value, log))
+    }
+    fn listen(self) -> Self::Listened {
+        IdentityT::new(self.inner.listen())
+    }
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        IdentityT::new(self.inner.censor(f))
+    }
+}
+
+impl<'a, M, A> MonadCont<'a> for IdentityT<M, A>
+where
+    M: MonadCont<'a, Value = A>,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        IdentityT::new(M::call_cc(move |escape| f(escape).inner))
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        IdentityT::new(M::escape(escape, value))
+    }
+}
+
+impl<'a, M, A> MonadIo<'a> for IdentityT<M, A>
+where
+    M: MonadIo<'a, Value = A>,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        IdentityT::new(M::lift_io(thunk))
+    }
+}
@@ -0,0 +1,51 @@
+//! Implementations for arrays `[A; N]`
+
+use super::*;
+
+impl<'a, A, B, const N: usize> Functor<'a, B> for [A; N]
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = [B; N];
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map(f)
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, const N: usize> FunctorMut<'a, A> for [A; N]
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+// Arrays can't implement `Applicative`, because boxed mappers aren't
+// `Clone` and thus don't satisfy the bound of `Pure` (see `zip_apply`).
+impl<'a, A, B, const N: usize> Pure<'a, B> for [A; N]
+where
+    A: 'a,
+    B: 'a + Clone,
+{
+    fn pure(b: B) -> Self::Mapped {
+        std::array::from_fn(|_| b.clone())
+    }
+}
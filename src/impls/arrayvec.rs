@@ -0,0 +1,77 @@
+//! Implementations for [`ArrayVec`] of the [`arrayvec`](::arrayvec) crate
+//! (requires feature `arrayvec`)
+//!
+//! The capacity `CAP` is retained when mapping. If the result of
+//! [`Monad::bind`] (or [`Pure::pure`] with a capacity of zero) exceeds the
+//! capacity, the operation panics, analogously to [`ArrayVec::push`].
+
+use super::*;
+
+use ::arrayvec::ArrayVec;
+
+impl<'a, A, B, const CAP: usize> Functor<'a, B> for ArrayVec<A, CAP>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = ArrayVec<B, CAP>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, const CAP: usize> FunctorMut<'a, A> for ArrayVec<A, CAP>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B, const CAP: usize> Pure<'a, B> for ArrayVec<A, CAP>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut vec = ArrayVec::new();
+        vec.push(b);
+        vec
+    }
+}
+
+impl<'a, A, B, const CAP: usize> Monad<'a, B> for ArrayVec<A, CAP>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut vec = ArrayVec::new();
+        for item in self.into_iter() {
+            for b in f(item) {
+                vec.push(b);
+            }
+        }
+        vec
+    }
+}
@@ -0,0 +1,83 @@
+//! Implementations for [`Bound`] and pairs of bounds
+//!
+//! A pair `(Bound<A>, Bound<A>)` (as used by [`RangeBounds`] and by range
+//! queries of [`BTreeMap`](std::collections::BTreeMap)) is mapped by
+//! mapping both bounds.
+//!
+//! [`RangeBounds`]: std::ops::RangeBounds
+
+use super::*;
+
+use std::ops::Bound;
+
+impl<'a, A, B> Functor<'a, B> for Bound<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Bound<B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map(f)
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Bound<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        if let Bound::Included(inner) | Bound::Excluded(inner) = self {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for (Bound<A>, Bound<A>)
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = (Bound<B>, Bound<B>);
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let (start, end) = self;
+        (start.map(&mut f), end.map(f))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for (Bound<A>, Bound<A>)
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.0.fmap_mut(&mut f);
+        self.1.fmap_mut(f);
+    }
+}
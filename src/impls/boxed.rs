@@ -0,0 +1,71 @@
+//! Implementations for [`Box`]
+
+use super::*;
+
+impl<'a, A, B> Functor<'a, B> for Box<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Box<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Box::new(f(*self))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Box<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        f(self)
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Box<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Box::new(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Box<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        f(*self)
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Box<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(self, f: Box<BoxMapper<'a, Self, B>>) -> Box<B> {
+        let mut mapper = *f;
+        Box::new(mapper(*self))
+    }
+}
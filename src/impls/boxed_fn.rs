@@ -294,6 +294,97 @@ where
     }
 }
 
+impl<'a, A, B> Monad<'a, B> for Box<dyn 'a + FnOnce() -> A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::new(move || (f((self)()))())
+    }
+}
+impl<'a, A, B> Monad<'a, B> for Box<dyn 'a + Send + FnOnce() -> A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::new(move || (f((self)()))())
+    }
+}
+
+impl<'a, A, B, X> Monad<'a, B> for Box<dyn 'a + FnOnce(X) -> A>
+where
+    A: 'a,
+    B: 'a,
+    X: 'a + Clone,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::new(move |x: X| {
+            let next = f((self)(x.clone()));
+            next(x)
+        })
+    }
+}
+impl<'a, A, B, X> Monad<'a, B> for Box<dyn 'a + Send + FnOnce(X) -> A>
+where
+    A: 'a,
+    B: 'a + Send,
+    X: 'a + Clone,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::new(move |x: X| {
+            let next = f((self)(x.clone()));
+            next(x)
+        })
+    }
+}
+
+impl<'a, A, B, X> Monad<'a, B> for Box<dyn 'a + FnMut(X) -> A>
+where
+    A: 'a,
+    B: 'a + Clone,
+    X: 'a + Clone,
+{
+    fn bind<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::new(move |x: X| {
+            let mut next = f((self)(x.clone()));
+            next(x)
+        })
+    }
+}
+impl<'a, A, B, X> Monad<'a, B> for Box<dyn 'a + Send + FnMut(X) -> A>
+where
+    A: 'a,
+    B: 'a + Clone + Send,
+    X: 'a + Clone,
+{
+    fn bind<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::new(move |x: X| {
+            let mut next = f((self)(x.clone()));
+            next(x)
+        })
+    }
+}
+
 impl<'a, A, B> Applicative<'a, B> for Box<dyn 'a + FnOnce() -> A>
 where
     A: 'a,
@@ -346,3 +437,62 @@ where
         Box::new(move |x| (f(x.clone()))((self)(x)))
     }
 }
+
+impl<'a, A> MonadIo<'a> for Box<dyn 'a + FnOnce() -> A> {
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Box::new(thunk)
+    }
+}
+impl<'a, A> MonadIo<'a> for Box<dyn 'a + Send + FnOnce() -> A> {
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Box::new(thunk)
+    }
+}
+
+impl<'a, R, A> MonadReader<'a, R> for Box<dyn 'a + FnOnce(R) -> A>
+where
+    R: 'a,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        Box::new(f)
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        Box::new(move |r| (self)(f(r)))
+    }
+}
+impl<'a, R, A> MonadReader<'a, R>
+    for Box<dyn 'a + Send + FnOnce(R) -> A>
+where
+    R: 'a,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        Box::new(f)
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        Box::new(move |r| (self)(f(r)))
+    }
+}
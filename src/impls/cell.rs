@@ -0,0 +1,50 @@
+//! Implementations for [`Cell`] and [`RefCell`]
+//!
+//! [`FunctorMut::fmap_mut`] has exclusive access to the cell, so it
+//! modifies the contained value through [`Cell::get_mut`] and
+//! [`RefCell::get_mut`] without any runtime borrow checks.
+
+use super::*;
+
+use std::cell::{Cell, RefCell};
+
+macro_rules! impl_cell {
+    ($cell:ident) => {
+        impl<'a, A, B> Functor<'a, B> for $cell<A>
+        where
+            A: 'a,
+            B: 'a,
+        {
+            type Inner = A;
+            type Mapped = $cell<B>;
+            fn fmap<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> B,
+            {
+                $cell::new(f(self.into_inner()))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, A> FunctorMut<'a, A> for $cell<A>
+        where
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                f(self.get_mut())
+            }
+        }
+    };
+}
+
+impl_cell!(Cell);
+impl_cell!(RefCell);
@@ -1,4 +1,10 @@
 //! Implementations for types in [`std::collections`]
+//!
+//! [`HashSet`], [`BTreeSet`], and [`BinaryHeap`] require the mapped type to
+//! implement [`Eq`] and [`Hash`] or [`Ord`], respectively. For sets,
+//! [`Functor::fmap`] keeps only one of several elements that are mapped to
+//! equal values, so the result may be smaller than the original set, and
+//! [`Monad::bind`] returns the union of all sets returned by the closure.
 
 use super::*;
 
@@ -91,6 +97,16 @@ where
     }
 }
 
+impl<A> Alternative for VecDeque<A> {
+    fn empty() -> Self {
+        VecDeque::new()
+    }
+    fn or(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
 impl<'a, A, B> Functor<'a, B> for LinkedList<A>
 where
     A: 'a,
@@ -177,6 +193,16 @@ where
     }
 }
 
+impl<A> Alternative for LinkedList<A> {
+    fn empty() -> Self {
+        LinkedList::new()
+    }
+    fn or(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
 impl<'a, K, A, B> Functor<'a, B> for HashMap<K, A>
 where
     K: Eq + Hash,
@@ -215,6 +241,26 @@ where
     }
 }
 
+impl<'a, K, A, B> FunctorWithKey<'a, B> for HashMap<K, A>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        self.into_iter()
+            .map(|(k, v)| {
+                let b = f(&k, v);
+                (k, b)
+            })
+            .collect()
+    }
+}
+
 impl<'a, K, A, B> Functor<'a, B> for BTreeMap<K, A>
 where
     K: Ord,
@@ -253,6 +299,26 @@ where
     }
 }
 
+impl<'a, K, A, B> FunctorWithKey<'a, B> for BTreeMap<K, A>
+where
+    K: Ord,
+    A: 'a,
+    B: 'a,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        self.into_iter()
+            .map(|(k, v)| {
+                let b = f(&k, v);
+                (k, b)
+            })
+            .collect()
+    }
+}
+
 impl<'a, A, B> Functor<'a, B> for HashSet<A>
 where
     A: 'a + Eq + Hash,
@@ -304,9 +370,7 @@ where
     {
         let mut set = HashSet::new();
         for item in self.into_iter() {
-            for item in f(item).into_iter() {
-                set.insert(item);
-            }
+            set.extend(f(item));
         }
         set
     }
@@ -363,9 +427,7 @@ where
     {
         let mut set = BTreeSet::new();
         for item in self.into_iter() {
-            for item in f(item).into_iter() {
-                set.insert(item);
-            }
+            set.extend(f(item));
         }
         set
     }
@@ -422,9 +484,7 @@ where
     {
         let mut heap = BinaryHeap::new();
         for item in self.into_iter() {
-            for item in f(item).into_iter() {
-                heap.push(item);
-            }
+            heap.append(&mut f(item));
         }
         heap
     }
@@ -0,0 +1,105 @@
+//! Implementations for [`ControlFlow`]
+//!
+//! [`ControlFlow::Continue`] is treated like [`Ok`] and
+//! [`ControlFlow::Break`] like [`Err`], i.e. binding short-circuits on
+//! `Break`.
+
+use super::*;
+
+use std::ops::ControlFlow;
+
+impl<'a, A, B, E> Functor<'a, B> for ControlFlow<E, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = ControlFlow<E, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        match self {
+            ControlFlow::Continue(a) => ControlFlow::Continue(f(a)),
+            ControlFlow::Break(e) => ControlFlow::Break(e),
+        }
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, E> FunctorMut<'a, A> for ControlFlow<E, A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        if let ControlFlow::Continue(inner) = self {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B, E> Pure<'a, B> for ControlFlow<E, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        ControlFlow::Continue(b)
+    }
+}
+
+impl<'a, A, B, E> Monad<'a, B> for ControlFlow<E, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        match self {
+            ControlFlow::Continue(a) => f(a),
+            ControlFlow::Break(e) => ControlFlow::Break(e),
+        }
+    }
+}
+
+impl<'a, A, B, E> Applicative<'a, B> for ControlFlow<E, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: ControlFlow<E, BoxMapper<'a, Self, B>>,
+    ) -> ControlFlow<E, B> {
+        match f {
+            ControlFlow::Continue(inner) => self.fmap(inner),
+            ControlFlow::Break(e) => ControlFlow::Break(e),
+        }
+    }
+}
+
+impl<'a, A, E> MonadError<'a, E> for ControlFlow<E, A> {
+    fn throw_error(error: E) -> Self {
+        ControlFlow::Break(error)
+    }
+    fn catch_error<F>(self, mut handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(E) -> Self,
+    {
+        match self {
+            ControlFlow::Continue(a) => ControlFlow::Continue(a),
+            ControlFlow::Break(e) => handler(e),
+        }
+    }
+}
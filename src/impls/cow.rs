@@ -0,0 +1,43 @@
+//! Implementations for [`Cow`]
+//!
+//! Only `Cow<'b, A>` where `A: Clone` is supported, i.e. where the owned
+//! type is `A` itself. Borrowed values are cloned when mapped, while owned
+//! values are mapped without cloning.
+
+use super::*;
+
+use std::borrow::Cow;
+
+impl<'a, 'b, A, B> Functor<'a, B> for Cow<'b, A>
+where
+    A: 'a + 'b + Clone,
+    B: 'a + 'b + Clone,
+{
+    type Inner = A;
+    type Mapped = Cow<'b, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Cow::Owned(f(self.into_owned()))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, 'b, A> FunctorMut<'a, A> for Cow<'b, A>
+where
+    A: 'a + 'b + Clone,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        f(self.to_mut())
+    }
+}
@@ -0,0 +1,75 @@
+//! Implementations for [`DashMap`] of the [`dashmap`](::dashmap) crate
+//! (requires feature `dashmap`)
+//!
+//! A [`DashMap`] is a functor over its values and keeps its hasher when
+//! mapped. [`FunctorMut::fmap_mut`] modifies the values in place, locking
+//! one shard at a time.
+
+use super::*;
+
+use ::dashmap::DashMap;
+use std::hash::{BuildHasher, Hash};
+
+impl<'a, K, A, B, S> Functor<'a, B> for DashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+    S: BuildHasher + Clone,
+{
+    type Inner = A;
+    type Mapped = DashMap<K, B, S>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        self.fmap_with_key(move |_, v| f(v))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, K, A, S> FunctorMut<'a, A> for DashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: 'a,
+    S: BuildHasher + Clone,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for mut entry in self.iter_mut() {
+            f(entry.value_mut());
+        }
+    }
+}
+
+impl<'a, K, A, B, S> FunctorWithKey<'a, B> for DashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+    S: BuildHasher + Clone,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        let map = DashMap::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().clone(),
+        );
+        for (k, v) in self {
+            let b = f(&k, v);
+            map.insert(k, b);
+        }
+        map
+    }
+}
@@ -0,0 +1,113 @@
+//! Implementations for [`Either`] of the [`either`](::either) crate
+//! (requires feature `either`)
+//!
+//! Like [`Result`], `Either` is right-biased: [`Functor`], [`Monad`], etc.
+//! operate on the [`Right`] value, while a [`Left`] value is passed
+//! through. [`MonadError`] uses `Left` as error.
+
+use super::*;
+
+use ::either::Either::{self, Left, Right};
+
+impl<'a, L, A, B> Functor<'a, B> for Either<L, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Either<L, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map_right(f)
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, L, A> FunctorMut<'a, A> for Either<L, A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        if let Right(inner) = self {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, L, A, B> Pure<'a, B> for Either<L, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Right(b)
+    }
+}
+
+impl<'a, L, A, B> Monad<'a, B> for Either<L, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.right_and_then(f)
+    }
+}
+
+impl<'a, L, A, B> Applicative<'a, B> for Either<L, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: Either<L, BoxMapper<'a, Self, B>>,
+    ) -> Either<L, B> {
+        f.right_and_then(move |inner| self.map_right(inner))
+    }
+}
+
+impl<'a, L, A> MonadError<'a, L> for Either<L, A> {
+    fn throw_error(error: L) -> Self {
+        Left(error)
+    }
+    fn catch_error<F>(self, handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(L) -> Self,
+    {
+        self.left_and_then(handler)
+    }
+}
+
+impl<'a, L, R, C, D> Bifunctor<'a, C, D> for Either<L, R>
+where
+    L: 'a,
+    R: 'a,
+    C: 'a,
+    D: 'a,
+{
+    type Left = L;
+    type Right = R;
+    type Mapped = Either<C, D>;
+    fn bimap<F, G>(self, f: F, g: G) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(L) -> C,
+        G: 'a + Send + FnMut(R) -> D,
+    {
+        self.map_either(f, g)
+    }
+}
@@ -2,8 +2,13 @@
 
 use super::*;
 
+use io::Panic;
+
 use std::future::Future;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
 use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
 
 impl<'a, A, B> Functor<'a, B> for Pin<Box<dyn 'a + Future<Output = A>>>
 where
@@ -34,6 +39,26 @@ where
         Box::pin(async move { f(self.await) })
     }
 }
+impl<'a, A, B> Functor<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Pin<Box<dyn 'a + Future<Output = B> + Send + Sync>>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        // `Mutex` makes the closure `Sync`
+        let f = Mutex::new(f);
+        Box::pin(async move {
+            let a = self.await;
+            (f.into_inner().unwrap())(a)
+        })
+    }
+}
 
 impl<'a, A> FunctorMut<'a, A> for Pin<Box<dyn 'a + Future<Output = A>>>
 where
@@ -66,6 +91,22 @@ where
         *self = this.fmap_fn_mutref(f);
     }
 }
+impl<'a, A> FunctorMut<'a, A>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::replace(
+            self,
+            Box::pin(async move { panic!("poisoned FunctorMut") }),
+        );
+        *self = this.fmap_fn_mutref(f);
+    }
+}
 
 impl<'a, A, B> Pure<'a, B> for Pin<Box<dyn 'a + Future<Output = A>>>
 where
@@ -86,6 +127,17 @@ where
         Box::pin(std::future::ready(b))
     }
 }
+impl<'a, A, B> Pure<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let b = Mutex::new(b);
+        Box::pin(async move { b.into_inner().unwrap() })
+    }
+}
 
 impl<'a, A, B> Monad<'a, B> for Pin<Box<dyn 'a + Future<Output = A>>>
 where
@@ -98,6 +150,12 @@ where
     {
         Box::pin(async move { f(self.await).await })
     }
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        Box::pin(async move { self.await.same().await })
+    }
 }
 impl<'a, A, B> Monad<'a, B>
     for Pin<Box<dyn 'a + Future<Output = A> + Send>>
@@ -111,6 +169,36 @@ where
     {
         Box::pin(async move { f(self.await).await })
     }
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        Box::pin(async move { self.await.same().await })
+    }
+}
+impl<'a, A, B> Monad<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let f = Mutex::new(f);
+        Box::pin(async move {
+            let a = self.await;
+            let next = (f.into_inner().unwrap())(a);
+            next.await
+        })
+    }
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        Box::pin(async move { self.await.same().await })
+    }
 }
 
 impl<'a, A, B> Applicative<'a, B>
@@ -153,3 +241,203 @@ where
         })
     }
 }
+impl<'a, A, B> Applicative<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn apply(
+        self,
+        f: Pin<
+            Box<
+                dyn 'a
+                    + Future<Output = BoxMapper<'a, Self, B>>
+                    + Send
+                    + Sync,
+            >,
+        >,
+    ) -> Pin<Box<dyn 'a + Future<Output = B> + Send + Sync>> {
+        Box::pin(async move {
+            let mapper = Mutex::new(f.await);
+            let a = self.await;
+            (mapper.into_inner().unwrap())(a)
+        })
+    }
+}
+
+impl<'a, A> MonadIo<'a> for Pin<Box<dyn 'a + Future<Output = A>>> {
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Box::pin(async move { thunk() })
+    }
+}
+impl<'a, A> MonadIo<'a>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send>>
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Box::pin(async move { thunk() })
+    }
+}
+impl<'a, A> MonadIo<'a>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        let thunk = Mutex::new(thunk);
+        Box::pin(async move { (thunk.into_inner().unwrap())() })
+    }
+}
+
+/// Future that catches panics while polling the wrapped future
+struct CatchUnwind<F>(F);
+
+impl<F> Future for CatchUnwind<F>
+where
+    F: Future + Unpin,
+{
+    type Output = Result<F::Output, Panic>;
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let inner = &mut self.0;
+        match catch_unwind(AssertUnwindSafe(|| {
+            Pin::new(inner).poll(cx)
+        })) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Err(panic) => Poll::Ready(Err(panic)),
+        }
+    }
+}
+
+impl<'a, A, B> MonadBracket<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A>>>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bracket<U, R>(
+        acquire: Self,
+        body: U,
+        release: R,
+    ) -> Pin<Box<dyn 'a + Future<Output = B>>>
+    where
+        A: Clone,
+        U: 'a
+            + Send
+            + FnOnce(A) -> Pin<Box<dyn 'a + Future<Output = B>>>,
+        R: 'a
+            + Send
+            + FnOnce(A) -> Pin<Box<dyn 'a + Future<Output = ()>>>,
+    {
+        Box::pin(async move {
+            let resource = acquire.await;
+            let resource_clone = resource.clone();
+            let result = CatchUnwind(Box::pin(async move {
+                body(resource_clone).await
+            }))
+            .await;
+            release(resource).await;
+            match result {
+                Ok(b) => b,
+                Err(panic) => resume_unwind(panic),
+            }
+        })
+    }
+}
+impl<'a, A, B> MonadBracket<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send>>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn bracket<U, R>(
+        acquire: Self,
+        body: U,
+        release: R,
+    ) -> Pin<Box<dyn 'a + Future<Output = B> + Send>>
+    where
+        A: Clone,
+        U: 'a
+            + Send
+            + FnOnce(A) -> Pin<Box<dyn 'a + Future<Output = B> + Send>>,
+        R: 'a
+            + Send
+            + FnOnce(A) -> Pin<Box<dyn 'a + Future<Output = ()> + Send>>,
+    {
+        Box::pin(async move {
+            let resource = acquire.await;
+            let resource_clone = resource.clone();
+            let result = CatchUnwind(Box::pin(async move {
+                body(resource_clone).await
+            }))
+            .await;
+            release(resource).await;
+            match result {
+                Ok(b) => b,
+                Err(panic) => resume_unwind(panic),
+            }
+        })
+    }
+}
+impl<'a, A, B> MonadBracket<'a, B>
+    for Pin<Box<dyn 'a + Future<Output = A> + Send + Sync>>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn bracket<U, R>(
+        acquire: Self,
+        body: U,
+        release: R,
+    ) -> Pin<Box<dyn 'a + Future<Output = B> + Send + Sync>>
+    where
+        A: Clone,
+        U: 'a
+            + Send
+            + FnOnce(
+                A,
+            ) -> Pin<
+                Box<dyn 'a + Future<Output = B> + Send + Sync>,
+            >,
+        R: 'a
+            + Send
+            + FnOnce(
+                A,
+            ) -> Pin<
+                Box<dyn 'a + Future<Output = ()> + Send + Sync>,
+            >,
+    {
+        let body = Mutex::new(body);
+        let release = Mutex::new(release);
+        Box::pin(async move {
+            let resource = Mutex::new(acquire.await);
+            let resource_clone =
+                Mutex::new(resource.lock().unwrap().clone());
+            let result = CatchUnwind(Box::pin(async move {
+                let body = body.into_inner().unwrap();
+                body(resource_clone.into_inner().unwrap()).await
+            }))
+            .await;
+            let result = Mutex::new(result);
+            let release = release.into_inner().unwrap();
+            release(resource.into_inner().unwrap()).await;
+            match result.into_inner().unwrap() {
+                Ok(b) => b,
+                Err(panic) => resume_unwind(panic),
+            }
+        })
+    }
+}
@@ -0,0 +1,145 @@
+//! Implementations for [`HashMap`] and [`HashSet`] of the
+//! [`hashbrown`](::hashbrown) crate (requires feature `hashbrown`)
+//!
+//! These mirror the implementations for the corresponding types in
+//! [`std::collections`], but keep the hasher of the original map or set.
+//! [`FunctorMut::fmap_mut`] on a map modifies the values in place without
+//! rehashing any keys.
+
+use super::*;
+
+use ::hashbrown::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+impl<'a, K, A, B, S> Functor<'a, B> for HashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+    S: BuildHasher + Clone,
+{
+    type Inner = A;
+    type Mapped = HashMap<K, B, S>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        self.fmap_with_key(move |_, v| f(v))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, K, A, S> FunctorMut<'a, A> for HashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: 'a,
+    S: BuildHasher + Clone,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.values_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, K, A, B, S> FunctorWithKey<'a, B> for HashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+    S: BuildHasher + Clone,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        let mut map = HashMap::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().clone(),
+        );
+        for (k, v) in self {
+            let b = f(&k, v);
+            map.insert(k, b);
+        }
+        map
+    }
+}
+
+impl<'a, A, B, S> Functor<'a, B> for HashSet<A, S>
+where
+    A: 'a + Eq + Hash,
+    B: 'a + Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Inner = A;
+    type Mapped = HashSet<B, S>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        let mut set = HashSet::with_capacity_and_hasher(
+            self.len(),
+            self.hasher().clone(),
+        );
+        set.extend(self.into_iter().map(f));
+        set
+    }
+}
+
+impl<'a, A, S> FunctorMut<'a, A> for HashSet<A, S>
+where
+    A: 'a + Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let hasher = self.hasher().clone();
+        let this =
+            std::mem::replace(self, HashSet::with_hasher(hasher));
+        *self = this.fmap_fn_mutref(f);
+    }
+}
+
+impl<'a, A, B, S> Pure<'a, B> for HashSet<A, S>
+where
+    A: 'a + Eq + Hash,
+    B: 'a + Eq + Hash,
+    S: BuildHasher + Clone + Default,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut this =
+            HashSet::with_capacity_and_hasher(1, S::default());
+        this.insert(b);
+        this
+    }
+}
+
+impl<'a, A, B, S> Monad<'a, B> for HashSet<A, S>
+where
+    A: 'a + Eq + Hash,
+    B: 'a + Eq + Hash,
+    S: BuildHasher + Clone + Default,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut set = HashSet::with_hasher(self.hasher().clone());
+        for item in self.into_iter() {
+            set.extend(f(item));
+        }
+        set
+    }
+}
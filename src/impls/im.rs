@@ -0,0 +1,199 @@
+//! Implementations for persistent collections of the [`im`](::im) crate
+//! (requires feature `im`)
+//!
+//! [`Vector`] is a [`Monad`], where [`Monad::bind`] concatenates the
+//! resulting vectors with [`Vector::append`], which shares structure
+//! instead of copying elements. [`HashMap`] and [`OrdMap`] are functors over
+//! their values.
+//!
+//! All these collections require their elements (and keys) to implement
+//! [`Clone`].
+
+use super::*;
+
+use ::im::{HashMap, OrdMap, Vector};
+use std::hash::Hash;
+
+impl<'a, A, B> Functor<'a, B> for Vector<A>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = A;
+    type Mapped = Vector<B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Vector<A>
+where
+    A: 'a + Clone,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Vector<A>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Vector::unit(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Vector<A>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut vec = Vector::new();
+        for item in self.into_iter() {
+            vec.append(f(item));
+        }
+        vec
+    }
+}
+
+impl<A> Alternative for Vector<A>
+where
+    A: Clone,
+{
+    fn empty() -> Self {
+        Vector::new()
+    }
+    fn or(mut self, other: Self) -> Self {
+        self.append(other);
+        self
+    }
+}
+
+impl<'a, K, A, B> Functor<'a, B> for HashMap<K, A>
+where
+    K: Clone + Eq + Hash,
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = A;
+    type Mapped = HashMap<K, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, K, A> FunctorMut<'a, A> for HashMap<K, A>
+where
+    K: Clone + Eq + Hash,
+    A: 'a + Clone,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for (_, inner) in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, K, A, B> FunctorWithKey<'a, B> for HashMap<K, A>
+where
+    K: Clone + Eq + Hash,
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        self.into_iter()
+            .map(|(k, v)| {
+                let b = f(&k, v);
+                (k, b)
+            })
+            .collect()
+    }
+}
+
+impl<'a, K, A, B> Functor<'a, B> for OrdMap<K, A>
+where
+    K: Clone + Ord,
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = A;
+    type Mapped = OrdMap<K, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<'a, K, A> FunctorMut<'a, A> for OrdMap<K, A>
+where
+    K: Clone + Ord,
+    A: 'a + Clone,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::take(self);
+        *self = this.fmap_fn_mutref(f);
+    }
+}
+
+impl<'a, K, A, B> FunctorWithKey<'a, B> for OrdMap<K, A>
+where
+    K: Clone + Ord,
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        self.into_iter()
+            .map(|(k, v)| {
+                let b = f(&k, v);
+                (k, b)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,129 @@
+//! Implementations for [`IndexMap`] and [`IndexSet`] of the
+//! [`indexmap`](::indexmap) crate (requires feature `indexmap`)
+//!
+//! Mapping preserves the insertion order. As for [`HashSet`], mapping an
+//! [`IndexSet`] requires the mapped type to implement [`Eq`] and [`Hash`],
+//! and only the first of several elements that are mapped to equal values
+//! is kept.
+//!
+//! [`HashSet`]: std::collections::HashSet
+
+use super::*;
+
+use ::indexmap::{IndexMap, IndexSet};
+use std::hash::Hash;
+
+impl<'a, K, A, B> Functor<'a, B> for IndexMap<K, A>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = IndexMap<K, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, K, A> FunctorMut<'a, A> for IndexMap<K, A>
+where
+    K: Eq + Hash,
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for (_, inner) in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, K, A, B> FunctorWithKey<'a, B> for IndexMap<K, A>
+where
+    K: Eq + Hash,
+    A: 'a,
+    B: 'a,
+{
+    type Key = K;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&K, A) -> B,
+    {
+        self.into_iter()
+            .map(|(k, v)| {
+                let b = f(&k, v);
+                (k, b)
+            })
+            .collect()
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for IndexSet<A>
+where
+    A: 'a + Eq + Hash,
+    B: 'a + Eq + Hash,
+{
+    type Inner = A;
+    type Mapped = IndexSet<B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for IndexSet<A>
+where
+    A: 'a + Eq + Hash,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::take(self);
+        *self = this.fmap_fn_mutref(f);
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for IndexSet<A>
+where
+    A: 'a + Eq + Hash,
+    B: 'a + Eq + Hash,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut this = IndexSet::with_capacity(1);
+        this.insert(b);
+        this
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for IndexSet<A>
+where
+    A: 'a + Eq + Hash,
+    B: 'a + Eq + Hash,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut set = IndexSet::new();
+        for item in self.into_iter() {
+            set.extend(f(item));
+        }
+        set
+    }
+}
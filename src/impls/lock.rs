@@ -0,0 +1,57 @@
+//! Implementations for [`Mutex`] and [`RwLock`]
+//!
+//! Poisoning is ignored: a poisoned lock is mapped like any other lock.
+//! [`Functor::fmap`] returns a new lock, which isn't poisoned, while
+//! [`FunctorMut::fmap_mut`] modifies the value in place and keeps the
+//! poisoning flag unchanged. Since `fmap_mut` has exclusive access, no
+//! locking is needed.
+
+use super::*;
+
+use std::sync::{Mutex, PoisonError, RwLock};
+
+macro_rules! impl_lock {
+    ($lock:ident) => {
+        impl<'a, A, B> Functor<'a, B> for $lock<A>
+        where
+            A: 'a,
+            B: 'a,
+        {
+            type Inner = A;
+            type Mapped = $lock<B>;
+            fn fmap<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> B,
+            {
+                let inner = self
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner);
+                $lock::new(f(inner))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, A> FunctorMut<'a, A> for $lock<A>
+        where
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                f(self
+                    .get_mut()
+                    .unwrap_or_else(PoisonError::into_inner))
+            }
+        }
+    };
+}
+
+impl_lock!(Mutex);
+impl_lock!(RwLock);
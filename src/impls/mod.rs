@@ -2,10 +2,52 @@
 
 use super::*;
 
+mod array;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+mod bound;
+mod boxed;
 mod boxed_fn;
+mod cell;
 mod collections;
+mod control_flow;
+mod cow;
+#[cfg(feature = "dashmap")]
+mod dashmap;
+#[cfg(feature = "either")]
+mod either;
 mod future;
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+#[cfg(feature = "im")]
+mod im;
+#[cfg(feature = "indexmap")]
+mod indexmap;
 mod iterator;
+mod lock;
+#[cfg(feature = "ndarray")]
+mod ndarray;
 mod option;
+#[cfg(feature = "parking_lot")]
+mod parking_lot;
+#[cfg(feature = "petgraph")]
+mod petgraph;
+mod phantom;
+mod poll;
+mod rc;
 mod result;
+mod slice;
+#[cfg(feature = "slotmap")]
+mod slotmap;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "threads")]
+mod thread;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
+#[cfg(feature = "tokio")]
+mod tokio;
 mod vec;
+mod wrappers;
@@ -0,0 +1,119 @@
+//! Implementations for [`Array`] of the [`ndarray`](::ndarray) crate
+//! (requires feature `ndarray`)
+//!
+//! [`Functor::fmap`] maps arrays element-wise. The [`Applicative`]
+//! implementation zips arrays: each function is applied to the element at
+//! the same index. Arrays of different shapes are broadcast to a common
+//! shape (as for arithmetic operations in `ndarray`), and [`Pure::pure`]
+//! returns an array with a single element, which broadcasts to any shape.
+//! [`Applicative::apply`] panics if the shapes are incompatible.
+
+use super::*;
+
+use ::ndarray::{Array, ArrayD, Dimension, IxDyn};
+
+impl<'a, A, B, D> Functor<'a, B> for Array<A, D>
+where
+    A: 'a,
+    B: 'a,
+    D: Dimension,
+{
+    type Inner = A;
+    type Mapped = Array<B, D>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let dim = self.raw_dim();
+        Array::from_shape_vec(dim, self.into_iter().map(f).collect())
+            .unwrap()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, D> FunctorMut<'a, A> for Array<A, D>
+where
+    A: 'a,
+    D: Dimension,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.map_inplace(f);
+    }
+}
+
+impl<'a, A, B, D> Pure<'a, B> for Array<A, D>
+where
+    A: 'a,
+    B: 'a,
+    D: Dimension,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut dim = D::zeros(D::NDIM.unwrap_or(0));
+        for len in dim.slice_mut() {
+            *len = 1;
+        }
+        Array::from_shape_vec(dim, vec![b]).unwrap()
+    }
+}
+
+fn broadcast_shape(s1: &[usize], s2: &[usize]) -> Vec<usize> {
+    let ndim = s1.len().max(s2.len());
+    let len_at = |shape: &[usize], axis: usize| {
+        (axis + shape.len())
+            .checked_sub(ndim)
+            .map_or(1, |i| shape[i])
+    };
+    (0..ndim)
+        .map(|axis| match (len_at(s1, axis), len_at(s2, axis)) {
+            (l1, l2) if l1 == l2 || l2 == 1 => l1,
+            (1, l2) => l2,
+            _ => panic!("incompatible shapes {s1:?} and {s2:?}"),
+        })
+        .collect()
+}
+
+fn broadcast_index(index: &IxDyn, shape: &[usize]) -> IxDyn {
+    let offset = index.ndim() - shape.len();
+    let mut result = IxDyn::zeros(shape.len());
+    for (axis, &len) in shape.iter().enumerate() {
+        if len != 1 {
+            result[axis] = index[offset + axis];
+        }
+    }
+    result
+}
+
+impl<'a, A, B, D> Applicative<'a, B> for Array<A, D>
+where
+    A: 'a + Clone,
+    B: 'a,
+    D: Dimension,
+{
+    fn apply(self, f: Array<BoxMapper<'a, Self, B>, D>) -> Array<B, D> {
+        let args: ArrayD<A> = self.into_dyn();
+        let mut funcs: ArrayD<BoxMapper<'a, Self, B>> = f.into_dyn();
+        let shape = broadcast_shape(args.shape(), funcs.shape());
+        let elems = ::ndarray::indices(&*shape)
+            .into_iter()
+            .map(|index| {
+                let arg =
+                    args[broadcast_index(&index, args.shape())].clone();
+                let func_index = broadcast_index(&index, funcs.shape());
+                (funcs[func_index])(arg)
+            })
+            .collect();
+        Array::from_shape_vec(IxDyn(&shape), elems)
+            .unwrap()
+            .into_dimensionality()
+            .unwrap()
+    }
+}
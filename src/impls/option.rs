@@ -59,6 +59,12 @@ where
     {
         self.and_then(f)
     }
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        self.and_then(Same::same)
+    }
 }
 
 impl<'a, A, B> Applicative<'a, B> for Option<A>
@@ -70,3 +76,24 @@ where
         f.and_then(move |inner| self.map(inner))
     }
 }
+
+impl<'a, A> MonadError<'a, ()> for Option<A> {
+    fn throw_error(_: ()) -> Self {
+        None
+    }
+    fn catch_error<F>(self, mut handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(()) -> Self,
+    {
+        self.or_else(|| handler(()))
+    }
+}
+
+impl<A> Alternative for Option<A> {
+    fn empty() -> Self {
+        None
+    }
+    fn or(self, other: Self) -> Self {
+        Option::or(self, other)
+    }
+}
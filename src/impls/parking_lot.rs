@@ -0,0 +1,52 @@
+//! Implementations for [`Mutex`] and [`RwLock`] of the
+//! [`parking_lot`](::parking_lot) crate (requires feature `parking_lot`)
+//!
+//! These locks don't support poisoning, so they are mapped like the
+//! corresponding locks in [`std::sync`] but without any special handling.
+//! Since [`FunctorMut::fmap_mut`] has exclusive access, no locking is
+//! needed.
+
+use super::*;
+
+use ::parking_lot::{Mutex, RwLock};
+
+macro_rules! impl_lock {
+    ($lock:ident) => {
+        impl<'a, A, B> Functor<'a, B> for $lock<A>
+        where
+            A: 'a,
+            B: 'a,
+        {
+            type Inner = A;
+            type Mapped = $lock<B>;
+            fn fmap<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> B,
+            {
+                $lock::new(f(self.into_inner()))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, A> FunctorMut<'a, A> for $lock<A>
+        where
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                f(self.get_mut())
+            }
+        }
+    };
+}
+
+impl_lock!(Mutex);
+impl_lock!(RwLock);
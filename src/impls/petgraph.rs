@@ -0,0 +1,86 @@
+//! Implementations for [`Graph`] of the [`petgraph`](::petgraph) crate
+//! (requires feature `petgraph`)
+//!
+//! A [`Graph`] is a [`Functor`] over its node weights and a [`Bifunctor`]
+//! over its edge weights (left) and node weights (right). Mapping keeps the
+//! structure of the graph, including all node and edge indices.
+
+use super::*;
+
+use ::petgraph::graph::{Graph, IndexType};
+use ::petgraph::EdgeType;
+
+impl<'a, N, E, Ty, Ix, C, D> Bifunctor<'a, C, D> for Graph<N, E, Ty, Ix>
+where
+    N: 'a,
+    E: 'a,
+    C: 'a,
+    D: 'a,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Left = E;
+    type Right = N;
+    type Mapped = Graph<D, C, Ty, Ix>;
+    fn bimap<F, G>(self, mut f: F, mut g: G) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(E) -> C,
+        G: 'a + Send + FnMut(N) -> D,
+    {
+        let (nodes, edges) = self.into_nodes_edges();
+        let mut graph = Graph::with_capacity(nodes.len(), edges.len());
+        for node in nodes {
+            graph.add_node(g(node.weight));
+        }
+        for edge in edges {
+            graph.add_edge(
+                edge.source(),
+                edge.target(),
+                f(edge.weight),
+            );
+        }
+        graph
+    }
+}
+
+impl<'a, N, E, Ty, Ix, B> Functor<'a, B> for Graph<N, E, Ty, Ix>
+where
+    N: 'a,
+    E: 'a,
+    B: 'a,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Inner = N;
+    type Mapped = Graph<B, E, Ty, Ix>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.bimap(|e| e, f)
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, N, E, Ty, Ix> FunctorMut<'a, N> for Graph<N, E, Ty, Ix>
+where
+    N: 'a,
+    E: 'a,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.node_weights_mut() {
+            f(inner);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Implementations for [`PhantomData`]
+//!
+//! `PhantomData<A>` contains no value, so mapping functions are never
+//! called.
+
+use super::*;
+
+use std::marker::PhantomData;
+
+impl<'a, A, B> Functor<'a, B> for PhantomData<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = PhantomData<B>;
+    fn fmap<F>(self, _: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        PhantomData
+    }
+    fn fmap_fn_mutref<F>(self, _: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for PhantomData<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, _: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for PhantomData<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(_: B) -> Self::Mapped {
+        PhantomData
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for PhantomData<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, _: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        PhantomData
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for PhantomData<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        _: PhantomData<BoxMapper<'a, Self, B>>,
+    ) -> PhantomData<B> {
+        PhantomData
+    }
+}
@@ -0,0 +1,82 @@
+//! Implementations for [`Poll`]
+//!
+//! [`Poll::Ready`] binds, while [`Poll::Pending`] is propagated.
+
+use super::*;
+
+use std::task::Poll;
+
+impl<'a, A, B> Functor<'a, B> for Poll<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Poll<B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map(f)
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Poll<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        if let Poll::Ready(inner) = self {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Poll<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Poll::Ready(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Poll<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        match self {
+            Poll::Ready(a) => f(a),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Poll<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn apply(self, f: Poll<BoxMapper<'a, Self, B>>) -> Poll<B> {
+        match f {
+            Poll::Ready(inner) => self.map(inner),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
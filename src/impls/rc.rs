@@ -0,0 +1,50 @@
+//! Implementations for [`Rc`] and [`Arc`]
+//!
+//! Mapping uses copy-on-write semantics: the inner value is moved out if
+//! the reference count is 1, and cloned otherwise.
+
+use super::*;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+macro_rules! impl_rc {
+    ($rc:ident) => {
+        impl<'a, A, B> Functor<'a, B> for $rc<A>
+        where
+            A: 'a + Clone,
+            B: 'a + Clone,
+        {
+            type Inner = A;
+            type Mapped = $rc<B>;
+            fn fmap<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> B,
+            {
+                $rc::new(f($rc::unwrap_or_clone(self)))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, A> FunctorMut<'a, A> for $rc<A>
+        where
+            A: 'a + Clone,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                f($rc::make_mut(self))
+            }
+        }
+    };
+}
+
+impl_rc!(Rc);
+impl_rc!(Arc);
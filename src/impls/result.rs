@@ -59,6 +59,12 @@ where
     {
         self.and_then(f)
     }
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        self.and_then(Same::same)
+    }
 }
 
 impl<'a, A, B, E> Applicative<'a, B> for Result<A, E>
@@ -73,3 +79,54 @@ where
         f.and_then(move |inner| self.map(inner))
     }
 }
+
+impl<'a, A, E, C, D> Bifunctor<'a, C, D> for Result<A, E>
+where
+    A: 'a,
+    E: 'a,
+    C: 'a,
+    D: 'a,
+{
+    type Left = E;
+    type Right = A;
+    type Mapped = Result<D, C>;
+    fn bimap<F, G>(self, f: F, g: G) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(E) -> C,
+        G: 'a + Send + FnMut(A) -> D,
+    {
+        self.map(g).map_err(f)
+    }
+}
+
+impl<'a, A, E> MonadError<'a, E> for Result<A, E> {
+    fn throw_error(error: E) -> Self {
+        Err(error)
+    }
+    fn catch_error<F>(self, handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(E) -> Self,
+    {
+        self.or_else(handler)
+    }
+}
+
+impl<'a, A, B, E> MonadBracket<'a, B> for Result<A, E>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bracket<U, R>(acquire: Self, body: U, release: R) -> Result<B, E>
+    where
+        A: Clone,
+        U: 'a + Send + FnOnce(A) -> Result<B, E>,
+        R: 'a + Send + FnOnce(A) -> Result<(), E>,
+    {
+        let resource = acquire?;
+        let result = body(resource.clone());
+        match (result, release(resource)) {
+            (Err(error), _) | (Ok(_), Err(error)) => Err(error),
+            (Ok(b), Ok(())) => Ok(b),
+        }
+    }
+}
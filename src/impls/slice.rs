@@ -0,0 +1,75 @@
+//! Implementations for boxed and reference-counted slices
+//!
+//! Reference-counted slices may be shared, so their elements are cloned
+//! when mapping.
+
+use super::*;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+impl<'a, A, B> Functor<'a, B> for Box<[A]>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Box<[B]>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.into_vec().into_iter().map(f).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Box<[A]>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Rc<[A]>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = A;
+    type Mapped = Rc<[B]>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.iter().cloned().map(f).collect()
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Arc<[A]>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = A;
+    type Mapped = Arc<[B]>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.iter().cloned().map(f).collect()
+    }
+}
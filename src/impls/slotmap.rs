@@ -0,0 +1,143 @@
+//! Implementations for the maps of the [`slotmap`](::slotmap) crate
+//! (requires feature `slotmap`)
+//!
+//! All maps are functors over their values, and keys are preserved when
+//! mapping. Since keys of a [`SlotMap`] or [`DenseSlotMap`] can only be
+//! created by the map itself, these maps are mapped in place and only
+//! implement [`Functor<'a, B>`] where `B` is the value type. The secondary
+//! maps [`SecondaryMap`] and [`SparseSecondaryMap`] can be mapped to any
+//! type.
+
+use super::*;
+
+use ::slotmap::{
+    DenseSlotMap, Key, SecondaryMap, SlotMap, SparseSecondaryMap,
+};
+
+macro_rules! impl_slot_map {
+    ($map:ident) => {
+        impl<'a, K, A> Functor<'a, A> for $map<K, A>
+        where
+            K: Key,
+            A: 'a,
+        {
+            type Inner = A;
+            type Mapped = Self;
+            fn fmap<F>(self, mut f: F) -> Self
+            where
+                F: 'a + Send + FnMut(A) -> A,
+            {
+                self.fmap_with_key(move |_, v| f(v))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, K, A> FunctorMut<'a, A> for $map<K, A>
+        where
+            K: Key,
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                for inner in self.values_mut() {
+                    f(inner);
+                }
+            }
+        }
+
+        impl<'a, K, A> FunctorWithKey<'a, A> for $map<K, A>
+        where
+            K: Key,
+            A: 'a,
+        {
+            type Key = K;
+            fn fmap_with_key<F>(mut self, mut f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&K, A) -> A,
+            {
+                let keys: Vec<K> = self.keys().collect();
+                for key in keys {
+                    let value = self.detach(key).unwrap();
+                    self.reattach(key, f(&key, value));
+                }
+                self
+            }
+        }
+    };
+}
+
+impl_slot_map!(SlotMap);
+impl_slot_map!(DenseSlotMap);
+
+macro_rules! impl_secondary_map {
+    ($map:ident) => {
+        impl<'a, K, A, B> Functor<'a, B> for $map<K, A>
+        where
+            K: Key,
+            A: 'a,
+            B: 'a,
+        {
+            type Inner = A;
+            type Mapped = $map<K, B>;
+            fn fmap<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(A) -> B,
+            {
+                self.into_iter().map(|(k, v)| (k, f(v))).collect()
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, K, A> FunctorMut<'a, A> for $map<K, A>
+        where
+            K: Key,
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                for inner in self.values_mut() {
+                    f(inner);
+                }
+            }
+        }
+
+        impl<'a, K, A, B> FunctorWithKey<'a, B> for $map<K, A>
+        where
+            K: Key,
+            A: 'a,
+            B: 'a,
+        {
+            type Key = K;
+            fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(&K, A) -> B,
+            {
+                self.into_iter()
+                    .map(|(k, v)| {
+                        let b = f(&k, v);
+                        (k, b)
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_secondary_map!(SecondaryMap);
+impl_secondary_map!(SparseSecondaryMap);
@@ -0,0 +1,102 @@
+//! Implementations for [`SmallVec`] of the [`smallvec`](::smallvec) crate
+//! (requires feature `smallvec`)
+//!
+//! The inline capacity `N` is retained when mapping.
+
+use super::*;
+
+use ::smallvec::SmallVec;
+
+impl<'a, A, B, const N: usize> Functor<'a, B> for SmallVec<[A; N]>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = SmallVec<[B; N]>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, const N: usize> FunctorMut<'a, A> for SmallVec<[A; N]>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B, const N: usize> Pure<'a, B> for SmallVec<[A; N]>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut vec = SmallVec::new();
+        vec.push(b);
+        vec
+    }
+}
+
+impl<'a, A, B, const N: usize> Monad<'a, B> for SmallVec<[A; N]>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut vec = SmallVec::new();
+        for item in self.into_iter() {
+            vec.extend(f(item));
+        }
+        vec
+    }
+}
+
+impl<'a, A, B, const N: usize> Applicative<'a, B> for SmallVec<[A; N]>
+where
+    A: 'a + Clone,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: SmallVec<[BoxMapper<'a, Self, B>; N]>,
+    ) -> SmallVec<[B; N]> {
+        let mut vec = SmallVec::with_capacity(f.len() * self.len());
+        for mut func in f.into_iter() {
+            for item in self.iter().cloned() {
+                vec.push((func)(item))
+            }
+        }
+        vec
+    }
+}
+
+impl<A, const N: usize> Alternative for SmallVec<[A; N]> {
+    fn empty() -> Self {
+        SmallVec::new()
+    }
+    fn or(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
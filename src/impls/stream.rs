@@ -0,0 +1,123 @@
+//! Implementation for boxed [`Stream`]s of the [`futures`] crate (requires
+//! feature `futures`)
+//!
+//! Mapping corresponds to [`StreamExt::map`] and binding to
+//! [`StreamExt::flat_map`], analogously to boxed [`Iterator`]s.
+
+use super::*;
+
+use futures::stream::{self, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::Poll;
+
+impl<'a, A, B> Functor<'a, B> for Pin<Box<dyn 'a + Stream<Item = A>>>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Pin<Box<dyn 'a + Stream<Item = B>>>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Box::pin(self.map(f))
+    }
+}
+impl<'a, A, B> Functor<'a, B>
+    for Pin<Box<dyn 'a + Stream<Item = A> + Send>>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Pin<Box<dyn 'a + Stream<Item = B> + Send>>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Box::pin(self.map(f))
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Pin<Box<dyn 'a + Stream<Item = A>>>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::replace(
+            self,
+            Box::pin(stream::poll_fn(|_| -> Poll<Option<A>> {
+                panic!("poisoned FunctorMut")
+            })),
+        );
+        *self = this.fmap_fn_mutref(f);
+    }
+}
+impl<'a, A> FunctorMut<'a, A>
+    for Pin<Box<dyn 'a + Stream<Item = A> + Send>>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::replace(
+            self,
+            Box::pin(stream::poll_fn(|_| -> Poll<Option<A>> {
+                panic!("poisoned FunctorMut")
+            })),
+        );
+        *self = this.fmap_fn_mutref(f);
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Pin<Box<dyn 'a + Stream<Item = A>>>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Box::pin(stream::iter(Some(b)))
+    }
+}
+impl<'a, A, B> Pure<'a, B>
+    for Pin<Box<dyn 'a + Stream<Item = A> + Send>>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Box::pin(stream::iter(Some(b)))
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Pin<Box<dyn 'a + Stream<Item = A>>>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::pin(self.flat_map(f))
+    }
+}
+impl<'a, A, B> Monad<'a, B>
+    for Pin<Box<dyn 'a + Stream<Item = A> + Send>>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Box::pin(self.flat_map(f))
+    }
+}
@@ -0,0 +1,30 @@
+//! Implementation for [`JoinHandle`] (requires feature `threads`)
+//!
+//! Mapping a [`JoinHandle`] spawns a new thread, which joins the original
+//! thread and applies the closure to its result. If the original thread
+//! panicked, the new thread resumes unwinding with the same payload, such
+//! that [joining](JoinHandle::join) the mapped handle returns that payload
+//! as error.
+
+use super::*;
+
+use std::panic::resume_unwind;
+use std::thread::{spawn, JoinHandle};
+
+impl<A, B> Functor<'static, B> for JoinHandle<A>
+where
+    A: 'static + Send,
+    B: 'static + Send,
+{
+    type Inner = A;
+    type Mapped = JoinHandle<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'static + Send + FnMut(Self::Inner) -> B,
+    {
+        spawn(move || match self.join() {
+            Ok(a) => f(a),
+            Err(payload) => resume_unwind(payload),
+        })
+    }
+}
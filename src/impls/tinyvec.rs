@@ -0,0 +1,144 @@
+//! Implementations for [`TinyVec`] and [`ArrayVec`] of the
+//! [`tinyvec`](::tinyvec) crate (requires feature `tinyvec`)
+//!
+//! The capacity `N` is retained when mapping. Since `tinyvec` stores
+//! elements in initialized arrays, both the original and the mapped element
+//! type must implement [`Default`]. If the result of [`Monad::bind`] on an
+//! [`ArrayVec`] exceeds the capacity, the operation panics, analogously to
+//! [`ArrayVec::push`].
+
+use super::*;
+
+use ::tinyvec::{ArrayVec, TinyVec};
+
+impl<'a, A, B, const N: usize> Functor<'a, B> for TinyVec<[A; N]>
+where
+    A: 'a + Default,
+    B: 'a + Default,
+{
+    type Inner = A;
+    type Mapped = TinyVec<[B; N]>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, const N: usize> FunctorMut<'a, A> for TinyVec<[A; N]>
+where
+    A: 'a + Default,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B, const N: usize> Pure<'a, B> for TinyVec<[A; N]>
+where
+    A: 'a + Default,
+    B: 'a + Default,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut vec = TinyVec::new();
+        vec.push(b);
+        vec
+    }
+}
+
+impl<'a, A, B, const N: usize> Monad<'a, B> for TinyVec<[A; N]>
+where
+    A: 'a + Default,
+    B: 'a + Default,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut vec = TinyVec::new();
+        for item in self.into_iter() {
+            vec.extend(f(item));
+        }
+        vec
+    }
+}
+
+impl<'a, A, B, const N: usize> Functor<'a, B> for ArrayVec<[A; N]>
+where
+    A: 'a + Default,
+    B: 'a + Default,
+{
+    type Inner = A;
+    type Mapped = ArrayVec<[B; N]>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, const N: usize> FunctorMut<'a, A> for ArrayVec<[A; N]>
+where
+    A: 'a + Default,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for inner in self.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B, const N: usize> Pure<'a, B> for ArrayVec<[A; N]>
+where
+    A: 'a + Default,
+    B: 'a + Default,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut vec = ArrayVec::new();
+        vec.push(b);
+        vec
+    }
+}
+
+impl<'a, A, B, const N: usize> Monad<'a, B> for ArrayVec<[A; N]>
+where
+    A: 'a + Default,
+    B: 'a + Default,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut vec = ArrayVec::new();
+        for item in self.into_iter() {
+            for b in f(item) {
+                vec.push(b);
+            }
+        }
+        vec
+    }
+}
@@ -0,0 +1,46 @@
+//! Implementation for [`oneshot::Receiver`] of the [`tokio`](::tokio)
+//! crate (requires feature `tokio`)
+//!
+//! Mapping a [`oneshot::Receiver`] within a Tokio runtime spawns a new task
+//! on that runtime, which awaits the original receiver and sends the mapped
+//! value through a new channel. Outside of a runtime, a new thread is
+//! spawned instead, which blocks until the value is received. If the
+//! original sender is dropped without sending a value, the mapped receiver
+//! returns an error as well.
+
+use super::*;
+
+use ::tokio::runtime::Handle;
+use ::tokio::sync::oneshot;
+
+impl<A, B> Functor<'static, B> for oneshot::Receiver<A>
+where
+    A: 'static + Send,
+    B: 'static + Send,
+{
+    type Inner = A;
+    type Mapped = oneshot::Receiver<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'static + Send + FnMut(Self::Inner) -> B,
+    {
+        let (tx, rx) = oneshot::channel();
+        match Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Ok(a) = self.await {
+                        let _ = tx.send(f(a));
+                    }
+                });
+            }
+            Err(_) => {
+                std::thread::spawn(move || {
+                    if let Ok(a) = self.blocking_recv() {
+                        let _ = tx.send(f(a));
+                    }
+                });
+            }
+        }
+        rx
+    }
+}
@@ -65,6 +65,16 @@ where
         }
         vec
     }
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        let mut vec = Vec::with_capacity(self.len());
+        for inner in self {
+            vec.extend(inner.same());
+        }
+        vec
+    }
 }
 
 impl<'a, A, B> Applicative<'a, B> for Vec<A>
@@ -82,3 +92,13 @@ where
         vec
     }
 }
+
+impl<A> Alternative for Vec<A> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+    fn or(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
@@ -0,0 +1,48 @@
+//! Implementations for [`Wrapping`], [`Saturating`], and [`Reverse`]
+
+use super::*;
+
+use std::cmp::Reverse;
+use std::num::{Saturating, Wrapping};
+
+macro_rules! impl_wrapper {
+    ($wrapper:ident) => {
+        impl<'a, A, B> Functor<'a, B> for $wrapper<A>
+        where
+            A: 'a,
+            B: 'a,
+        {
+            type Inner = A;
+            type Mapped = $wrapper<B>;
+            fn fmap<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> B,
+            {
+                $wrapper(f(self.0))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, A> FunctorMut<'a, A> for $wrapper<A>
+        where
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, mut f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                f(&mut self.0)
+            }
+        }
+    };
+}
+
+impl_wrapper!(Wrapping);
+impl_wrapper!(Saturating);
+impl_wrapper!(Reverse);
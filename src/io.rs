@@ -0,0 +1,183 @@
+//! Side-effecting computations
+//!
+//! See [`Io`].
+
+use super::*;
+
+use std::any::Any;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+/// Payload of a panic, as returned by [`std::panic::catch_unwind`]
+pub type Panic = Box<dyn Any + Send>;
+
+/// Side-effecting computation that is executed when [run]
+///
+/// An `Io<'a, A>` wraps a thunk (like the [implementations] for boxed
+/// [`FnOnce`] closures), but as a named type. Nothing is executed until
+/// [`Io::run`] is called.
+///
+/// Panics are treated as errors: [`MonadError::catch_error`] catches panics
+/// that occur while running the computation, and
+/// [`MonadError::throw_error`] resumes unwinding with a given [`Panic`]
+/// payload.
+///
+/// [run]: Io::run
+/// [implementations]: Functor#foreign-impls
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadError;
+/// use fmap::io::Io;
+///
+/// let read = Io::new(|| "42");
+/// let parse = read.and_then(|s| Io::new(move || s.parse::<i32>().unwrap()));
+/// assert_eq!(parse.run(), 42);
+///
+/// let fail = Io::new(|| "x").map(|s| s.parse::<i32>().unwrap());
+/// let recovered = fail.catch_error(|_| Io::new(|| -1));
+/// assert_eq!(recovered.run(), -1);
+/// ```
+pub struct Io<'a, A>(Box<dyn 'a + Send + FnOnce() -> A>);
+
+impl<'a, A> Io<'a, A> {
+    /// Create computation from thunk
+    pub fn new<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Io(Box::new(thunk))
+    }
+
+    /// Execute computation
+    pub fn run(self) -> A {
+        (self.0)()
+    }
+
+    /// Execute computation and catch panics
+    pub fn try_run(self) -> Result<A, Panic> {
+        catch_unwind(AssertUnwindSafe(self.0))
+    }
+
+    /// Modify result with a closure
+    pub fn map<B, F>(self, f: F) -> Io<'a, B>
+    where
+        A: 'a,
+        F: 'a + Send + FnOnce(A) -> B,
+    {
+        Io::new(move || f(self.run()))
+    }
+
+    /// Chain a computation that depends on the result
+    pub fn and_then<B, F>(self, f: F) -> Io<'a, B>
+    where
+        A: 'a,
+        F: 'a + Send + FnOnce(A) -> Io<'a, B>,
+    {
+        Io::new(move || f(self.run()).run())
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Io<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Io<'a, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map(f)
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Io<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Io::new(move || b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Io<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.and_then(f)
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Io<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn apply(self, f: Io<'a, BoxMapper<'a, Self, B>>) -> Io<'a, B> {
+        Io::new(move || {
+            let mut mapper = f.run();
+            mapper(self.run())
+        })
+    }
+}
+
+impl<'a, A> MonadError<'a, Panic> for Io<'a, A>
+where
+    A: 'a,
+{
+    fn throw_error(error: Panic) -> Self {
+        Io::new(move || resume_unwind(error))
+    }
+    fn catch_error<F>(self, mut handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(Panic) -> Self,
+    {
+        Io::new(move || match self.try_run() {
+            Ok(a) => a,
+            Err(error) => handler(error).run(),
+        })
+    }
+}
+
+impl<'a, A> MonadIo<'a> for Io<'a, A> {
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Io::new(thunk)
+    }
+}
+
+impl<'a, A, B> MonadBracket<'a, B> for Io<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bracket<U, R>(acquire: Self, body: U, release: R) -> Io<'a, B>
+    where
+        A: Clone,
+        U: 'a + Send + FnOnce(A) -> Io<'a, B>,
+        R: 'a + Send + FnOnce(A) -> Io<'a, ()>,
+    {
+        Io::new(move || {
+            let resource = acquire.run();
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                body(resource.clone()).run()
+            }));
+            release(resource).run();
+            match result {
+                Ok(b) => b,
+                Err(panic) => resume_unwind(panic),
+            }
+        })
+    }
+}
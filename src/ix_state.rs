@@ -0,0 +1,210 @@
+//! Indexed state monad
+//!
+//! See [`IxState`].
+
+use super::*;
+
+/// State monad whose computations may change the type of the state
+///
+/// An `IxState<'a, S1, S2, A>` is a computation that takes a state of type
+/// `S1` and returns a value of type `A` along with a new state of type `S2`.
+/// [`IxState::and_then`] chains computations `S1 -> S2` and `S2 -> S3`,
+/// such that the type of the state can be used to track a protocol at
+/// compile time (type-state pattern).
+///
+/// If the state type doesn't change (i.e. `S1` and `S2` are equal), then
+/// `IxState` is an ordinary state [`Monad`].
+///
+/// # Examples
+///
+/// ```
+/// use fmap::ix_state::IxState;
+///
+/// struct Closed;
+/// struct Open(Vec<String>);
+///
+/// fn open() -> IxState<'static, Closed, Open, ()> {
+///     IxState::put(Open(Vec::new()))
+/// }
+/// fn send(msg: &str) -> IxState<'static, Open, Open, ()> {
+///     let msg = msg.to_string();
+///     IxState::modify(move |Open(mut log)| {
+///         log.push(msg);
+///         Open(log)
+///     })
+/// }
+/// fn close() -> IxState<'static, Open, Closed, usize> {
+///     IxState::new(|Open(log)| (log.len(), Closed))
+/// }
+///
+/// let session = open()
+///     .and_then(|()| send("hello"))
+///     .and_then(|()| send("bye"))
+///     .and_then(|()| close());
+/// // `send` after `close` would not compile:
+/// // let invalid = session.and_then(|_| send("oops"));
+/// let (sent, Closed) = session.run(Closed);
+/// assert_eq!(sent, 2);
+/// ```
+pub struct IxState<'a, S1, S2, A>(
+    Box<dyn 'a + Send + FnOnce(S1) -> (A, S2)>,
+);
+
+impl<'a, S1, S2, A> IxState<'a, S1, S2, A>
+where
+    S1: 'a,
+    S2: 'a,
+    A: 'a,
+{
+    /// Create computation from a state transition function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S1) -> (A, S2),
+    {
+        IxState(Box::new(f))
+    }
+
+    /// Run computation with initial state, returning result and final
+    /// state
+    pub fn run(self, state: S1) -> (A, S2) {
+        (self.0)(state)
+    }
+
+    /// Run computation with initial state, returning only the result
+    pub fn eval(self, state: S1) -> A {
+        self.run(state).0
+    }
+
+    /// Run computation with initial state, returning only the final state
+    pub fn exec(self, state: S1) -> S2 {
+        self.run(state).1
+    }
+
+    /// Modify result with a closure
+    pub fn map<B, F>(self, f: F) -> IxState<'a, S1, S2, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnOnce(A) -> B,
+    {
+        IxState::new(move |s1| {
+            let (a, s2) = self.run(s1);
+            (f(a), s2)
+        })
+    }
+
+    /// Chain a computation that depends on the result and continues with
+    /// the new state type
+    pub fn and_then<S3, B, F>(self, f: F) -> IxState<'a, S1, S3, B>
+    where
+        S3: 'a,
+        B: 'a,
+        F: 'a + Send + FnOnce(A) -> IxState<'a, S2, S3, B>,
+    {
+        IxState::new(move |s1| {
+            let (a, s2) = self.run(s1);
+            f(a).run(s2)
+        })
+    }
+}
+
+impl<'a, S1, S2> IxState<'a, S1, S2, ()>
+where
+    S1: 'a,
+    S2: 'a,
+{
+    /// Replace state (possibly changing its type)
+    pub fn put(state: S2) -> Self
+    where
+        S2: Send,
+    {
+        IxState::new(move |_| ((), state))
+    }
+
+    /// Modify state with a closure (possibly changing its type)
+    pub fn modify<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S1) -> S2,
+    {
+        IxState::new(move |s1| ((), f(s1)))
+    }
+}
+
+impl<'a, S> IxState<'a, S, S, S>
+where
+    S: 'a + Clone,
+{
+    /// Retrieve current state
+    pub fn get() -> Self {
+        IxState::new(|s: S| (s.clone(), s))
+    }
+}
+
+impl<'a, S1, S2, A, B> Functor<'a, B> for IxState<'a, S1, S2, A>
+where
+    S1: 'a,
+    S2: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = IxState<'a, S1, S2, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map(f)
+    }
+}
+
+impl<'a, S, A, B> Pure<'a, B> for IxState<'a, S, S, A>
+where
+    S: 'a,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        IxState::new(move |s| (b, s))
+    }
+}
+
+impl<'a, S, A, B> Monad<'a, B> for IxState<'a, S, S, A>
+where
+    S: 'a,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.and_then(f)
+    }
+}
+
+impl<'a, S, A, B> Applicative<'a, B> for IxState<'a, S, S, A>
+where
+    S: 'a,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn apply(
+        self,
+        f: IxState<'a, S, S, BoxMapper<'a, Self, B>>,
+    ) -> IxState<'a, S, S, B> {
+        f.and_then(move |mapper| self.map(mapper))
+    }
+}
+
+impl<'a, S, A> MonadState<'a, S> for IxState<'a, S, S, A>
+where
+    S: 'a,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        IxState::new(f)
+    }
+}
@@ -0,0 +1,219 @@
+//! Functor wrappers for JSON values (requires feature `serde_json`)
+//!
+//! See [`JsonArray`] and [`JsonObject`].
+
+use super::*;
+
+use serde_json::{Map, Value};
+
+/// Elements of a JSON array
+///
+/// A `JsonArray<A>` is a [`Functor`] over its elements. It can be
+/// [converted from] a [`Value`] that is an array, and any `JsonArray<A>`
+/// where `A` can be converted into a `Value` can be converted back.
+///
+/// [converted from]: TryFrom
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::json::JsonArray;
+/// use serde_json::{json, Value};
+///
+/// let array = JsonArray::try_from(json!([1, 2, "x"])).unwrap();
+/// let kinds = array.clone().fmap(|v| v.is_number());
+/// assert_eq!(Value::from(kinds), json!([true, true, false]));
+///
+/// let numbers = array.try_fmap(|v| v.as_i64().ok_or(v));
+/// assert_eq!(numbers, Err(json!("x")));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct JsonArray<A = Value>(pub Vec<A>);
+
+impl<A> JsonArray<A> {
+    /// Map elements with a fallible closure, stopping at the first error
+    pub fn try_fmap<B, E, F>(self, f: F) -> Result<JsonArray<B>, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        Ok(JsonArray(
+            self.0.into_iter().map(f).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl TryFrom<Value> for JsonArray {
+    type Error = Value;
+    /// Unwrap array, or return the value unchanged if it is not an array
+    fn try_from(value: Value) -> Result<Self, Value> {
+        match value {
+            Value::Array(vec) => Ok(JsonArray(vec)),
+            other => Err(other),
+        }
+    }
+}
+
+impl<A> From<JsonArray<A>> for Value
+where
+    A: Into<Value>,
+{
+    fn from(array: JsonArray<A>) -> Self {
+        Value::Array(array.0.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for JsonArray<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = JsonArray<B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        JsonArray(self.0.fmap(f))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for JsonArray<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.0.fmap_mut(f)
+    }
+}
+
+/// Entries of a JSON object
+///
+/// A `JsonObject<A>` is a [`Functor`] over the values of its entries,
+/// which also implements [`FunctorWithKey`]. The entries are stored in the
+/// iteration order of the [`Map`] they were converted from.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::FunctorWithKey;
+/// use fmap::json::JsonObject;
+/// use serde_json::{json, Value};
+///
+/// let object = JsonObject::try_from(json!({"a": 1, "b": 2})).unwrap();
+/// let tagged = object.fmap_with_key(|k, v| format!("{k}:{v}"));
+/// assert_eq!(Value::from(tagged), json!({"a": "a:1", "b": "b:2"}));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct JsonObject<A = Value>(pub Vec<(String, A)>);
+
+impl<A> JsonObject<A> {
+    /// Map values with a fallible closure, stopping at the first error
+    pub fn try_fmap<B, E, F>(self, mut f: F) -> Result<JsonObject<B>, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        Ok(JsonObject(
+            self.0
+                .into_iter()
+                .map(|(k, v)| Ok((k, f(v)?)))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl TryFrom<Value> for JsonObject {
+    type Error = Value;
+    /// Unwrap object, or return the value unchanged if it is not an object
+    fn try_from(value: Value) -> Result<Self, Value> {
+        match value {
+            Value::Object(map) => {
+                Ok(JsonObject(map.into_iter().collect()))
+            }
+            other => Err(other),
+        }
+    }
+}
+
+impl<A> From<JsonObject<A>> for Value
+where
+    A: Into<Value>,
+{
+    fn from(object: JsonObject<A>) -> Self {
+        Value::Object(
+            object
+                .0
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect::<Map<_, _>>(),
+        )
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for JsonObject<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = JsonObject<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        JsonObject(self.0.into_iter().map(|(k, v)| (k, f(v))).collect())
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for JsonObject<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        for (_, inner) in self.0.iter_mut() {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, B> FunctorWithKey<'a, B> for JsonObject<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Key = String;
+    fn fmap_with_key<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&String, A) -> B,
+    {
+        JsonObject(
+            self.0
+                .into_iter()
+                .map(|(k, v)| {
+                    let b = f(&k, v);
+                    (k, b)
+                })
+                .collect(),
+        )
+    }
+}
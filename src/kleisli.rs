@@ -0,0 +1,173 @@
+//! Kleisli arrows
+//!
+//! See [`Kleisli`] and [`compose_m`].
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Compose two functions returning a [`Monad`] (known as `>=>` in Haskell)
+///
+/// The returned closure calls `f` and [binds] its result to `g`. See
+/// [`Kleisli`] for a wrapper type that allows composing several functions
+/// in a chain of method calls.
+///
+/// [binds]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::kleisli::compose_m;
+///
+/// let parse = |s: &str| s.parse::<i32>().ok();
+/// let half = |x: i32| (x % 2 == 0).then_some(x / 2);
+/// let mut parse_half = compose_m(parse, half);
+/// assert_eq!(parse_half("42"), Some(21));
+/// assert_eq!(parse_half("7"), None);
+/// ```
+#[doc(alias = ">=>")]
+pub fn compose_m<'a, A, B, C, M, F, G>(
+    mut f: F,
+    g: G,
+) -> impl 'a + Send + FnMut(A) -> M::Mapped
+where
+    M: Monad<'a, C, Inner = B>,
+    B: 'a,
+    C: 'a,
+    F: 'a + Send + FnMut(A) -> M,
+    G: 'a + Send + FnMut(B) -> M::Mapped,
+{
+    let g = Arc::new(Mutex::new(g));
+    move |a| {
+        let g = g.clone();
+        f(a).bind(move |b| (g.lock().unwrap())(b))
+    }
+}
+
+/// Function returning a [`Monad`] (Kleisli arrow)
+///
+/// A `Kleisli<'a, A, M>` wraps a function `A -> M`, where `M` is a monad
+/// (e.g. `M = Option<B>` for a fallible function from `A` to `B`). Kleisli
+/// arrows can be composed with [`Kleisli::and_then`] (known as `>=>` in
+/// Haskell), which binds the result of the first function to the second
+/// function. This allows building effectful pipelines point-free.
+///
+/// Other than that, the methods [`Kleisli::arr`], [`Kleisli::first`], and
+/// [`Kleisli::second`] provide the usual arrow combinators.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::kleisli::Kleisli;
+///
+/// let parse = Kleisli::new(|s: &str| s.parse::<i32>().ok());
+/// let half = Kleisli::new(|x: i32| (x % 2 == 0).then_some(x / 2));
+/// let mut pipeline = parse.and_then(half).and_then(Kleisli::arr(|x| x + 1));
+/// assert_eq!(pipeline.run("42"), Some(22));
+/// assert_eq!(pipeline.run("7"), None);
+/// assert_eq!(pipeline.run("x"), None);
+/// ```
+pub struct Kleisli<'a, A, M>(Box<dyn 'a + Send + FnMut(A) -> M>);
+
+impl<'a, A, M> Kleisli<'a, A, M> {
+    /// Create Kleisli arrow from function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnMut(A) -> M,
+    {
+        Kleisli(Box::new(f))
+    }
+
+    /// Lift a pure function
+    pub fn arr<B, F>(mut f: F) -> Self
+    where
+        B: 'a,
+        M: Pure<'a, B, Mapped = M>,
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        Kleisli::new(move |a| M::pure(f(a)))
+    }
+
+    /// Call function
+    pub fn run(&mut self, a: A) -> M {
+        (self.0)(a)
+    }
+
+    /// Unwrap boxed function
+    pub fn into_fn(self) -> Box<dyn 'a + Send + FnMut(A) -> M> {
+        self.0
+    }
+
+    /// Compose with another Kleisli arrow, which is applied afterwards
+    ///
+    /// See also [`compose_m`].
+    #[doc(alias = ">=>")]
+    pub fn and_then<B, C, N>(
+        self,
+        mut next: Kleisli<'a, B, N>,
+    ) -> Kleisli<'a, A, N>
+    where
+        A: 'a,
+        M: 'a + Monad<'a, C, Inner = B, Mapped = N>,
+        B: 'a,
+        C: 'a,
+        N: 'a,
+    {
+        Kleisli::new(compose_m(self.0, move |b| next.run(b)))
+    }
+
+    /// Apply to the first component of a pair, leaving the second
+    /// component unchanged
+    pub fn first<B, C>(
+        self,
+    ) -> Kleisli<'a, (A, C), <M as Functor<'a, (B, C)>>::Mapped>
+    where
+        A: 'a,
+        M: 'a + Functor<'a, (B, C), Inner = B>,
+        B: 'a,
+        C: 'a + Clone + Send,
+    {
+        let mut f = self.0;
+        Kleisli::new(move |(a, c): (A, C)| {
+            f(a).fmap(move |b| (b, c.clone()))
+        })
+    }
+
+    /// Apply to the second component of a pair, leaving the first
+    /// component unchanged
+    pub fn second<B, C>(
+        self,
+    ) -> Kleisli<'a, (C, A), <M as Functor<'a, (C, B)>>::Mapped>
+    where
+        A: 'a,
+        M: 'a + Functor<'a, (C, B), Inner = B>,
+        B: 'a,
+        C: 'a + Clone + Send,
+    {
+        let mut f = self.0;
+        Kleisli::new(move |(c, a): (C, A)| {
+            f(a).fmap(move |b| (c.clone(), b))
+        })
+    }
+}
+
+impl<'a, A, M, B> Functor<'a, B> for Kleisli<'a, A, M>
+where
+    A: 'a,
+    M: 'a + Functor<'a, B>,
+    B: 'a,
+{
+    type Inner = M::Inner;
+    type Mapped = Kleisli<'a, A, M::Mapped>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let mut g = self.0;
+        let f = Arc::new(Mutex::new(f));
+        Kleisli::new(move |a| {
+            let f = f.clone();
+            g(a).fmap(move |x| (f.lock().unwrap())(x))
+        })
+    }
+}
@@ -0,0 +1,151 @@
+//! Memoized lazy values
+//!
+//! See [`Lazy`].
+
+use super::*;
+
+use std::cell::{Cell, OnceCell};
+use std::fmt;
+
+/// Lazily evaluated value, which is memoized once computed
+///
+/// A `Lazy<'a, A>` wraps a thunk that is evaluated when [forced] for the
+/// first time. Unlike the [implementations] for boxed [`FnOnce`] closures,
+/// the result is cached, such that subsequent calls of [`Lazy::force`] return
+/// the same value without evaluating the thunk again.
+///
+/// [Mapping] and [binding] don't evaluate anything but return a new `Lazy`
+/// value, which forces the original value when it is forced itself.
+///
+/// [forced]: Lazy::force
+/// [implementations]: Functor#foreign-impls
+/// [Mapping]: Functor::fmap
+/// [binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad};
+/// use fmap::lazy::Lazy;
+///
+/// let a = Lazy::new(|| 20);
+/// let b = a.fmap(|x| x + 1).bind(|x| Lazy::new(move || x * 2));
+/// assert!(!b.is_evaluated());
+/// assert_eq!(*b.force(), 42);
+/// assert!(b.is_evaluated());
+/// assert_eq!(b.into_inner(), 42);
+/// ```
+pub struct Lazy<'a, A> {
+    value: OnceCell<A>,
+    thunk: Cell<Option<Box<dyn 'a + Send + FnOnce() -> A>>>,
+}
+
+impl<'a, A> Lazy<'a, A> {
+    /// Create lazy value from thunk
+    pub fn new<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        Lazy {
+            value: OnceCell::new(),
+            thunk: Cell::new(Some(Box::new(thunk))),
+        }
+    }
+
+    /// Create already evaluated value
+    pub fn from_value(value: A) -> Self {
+        Lazy {
+            value: OnceCell::from(value),
+            thunk: Cell::new(None),
+        }
+    }
+
+    /// Evaluate thunk (unless already done) and return reference to value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thunk forces its own value or if an earlier evaluation
+    /// panicked.
+    pub fn force(&self) -> &A {
+        self.value.get_or_init(|| match self.thunk.take() {
+            Some(thunk) => thunk(),
+            None => panic!("lazy value is poisoned"),
+        })
+    }
+
+    /// Check whether the value has been evaluated already
+    pub fn is_evaluated(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Evaluate thunk (unless already done) and return value
+    pub fn into_inner(self) -> A {
+        match self.value.into_inner() {
+            Some(value) => value,
+            None => match self.thunk.into_inner() {
+                Some(thunk) => thunk(),
+                None => panic!("lazy value is poisoned"),
+            },
+        }
+    }
+}
+
+impl<'a, A> fmt::Debug for Lazy<'a, A>
+where
+    A: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.get() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(<unevaluated>)"),
+        }
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Lazy<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    type Inner = A;
+    type Mapped = Lazy<'a, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Lazy::new(move || f(self.into_inner()))
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Lazy<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Lazy::from_value(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Lazy<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Lazy::new(move || f(self.into_inner()).into_inner())
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Lazy<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn apply(self, f: Lazy<'a, BoxMapper<'a, Self, B>>) -> Lazy<'a, B> {
+        Lazy::new(move || (f.into_inner())(self.into_inner()))
+    }
+}
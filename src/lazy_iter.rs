@@ -0,0 +1,86 @@
+//! Unboxed lazy iterators
+//!
+//! See [`LazyIter`].
+
+#[cfg(doc)]
+use super::*;
+
+use std::iter::{FlatMap, Map};
+
+/// Wrapper for an [`Iterator`] that is mapped without boxing
+///
+/// Boxed iterators implement [`Functor`] and [`Monad`], but each call of
+/// [`fmap`](Functor::fmap) or [`bind`](Monad::bind) allocates and adds a
+/// layer of dynamic dispatch. The methods [`LazyIter::fmap`] and
+/// [`LazyIter::bind`] instead return the concrete adapter types [`Map`]
+/// and [`FlatMap`], such that a pipeline can be compiled statically.
+///
+/// *Note:* `LazyIter` doesn't implement the [`Functor`] trait, because the
+/// [mapped type] of a `Functor` can't depend on the type of the closure.
+/// Use [`LazyIter::boxed`] to convert into a boxed iterator when generic
+/// code requires a `Functor`.
+///
+/// [mapped type]: Functor::Mapped
+///
+/// # Examples
+///
+/// ```
+/// use fmap::lazy_iter::LazyIter;
+///
+/// let pipeline = LazyIter(1..4)
+///     .fmap(|x| x * 10)
+///     .bind(|x| [x, x + 1]);
+/// assert_eq!(pipeline.collect::<Vec<_>>(), vec![10, 11, 20, 21, 30, 31]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LazyIter<I>(pub I);
+
+impl<I> LazyIter<I>
+where
+    I: Iterator,
+{
+    /// Map items lazily, returning a [`Map`] adapter
+    pub fn fmap<B, F>(self, f: F) -> LazyIter<Map<I, F>>
+    where
+        F: FnMut(I::Item) -> B,
+    {
+        LazyIter(self.0.map(f))
+    }
+
+    /// Map items to iterators and flatten lazily, returning a [`FlatMap`]
+    /// adapter
+    pub fn bind<U, F>(self, f: F) -> LazyIter<FlatMap<I, U, F>>
+    where
+        U: IntoIterator,
+        F: FnMut(I::Item) -> U,
+    {
+        LazyIter(self.0.flat_map(f))
+    }
+
+    /// Unwrap iterator
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+
+    /// Convert into a boxed iterator, which implements [`Functor`] and
+    /// [`Monad`]
+    pub fn boxed<'a>(self) -> Box<dyn 'a + Iterator<Item = I::Item>>
+    where
+        I: 'a,
+    {
+        Box::new(self.0)
+    }
+}
+
+impl<I> Iterator for LazyIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
@@ -19,6 +19,12 @@
 //!   this crate provides implementations for all types in the standard library
 //!   for which `Functor` is implemented.
 //!
+//! Maps additionally implement [`FunctorWithKey`], which passes the key of
+//! each value to the mapping function.
+//!
+//! Types with two type parameters that can both be mapped (e.g. [`Result`])
+//! implement [`Bifunctor`].
+//!
 //! [`fmap`]: Functor::fmap
 //! [`fmap_mut`]: FunctorMut::fmap_mut
 //! [implemented]: Functor#foreign-impls
@@ -31,6 +37,12 @@
 //! * [`Contravariant`] (akin to `Functor`)
 //! * [`ContravariantSelf`] (akin to `FunctorSelf`)
 //! * [`ContravariantMut`] (akin to `FunctorMut`)
+//! * [`Divisible`] and [`Decidable`], which allow combining contravariant
+//!   functors (akin to `Applicative` and `Alternative`)
+//!
+//! [`Comparison`](comparison::Comparison) and
+//! [`Predicate`](predicate::Predicate) are provided as examples of
+//! contravariant functors.
 //!
 //! # Monads
 //!
@@ -38,10 +50,169 @@
 //! supertrait [`Pure`] allows wrapping a single value. ([`Pure::pure`] is
 //! equivalent to what's usually called "return" in the context of monads).
 //! Nested monads implement [`NestedMonad`] through a blanket implementation.
+//! Monads that can fail (and recover from failure) implement
+//! [`MonadError`]. Monad transformers, which add effects to a base monad,
+//! implement [`MonadTrans`]. Monads with a state implement [`MonadState`],
+//! monads with a read-only environment implement [`MonadReader`], monads
+//! that accumulate a log implement [`MonadWriter`], monads that allow
+//! capturing the current continuation implement [`MonadCont`], and monads
+//! that can perform side effects implement [`MonadIo`]. [`MonadBracket`]
+//! allows releasing resources on both success and failure. The functions
+//! [`map2`] and [`map3`] combine the inner values of several monads with a
+//! plain function, and [`fold_m`] folds an iterator with a function
+//! returning a monad.
 //!
 //! # Applicative functors
 //!
-//! For applicative functors see the [`Applicative`] trait.
+//! For applicative functors see the [`Applicative`] trait. Types with a
+//! choice operation (e.g. fallback on failure) implement [`Alternative`],
+//! and monads that are `Alternative` implement [`MonadPlus`] through a
+//! blanket implementation. Types whose effects can run in parallel
+//! implement [`Parallel`]. The functions [`sequence`], [`traverse`], and
+//! [`replicate_m`] collect the inner values of several applicative functors
+//! into a single functor.
+//!
+//! # Comonads
+//!
+//! The [`Comonad`] trait describes functors which are also comonads, i.e.
+//! which allow [extracting] a value in focus and [extending] a function over
+//! all positions. Its supertrait [`Extract`] is the dual of [`Pure`].
+//!
+//! [extracting]: Extract::extract
+//! [extending]: Comonad::extend
+//!
+//! # Monoids
+//!
+//! Some types (e.g. [`Traced`](traced::Traced)) require a
+//! [`Monoid`](monoid::Monoid), which is defined in the [`monoid`] module.
+//!
+//! # Additional types
+//!
+//! Besides implementations for types in the standard library, this crate
+//! provides the following types:
+//!
+//! * [`Backwards`](backwards::Backwards), which reverses the order of
+//!   effects of an [`Applicative`] functor
+//! * [`Codensity`](codensity::Codensity), which re-associates calls of
+//!   [`bind`]
+//! * [`Cofree`](cofree::Cofree), the cofree comonad over a [`Functor`]
+//! * [`Cont`](cont::Cont), the continuation monad (with
+//!   [`call_cc`](cont::call_cc))
+//! * [`ContT`](cont_t::ContT), the continuation monad transformer (with
+//!   [`call_cc`](cont_t::call_cc))
+//! * [`Coyoneda`](coyoneda::Coyoneda), which fuses successive calls of
+//!   [`fmap`]
+//! * [`Day`](day::Day), the Day convolution of two functors
+//! * [`Dist`](dist::Dist), a discrete probability distribution monad
+//! * [`Env`](env::Env), the environment (coreader) comonad
+//! * [`ExceptT`](except_t::ExceptT), the monad transformer adding errors
+//!   (like [`Result`]) to a base monad
+//! * [`Fix`](fix::Fix), the fixed point of a [`Functor`] (with recursion
+//!   schemes)
+//! * [`Fut`](fut::Fut), a future that is mapped without boxing
+//! * [`Free`](free::Free), the free monad over a [`Functor`]
+//! * [`Freer`](freer::Freer), a free monad over an instruction set that
+//!   doesn't need to be a functor
+//! * [`FreeAp`](free_ap::FreeAp), the free applicative functor over a
+//!   [`Functor`]
+//! * [`FreeT`](free_t::FreeT), the free monad transformer over a
+//!   [`Functor`]
+//! * [`IdentityT`](identity_t::IdentityT), the monad transformer that
+//!   doesn't add any effect
+//! * [`Io`](io::Io), a monad for side-effecting computations
+//! * [`IxState`](ix_state::IxState), a state monad whose computations may
+//!   change the type of the state
+//! * [`Kleisli`](kleisli::Kleisli), a composable function returning a
+//!   [`Monad`]
+//! * [`Lazy`](lazy::Lazy), a memoized lazy value
+//! * [`LazyIter`](lazy_iter::LazyIter), an iterator that is mapped
+//!   without boxing
+//! * [`Logic`](logic::Logic), a backtracking monad with fair interleaving
+//! * [`Memo`](memo::Memo), a function that caches its results
+//! * [`MergeMap`](merge_map::MergeMap), a map whose values form a monad
+//!   with a configurable merge policy
+//! * [`OnErr`](on_err::OnErr), which maps the error of a [`Result`]
+//! * [`OptionT`](option_t::OptionT), the monad transformer adding
+//!   failure (like [`Option`]) to a base monad
+//! * [`Par`](par::Par), a future that is polled concurrently when applied
+//! * [`Parser`](parser::Parser), a parser combinator monad
+//! * [`MappedReceiver`](receiver::MappedReceiver), a channel receiver
+//!   whose received values are mapped
+//! * [`ReaderT`](reader_t::ReaderT), the monad transformer adding a
+//!   read-only environment to a base monad
+//! * [`RWST`](rws_t::RWST), the monad transformer combining a read-only
+//!   environment, an accumulated log, and a state
+//! * [`Star`](star::Star), a function returning a functor, as
+//!   [profunctor]
+//! * [`StateT`](state_t::StateT), the monad transformer adding a state to
+//!   a base monad
+//! * [`StaticCoyoneda`](static_coyoneda::StaticCoyoneda), a variant of
+//!   `Coyoneda` which fuses successive mapping functions without boxing them
+//! * [`Store`](store::Store), the store comonad
+//! * [`Supply`](supply::Supply), a monad supplying fresh identifiers
+//! * [`Traced`](traced::Traced), the traced comonad over a
+//!   [`Monoid`](monoid::Monoid)
+//! * [`Trampoline`](trampoline::Trampoline), a monad for stack-safe
+//!   computations
+//! * [`Tree`](tree::Tree), a rose tree
+//! * [`VecZipper`](zipper::VecZipper), a comonad of vectors with a focused
+//!   element
+//! * [`WriterT`](writer_t::WriterT), the monad transformer adding an
+//!   accumulated log to a base monad
+//!
+//! The [`poll`] module provides helpers for the `Poll<Option<A>>` shape
+//! used by streams.
+//!
+//! [`bind`]: Monad::bind
+//!
+//! # Optional features
+//!
+//! * `arrayvec`: implementations for `ArrayVec` of the `arrayvec` crate,
+//!   where [`bind`](Monad::bind) panics if the capacity is exceeded
+//! * `crossbeam`: conversion of `Receiver`s of the `crossbeam-channel`
+//!   crate into [`MappedReceiver`](receiver::MappedReceiver)s
+//! * `dashmap`: implementations for `DashMap` of the `dashmap` crate
+//! * `either`: implementations for `Either` of the `either` crate
+//! * `futures`: implementations for boxed `Stream`s of the `futures` crate
+//! * `hashbrown`: implementations for `HashMap` and `HashSet` of the
+//!   `hashbrown` crate
+//! * `higher`: module `higher_interop` with a wrapper providing this
+//!   crate's [`Monad`] for types implementing the traits of the `higher`
+//!   crate
+//! * `im`: implementations for `Vector`, `HashMap`, and `OrdMap` of the `im`
+//!   crate
+//! * `indexmap`: implementations for `IndexMap` and `IndexSet` of the
+//!   `indexmap` crate, which preserve insertion order
+//! * `ndarray`: implementations for `Array` of the `ndarray` crate, where
+//!   [`Applicative::apply`] zips arrays element-wise (with broadcasting)
+//! * `parking_lot`: implementations for `Mutex` and `RwLock` of the
+//!   `parking_lot` crate
+//! * `petgraph`: implementations for `Graph` of the `petgraph` crate,
+//!   which is a [`Functor`] over its node weights and a [`Bifunctor`] over
+//!   its edge and node weights
+//! * `rand`: module `random` with a random-generation monad `Gen`
+//! * `rayon`: module `par_functor` with a trait `ParFunctor` for mapping
+//!   collections in parallel using the `rayon` crate
+//! * `serde_json`: module `json` with functor wrappers for arrays and
+//!   objects of the `serde_json` crate
+//! * `slotmap`: implementations for the maps of the `slotmap` crate, where
+//!   `SlotMap` and `DenseSlotMap` can only be mapped without changing the
+//!   value type (e.g. with [`FunctorMut`]), because keys can't be recreated
+//!   in a new map
+//! * `smallvec`: implementations for `SmallVec` of the `smallvec` crate
+//! * `threads`: [`Functor`] implementation for
+//!   [`JoinHandle`](std::thread::JoinHandle), which maps the result in a
+//!   new thread
+//! * `tinyvec`: implementations for `TinyVec` and `ArrayVec` of the
+//!   `tinyvec` crate, which require elements to implement [`Default`] and
+//!   where [`bind`](Monad::bind) on an `ArrayVec` panics if the capacity is
+//!   exceeded
+//! * `tokio`: [`Functor`] implementation for `oneshot::Receiver` of the
+//!   `tokio` crate, which maps the received value in a new task (or in a new
+//!   thread when used outside of a Tokio runtime), and module
+//!   `tokio_receiver` with a mapped wrapper for `mpsc` receivers
+//! * `tower`: module `service` with a wrapper for `Service`s of the `tower`
+//!   crate, which maps responses and requests
 //!
 //! # Caveats
 //!
@@ -88,14 +259,75 @@
 
 #![warn(missing_docs)]
 
+pub mod backwards;
+pub mod codensity;
+pub mod cofree;
+pub mod comparison;
+pub mod cont;
+pub mod cont_t;
+pub mod coyoneda;
+pub mod day;
+pub mod dist;
+pub mod env;
+pub mod except_t;
+pub mod fix;
+pub mod free;
+pub mod free_ap;
+pub mod free_t;
+pub mod freer;
+pub mod fut;
+#[cfg(feature = "higher")]
+pub mod higher_interop;
+pub mod identity_t;
 mod impls;
+pub mod io;
+pub mod ix_state;
+#[cfg(feature = "serde_json")]
+pub mod json;
+pub mod kleisli;
+pub mod lazy;
+pub mod lazy_iter;
+pub mod logic;
+pub mod memo;
+pub mod merge_map;
+pub mod monoid;
+pub mod on_err;
+pub mod option_t;
+pub mod par;
+#[cfg(feature = "rayon")]
+pub mod par_functor;
+pub mod parser;
+pub mod poll;
+pub mod predicate;
+pub mod profunctor;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod reader_t;
+pub mod receiver;
+pub mod rws_t;
+#[cfg(feature = "tower")]
+pub mod service;
+pub mod star;
+pub mod state_t;
+pub mod static_coyoneda;
+pub mod store;
+pub mod supply;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "tokio")]
+pub mod tokio_receiver;
+pub mod traced;
+pub mod trampoline;
+pub mod tree;
 pub mod universal;
+pub mod writer_t;
+pub mod zipper;
 
 #[cfg(doc)]
 use universal::UniversalFunctor;
 
+use std::sync::{Arc, Mutex};
+
 /// A [`Functor`] that can be mapped to itself
 ///
 /// This trait should be required as bound when the compiler shall infer that
@@ -285,6 +517,37 @@ where
         F: 'a + Send + FnMut(&mut Self::Inner);
 }
 
+/// A [`Functor`] whose inner values are associated with keys
+///
+/// This trait is implemented for maps, where [`fmap_with_key`] passes a
+/// reference to each key along with the value to the mapping function.
+///
+/// [`fmap_with_key`]: Self::fmap_with_key
+///
+/// # Examples
+///
+/// ```
+/// use fmap::FunctorWithKey;
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([("a", 1), ("b", 2)]);
+/// let described = map.fmap_with_key(|k, v| format!("{k}={v}"));
+/// assert_eq!(described["b"], "b=2");
+/// ```
+pub trait FunctorWithKey<'a, B>
+where
+    Self: Functor<'a, B>,
+    B: 'a,
+{
+    /// Type of the keys
+    type Key;
+
+    /// Replace inner values using a closure that also receives the key
+    fn fmap_with_key<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(&Self::Key, Self::Inner) -> B;
+}
+
 /// A [`Contravariant`] functor that can be mapped to itself
 ///
 /// This trait should be required as bound when the compiler shall infer that
@@ -375,6 +638,132 @@ where
         F: 'a + Send + FnMut(&mut Self::Inner);
 }
 
+/// A [`Contravariant`] functor whose consumed values can be split into two
+/// parts
+///
+/// [`divide`] creates a consumer of `A` from a consumer of `self`'s [inner
+/// type] and a consumer of `C` (which is `self` but consuming `C`). The
+/// closure passed to `divide` splits each consumed `A` into the two parts.
+/// [`conquer`] is a consumer that ignores the consumed value, which is the
+/// neutral element of `divide`.
+///
+/// [`divide`]: Self::divide
+/// [`conquer`]: Self::conquer
+/// [inner type]: Contravariant::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Divisible;
+/// use fmap::predicate::Predicate;
+///
+/// let positive = Predicate::new(|x: &i32| *x > 0);
+/// let short = Predicate::new(|s: &String| s.len() < 3);
+/// let mut both = positive.divide(short, |(x, s): (i32, String)| (x, s));
+/// assert!(both.test(&(1, "ab".to_string())));
+/// assert!(!both.test(&(1, "abc".to_string())));
+/// assert!(!both.test(&(0, "ab".to_string())));
+/// ```
+pub trait Divisible<'a, A, C>
+where
+    Self: Contravariant<'a, A>,
+    A: 'a,
+    C: 'a,
+{
+    /// `Self` but consuming `C` instead of [`Contravariant::Inner`]
+    type Other;
+
+    /// Consume both parts of the values returned by `f`
+    fn divide<F>(self, other: Self::Other, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> (Self::Inner, C);
+
+    /// Consumer ignoring the consumed value
+    fn conquer() -> Self::Mapped;
+}
+
+/// A [`Divisible`] functor that can choose between two consumers
+///
+/// [`choose`] passes each consumed value to `self` or `other`, depending on
+/// whether the closure returns [`Ok`] or [`Err`]. [`lose`] creates a
+/// consumer of a type that can't be constructed.
+///
+/// [`choose`]: Self::choose
+/// [`lose`]: Self::lose
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Decidable;
+/// use fmap::predicate::Predicate;
+///
+/// let even = Predicate::new(|x: &i32| x % 2 == 0);
+/// let empty = Predicate::new(|s: &String| s.is_empty());
+/// let mut either = even.choose(empty, |x: Result<i32, String>| x);
+/// assert!(either.test(&Ok(2)));
+/// assert!(!either.test(&Ok(3)));
+/// assert!(either.test(&Err(String::new())));
+/// ```
+pub trait Decidable<'a, A, C>
+where
+    Self: Divisible<'a, A, C>,
+    A: 'a,
+    C: 'a,
+{
+    /// Consume values with `self` or `other`, depending on `f`
+    fn choose<F>(self, other: Self::Other, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Result<Self::Inner, C>;
+
+    /// Consumer of values that can't exist
+    fn lose<F>(f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> std::convert::Infallible;
+}
+
+/// Type with two type parameters that can both be mapped
+///
+/// [`bimap`](Self::bimap) maps [`Left`](Self::Left) to `C` and
+/// [`Right`](Self::Right) to `D`. For types that are also a [`Functor`], the
+/// right type is always the [inner type] (as in Haskell), such that
+/// `.bimap(|l| l, f)` is equivalent to `.fmap(f)`. Thus for [`Result`], the
+/// left type is the error type and the right type is the success type.
+///
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Bifunctor;
+///
+/// let ok: Result<i32, &str> = Ok(4);
+/// assert_eq!(ok.bimap(str::len, |x| x * 2), Ok(8));
+/// let err: Result<i32, &str> = Err("bad");
+/// assert_eq!(err.bimap(str::len, |x| x * 2), Err(3));
+/// ```
+pub trait Bifunctor<'a, C, D>
+where
+    Self: Sized,
+    C: 'a,
+    D: 'a,
+{
+    /// First (left) type parameter
+    type Left: 'a;
+
+    /// Second (right) type parameter
+    type Right: 'a;
+
+    /// `Self` with [`Left`](Self::Left) replaced by `C` and
+    /// [`Right`](Self::Right) replaced by `D`
+    type Mapped;
+
+    /// Replace both type parameters using two mapping functions
+    fn bimap<F, G>(self, f: F, g: G) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Left) -> C,
+        G: 'a + Send + FnMut(Self::Right) -> D;
+}
+
 /// A [`Functor`] that provides a [`pure`] operation to wrap a single inner
 /// value
 ///
@@ -411,8 +800,9 @@ where
 /// will thus not short-circuit when a [`Result::Err`] is returned. Therefore,
 /// it rather behaves like `.then` (instead of `.and_then`) on futures.
 ///
-/// Nested monads automatically implement [`NestedMonad`] and can be joined
-/// with [`NestedMonad::mjoin`], which is equivalent to `.bind(|x| x)`.
+/// Nested monads can be joined with [`Monad::join_monad`]. They also
+/// automatically implement [`NestedMonad`], which provides
+/// [`NestedMonad::mjoin`] for generic code.
 ///
 /// [`Future`]: std::future::Future
 /// [`Future::Output`]: std::future::Future::Output
@@ -447,6 +837,88 @@ where
     fn bind<F>(self, f: F) -> Self::Mapped
     where
         F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped;
+
+    /// Call function for each [inner value], discarding the inner value
+    ///
+    /// `.mthen(f)` is equivalent to `.bind(move |_| f())`.
+    ///
+    /// This method isn't named `then` to avoid ambiguities with methods like
+    /// [`bool::then`] or `FutureExt::then` of the `futures` crate.
+    ///
+    /// [inner value]: Functor::Inner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmap::Monad;
+    ///
+    /// let mut count = 0;
+    /// let a = vec![1, 2, 3].mthen(|| {
+    ///     count += 1;
+    ///     vec![count * 10]
+    /// });
+    /// assert_eq!(a, vec![10, 20, 30]);
+    /// ```
+    #[doc(alias = "then")]
+    fn mthen<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut() -> Self::Mapped,
+    {
+        self.bind(move |_| f())
+    }
+
+    /// Replace each [inner value] with `next`, discarding the inner value
+    ///
+    /// `.followed_by(next)` is equivalent to
+    /// `.bind(move |_| next.clone())`.
+    ///
+    /// [inner value]: Functor::Inner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmap::Monad;
+    ///
+    /// assert_eq!(Some(1).followed_by(Some("x")), Some("x"));
+    /// assert_eq!(None::<i32>.followed_by(Some("x")), None);
+    /// assert_eq!(vec![1, 2].followed_by(vec![7, 8]), vec![7, 8, 7, 8]);
+    /// ```
+    fn followed_by(self, next: Self::Mapped) -> Self::Mapped
+    where
+        Self::Mapped: 'a + Send + Clone,
+    {
+        self.bind(move |_| next.clone())
+    }
+
+    /// Join nested monad, i.e. flatten `M<M<B>>` into `M<B>`
+    ///
+    /// `.join_monad()` is equivalent to `.bind(|x| x)` and can be called
+    /// when the [inner type] is `Self::Mapped` (see [`Same`]).
+    /// Implementations may override this method if there is a more efficient
+    /// way to join the monad.
+    ///
+    /// This method isn't named `join` or `flatten` to avoid ambiguities with
+    /// methods like [`slice::join`] or [`Iterator::flatten`].
+    ///
+    /// [inner type]: Functor::Inner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmap::Monad;
+    ///
+    /// let nested = vec![vec![1, 3], vec![2, 9, 9]];
+    /// assert_eq!(nested.join_monad(), vec![1, 3, 2, 9, 9]);
+    /// assert_eq!(Some(Some(4)).join_monad(), Some(4));
+    /// ```
+    #[doc(alias = "join")]
+    #[doc(alias = "flatten")]
+    fn join_monad(self) -> Self::Mapped
+    where
+        Self::Inner: Same<Self::Mapped>,
+    {
+        self.bind(|x| x.same())
+    }
 }
 
 /// Nested monad that can be [joined]
@@ -491,7 +963,13 @@ where
     type InnerMonad: Functor<'a, A>;
     /// Generic join
     ///
-    /// `.mjoin()` is equivalent to `.bind(|x| x)`.
+    /// `.mjoin()` is equivalent to `.bind(|x| x)`, but the result type
+    /// doesn't need to be inferred from the closure.
+    ///
+    /// This method isn't named `join` or `flatten` to avoid ambiguities with
+    /// methods like [`slice::join`] or [`Iterator::flatten`].
+    #[doc(alias = "join")]
+    #[doc(alias = "flatten")]
     fn mjoin(self) -> A {
         self.bind(|x| x)
     }
@@ -507,6 +985,23 @@ where
     type InnerMonad = A;
 }
 
+/// Type equality
+///
+/// `T: Same<U>` is implemented if and only if `T` and `U` are the same type.
+/// It is used for bounds such as the one of [`Monad::join_monad`], because an
+/// equality bound between associated types would interfere with normalizing
+/// them in implementations.
+pub trait Same<T> {
+    /// Return `self` unchanged
+    fn same(self) -> T;
+}
+
+impl<T> Same<T> for T {
+    fn same(self) -> T {
+        self
+    }
+}
+
 /// Generic implementation of [`Functor::fmap`] for [`Monad`]s
 ///
 /// This generic implementation can be used to define `Functor::fmap` based on
@@ -651,3 +1146,908 @@ where
 {
     f.bind(move |inner| monad.clone().fmap(inner))
 }
+
+/// Combine two [`Monad`]s with a function of their [inner values]
+///
+/// The second monad is cloned for every inner value of the first monad,
+/// and inner values of the first monad are cloned for every inner value of
+/// the second monad. This is also known as `liftA2` or `lift2`.
+///
+/// [inner values]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::map2;
+///
+/// assert_eq!(map2(Some(3), Some(4), |a, b| a * b), Some(12));
+/// assert_eq!(map2(Some(3), None, |a, b: i32| a * b), None);
+/// assert_eq!(
+///     map2(vec![1, 2], vec!["a", "b"], |n, s| s.repeat(n)),
+///     vec!["a", "b", "aa", "bb"],
+/// );
+/// ```
+#[doc(alias = "lift2")]
+#[doc(alias = "liftA2")]
+pub fn map2<'a, MA, MB, C, F>(
+    ma: MA,
+    mb: MB,
+    f: F,
+) -> <MA as Functor<'a, C>>::Mapped
+where
+    MA: Monad<'a, C>,
+    <MA as Functor<'a, C>>::Inner: Send + Clone,
+    MB: 'a + Send + Clone,
+    MB: Functor<'a, C, Mapped = <MA as Functor<'a, C>>::Mapped>,
+    C: 'a,
+    F: 'a + Send + FnMut(<MA as Functor<'a, C>>::Inner, MB::Inner) -> C,
+{
+    let f = Arc::new(Mutex::new(f));
+    ma.bind(move |a| {
+        let f = f.clone();
+        mb.clone().fmap(move |b| (f.lock().unwrap())(a.clone(), b))
+    })
+}
+
+/// Combine three [`Monad`]s with a function of their [inner values]
+///
+/// See [`map2`].
+///
+/// [inner values]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::map3;
+///
+/// let sum = map3(Ok(1), Ok(2), Ok::<_, ()>(3), |a, b, c| a + b + c);
+/// assert_eq!(sum, Ok(6));
+/// ```
+#[doc(alias = "lift3")]
+#[doc(alias = "liftA3")]
+pub fn map3<'a, MA, MB, MC, D, F>(
+    ma: MA,
+    mb: MB,
+    mc: MC,
+    f: F,
+) -> <MA as Functor<'a, D>>::Mapped
+where
+    MA: Monad<'a, D>,
+    <MA as Functor<'a, D>>::Inner: Send + Clone,
+    MB: 'a + Send + Clone,
+    MB: Monad<'a, D, Mapped = <MA as Functor<'a, D>>::Mapped>,
+    <MB as Functor<'a, D>>::Inner: Send + Clone,
+    MC: 'a + Send + Clone,
+    MC: Functor<'a, D, Mapped = <MA as Functor<'a, D>>::Mapped>,
+    D: 'a,
+    F: 'a
+        + Send
+        + FnMut(
+            <MA as Functor<'a, D>>::Inner,
+            <MB as Functor<'a, D>>::Inner,
+            MC::Inner,
+        ) -> D,
+{
+    let f = Arc::new(Mutex::new(f));
+    ma.bind(move |a| {
+        let f = f.clone();
+        let mc = mc.clone();
+        mb.clone().bind(move |b| {
+            let f = f.clone();
+            let a = a.clone();
+            mc.clone().fmap(move |c| {
+                (f.lock().unwrap())(a.clone(), b.clone(), c)
+            })
+        })
+    })
+}
+
+type PushMapper<'a, A> = Box<dyn 'a + Send + FnMut(A) -> Vec<A>>;
+
+/// Collect the [inner values] of several [`Applicative`] functors into a
+/// single functor containing a [`Vec`]
+///
+/// The functors are combined from left to right with
+/// [`Applicative::apply`]. Thus the result of [`Option`]s or [`Result`]s
+/// is [`None`] or the first [`Err`] if any of them failed, and futures are
+/// awaited one after another in the order of the iterator. Unlike
+/// [`Iterator::collect`], the iterator is always consumed completely.
+///
+/// [inner values]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::sequence;
+///
+/// assert_eq!(sequence(vec![Some(1), Some(2)]), Some(vec![1, 2]));
+/// assert_eq!(sequence(vec![Some(1), None]), None);
+/// assert_eq!(sequence([Ok(1), Err("a"), Err("b")]), Err("a"));
+/// assert_eq!(
+///     sequence(vec![vec![1, 2], vec![3]]),
+///     vec![vec![1, 3], vec![2, 3]],
+/// );
+/// ```
+pub fn sequence<'a, I, M, A>(
+    iter: I,
+) -> <M as Functor<'a, Vec<A>>>::Mapped
+where
+    I: IntoIterator<Item = M>,
+    M: Functor<'a, Vec<A>, Inner = A>,
+    M: Applicative<'a, Vec<A>>,
+    M: Pure<'a, PushMapper<'a, A>>,
+    <M as Functor<'a, Vec<A>>>::Mapped: Functor<
+        'a,
+        PushMapper<'a, A>,
+        Inner = Vec<A>,
+        Mapped = <M as Functor<'a, PushMapper<'a, A>>>::Mapped,
+    >,
+    A: Clone + Send,
+{
+    let mut acc = <M as Pure<'a, Vec<A>>>::pure(Vec::new());
+    for m in iter {
+        let mappers = acc.fmap(|vec| {
+            Box::new(move |a| {
+                let mut vec = vec.clone();
+                vec.push(a);
+                vec
+            }) as PushMapper<'a, A>
+        });
+        acc = m.apply(mappers);
+    }
+    acc
+}
+
+/// Apply a function returning an [`Applicative`] functor to each item of
+/// an iterator and [collect] the results
+///
+/// `traverse(iter, f)` is equivalent to
+/// `sequence(iter.into_iter().map(f))`. In particular, futures are awaited
+/// one after another in the order of the iterator. See also
+/// [`Tree::traverse`](tree::Tree::traverse).
+///
+/// [collect]: sequence
+///
+/// # Examples
+///
+/// ```
+/// use fmap::traverse;
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// assert_eq!(traverse(["1", "2"], parse), Ok(vec![1, 2]));
+/// assert!(traverse(["1", "x"], parse).is_err());
+/// ```
+pub fn traverse<'a, I, M, A, F>(
+    iter: I,
+    f: F,
+) -> <M as Functor<'a, Vec<A>>>::Mapped
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> M,
+    M: Functor<'a, Vec<A>, Inner = A>,
+    M: Applicative<'a, Vec<A>>,
+    M: Pure<'a, PushMapper<'a, A>>,
+    <M as Functor<'a, Vec<A>>>::Mapped: Functor<
+        'a,
+        PushMapper<'a, A>,
+        Inner = Vec<A>,
+        Mapped = <M as Functor<'a, PushMapper<'a, A>>>::Mapped,
+    >,
+    A: Clone + Send,
+{
+    sequence(iter.into_iter().map(f))
+}
+
+/// Repeat an [`Applicative`] functor `n` times and [collect] the results
+///
+/// `replicate_m(n, m)` is equivalent to
+/// `sequence(std::iter::repeat(m).take(n))`. For a [`Vec`], this results in
+/// all vectors of length `n` with elements of the original vector. Use
+/// [`replicate_m_iter`] to generate these lazily.
+///
+/// [collect]: sequence
+///
+/// # Examples
+///
+/// ```
+/// use fmap::replicate_m;
+///
+/// assert_eq!(replicate_m(3, Some(1)), Some(vec![1, 1, 1]));
+/// assert_eq!(
+///     replicate_m(2, vec![0, 1]),
+///     vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]],
+/// );
+/// ```
+#[doc(alias = "replicateM")]
+// `std::iter::repeat_n` would require Rust 1.82
+#[allow(clippy::manual_repeat_n)]
+pub fn replicate_m<'a, M, A>(
+    n: usize,
+    m: M,
+) -> <M as Functor<'a, Vec<A>>>::Mapped
+where
+    M: Clone,
+    M: Functor<'a, Vec<A>, Inner = A>,
+    M: Applicative<'a, Vec<A>>,
+    M: Pure<'a, PushMapper<'a, A>>,
+    <M as Functor<'a, Vec<A>>>::Mapped: Functor<
+        'a,
+        PushMapper<'a, A>,
+        Inner = Vec<A>,
+        Mapped = <M as Functor<'a, PushMapper<'a, A>>>::Mapped,
+    >,
+    A: Clone + Send,
+{
+    sequence(std::iter::repeat(m).take(n))
+}
+
+/// Lazily generate all vectors of length `n` whose elements are items of
+/// an iterator
+///
+/// This is the lazy variant of [`replicate_m`] for iterators. The iterator
+/// is cloned for each position of each generated vector, and the vectors
+/// are generated in lexicographic order.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::replicate_m_iter;
+///
+/// let mut words = replicate_m_iter(3, "ab".chars())
+///     .map(|chars| chars.into_iter().collect::<String>());
+/// assert_eq!(words.next().as_deref(), Some("aaa"));
+/// assert_eq!(words.next().as_deref(), Some("aab"));
+/// assert_eq!(words.count(), 6);
+/// ```
+pub fn replicate_m_iter<'a, I>(
+    n: usize,
+    iter: I,
+) -> Box<dyn 'a + Iterator<Item = Vec<I::Item>>>
+where
+    I: IntoIterator,
+    I::IntoIter: 'a + Clone,
+    I::Item: 'a + Clone,
+{
+    let iter = iter.into_iter();
+    let mut acc: Box<dyn 'a + Iterator<Item = Vec<I::Item>>> =
+        Box::new(std::iter::once(Vec::with_capacity(n)));
+    for _ in 0..n {
+        let iter = iter.clone();
+        acc = Box::new(acc.flat_map(move |vec| {
+            iter.clone().map(move |item| {
+                let mut vec = vec.clone();
+                vec.push(item);
+                vec
+            })
+        }));
+    }
+    acc
+}
+
+/// Fold an iterator with a function returning a [`Monad`]
+///
+/// Starting with [`Pure::pure`] of `init`, the accumulator is [bound] to
+/// `f` for each item of the iterator. Thus `f` isn't called anymore once
+/// an [`Option`] or [`Result`] failed, and futures are awaited one after
+/// another. Items are cloned because `f` may be called several times per
+/// item (e.g. for a [`Vec`]). The iterator is always consumed completely.
+///
+/// [bound]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::fold_m;
+///
+/// let checked_sum = |items: Vec<u8>| {
+///     fold_m(items, 0u8, |acc, x| acc.checked_add(x))
+/// };
+/// assert_eq!(checked_sum(vec![100, 100, 50]), Some(250));
+/// assert_eq!(checked_sum(vec![100, 100, 100]), None);
+/// ```
+#[doc(alias = "foldM")]
+pub fn fold_m<'a, I, M, B, F>(iter: I, init: B, f: F) -> M
+where
+    I: IntoIterator,
+    I::Item: 'a + Send + Clone,
+    M: Monad<'a, B, Inner = B, Mapped = M>,
+    B: 'a,
+    F: 'a + Send + FnMut(B, I::Item) -> M,
+{
+    let f = Arc::new(Mutex::new(f));
+    let mut acc = M::pure(init);
+    for item in iter {
+        let f = f.clone();
+        acc = acc.bind(move |b| (f.lock().unwrap())(b, item.clone()));
+    }
+    acc
+}
+
+/// Apply an array of functions to an array of values element-wise
+///
+/// This is the zipping [`Applicative::apply`] for arrays `[A; N]`, where
+/// the function at each index is applied to the value at the same index.
+/// Arrays don't implement `Applicative`, because the trait requires
+/// [`Pure`] for [boxed mappers], and `Pure` for arrays needs to [clone]
+/// its value to fill all `N` elements, which boxed closures can't do.
+/// As the functions are consumed, they may be [`FnOnce`] closures.
+///
+/// [boxed mappers]: BoxMapper
+/// [clone]: Clone
+///
+/// # Examples
+///
+/// ```
+/// use fmap::zip_apply;
+///
+/// let name = String::from("x");
+/// let fs: [Box<dyn FnOnce(i32) -> String>; 2] = [
+///     Box::new(|a| (a + 1).to_string()),
+///     Box::new(move |a| format!("{name}{a}")),
+/// ];
+/// assert_eq!(zip_apply(fs, [1, 2]), ["2", "x2"]);
+/// ```
+pub fn zip_apply<A, B, F, const N: usize>(
+    fs: [F; N],
+    values: [A; N],
+) -> [B; N]
+where
+    F: FnOnce(A) -> B,
+{
+    let mut fs = fs.into_iter();
+    values.map(|a| (fs.next().unwrap())(a))
+}
+
+/// A [`Monad`] that can represent failure with an error of type `E`
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadError;
+///
+/// let failed: Result<i32, String> =
+///     MonadError::throw_error("bad".to_string());
+/// let recovered = failed.catch_error(|e| Ok(e.len() as i32));
+/// assert_eq!(recovered, Ok(3));
+/// ```
+pub trait MonadError<'a, E>
+where
+    Self: Sized,
+{
+    /// Create a failed computation
+    fn throw_error(error: E) -> Self;
+
+    /// Handle failure by calling `handler` with the error
+    ///
+    /// The handler may recover or fail again.
+    fn catch_error<F>(self, handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(E) -> Self;
+}
+
+/// Monad transformer that can [lift] a computation of the base monad `N`
+///
+/// [lift]: Self::lift
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadTrans;
+/// use fmap::option_t::OptionT;
+///
+/// let lifted: OptionT<Vec<Option<i32>>, i32> = MonadTrans::lift(vec![1, 2]);
+/// assert_eq!(lifted.run(), vec![Some(1), Some(2)]);
+/// ```
+pub trait MonadTrans<'a, N>
+where
+    Self: Sized,
+{
+    /// Lift a computation of the base monad
+    fn lift(base: N) -> Self;
+}
+
+/// A [`Monad`] with a state of type `S`
+///
+/// This trait allows writing functions that work with any monad (or stack
+/// of [monad transformers]) that provides a state of type `S`. Transformers
+/// that don't provide a state themselves pass the state of their base monad
+/// through.
+///
+/// [monad transformers]: MonadTrans
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadState;
+/// use fmap::option_t::OptionT;
+/// use fmap::state_t::StateT;
+///
+/// fn next_id<'a, M>() -> M
+/// where
+///     M: MonadState<'a, u32, Value = u32>,
+/// {
+///     M::state(|id| (id, id + 1))
+/// }
+///
+/// type Counter<A> = StateT<'static, u32, Option<(A, u32)>, A>;
+/// let id: Counter<u32> = next_id();
+/// assert_eq!(id.run_state_t(5), Some((5, 6)));
+///
+/// type Stack<A> = OptionT<Counter<Option<A>>, A>;
+/// let id: Stack<u32> = next_id();
+/// assert_eq!(id.run().run_state_t(7), Some((Some(7), 8)));
+/// ```
+pub trait MonadState<'a, S>
+where
+    Self: Sized,
+{
+    /// Result type of the computation (i.e. the [inner type])
+    ///
+    /// [inner type]: Functor::Inner
+    type Value;
+
+    /// Create computation from a state transition function
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (Self::Value, S);
+
+    /// Retrieve current state
+    fn get() -> Self
+    where
+        Self: MonadState<'a, S, Value = S>,
+        S: Clone,
+    {
+        Self::state(|s| (s.clone(), s))
+    }
+
+    /// Replace state
+    fn put(state: S) -> Self
+    where
+        Self: MonadState<'a, S, Value = ()>,
+        S: 'a + Send,
+    {
+        Self::state(move |_| ((), state))
+    }
+
+    /// Modify state with a closure
+    fn modify<F>(f: F) -> Self
+    where
+        Self: MonadState<'a, S, Value = ()>,
+        F: 'a + Send + FnOnce(S) -> S,
+    {
+        Self::state(move |s| ((), f(s)))
+    }
+}
+
+/// A [`Monad`] with a read-only environment of type `R`
+///
+/// This trait allows writing functions that work with any monad (or stack
+/// of [monad transformers]) that provides an environment of type `R`.
+/// Transformers that don't provide an environment themselves pass the
+/// environment of their base monad through.
+///
+/// [monad transformers]: MonadTrans
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadReader;
+/// use fmap::except_t::ExceptT;
+/// use fmap::reader_t::ReaderT;
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// fn greeting<'a, M>() -> M
+/// where
+///     M: MonadReader<'a, &'a Config, Value = String>,
+/// {
+///     M::reader(|config| format!("Hello {}", config.name))
+/// }
+///
+/// let config = Config { name: "World".to_string() };
+///
+/// type App<'a, A> = ReaderT<'a, &'a Config, Option<A>, A>;
+/// let app: App<String> = greeting();
+/// assert_eq!(app.run_reader_t(&config).as_deref(), Some("Hello World"));
+///
+/// type Fallible<'a, A> = ExceptT<App<'a, Result<A, ()>>, (), A>;
+/// let fallible: Fallible<String> = greeting();
+/// assert_eq!(
+///     fallible.run().run_reader_t(&config),
+///     Some(Ok("Hello World".to_string())),
+/// );
+/// ```
+pub trait MonadReader<'a, R>
+where
+    Self: Sized,
+{
+    /// Result type of the computation (i.e. the [inner type])
+    ///
+    /// [inner type]: Functor::Inner
+    type Value;
+
+    /// Create computation from a function of the environment
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> Self::Value;
+
+    /// Run computation with an environment modified by a closure
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R;
+
+    /// Retrieve environment
+    fn ask() -> Self
+    where
+        Self: MonadReader<'a, R, Value = R>,
+    {
+        Self::reader(|r| r)
+    }
+}
+
+/// A [`Monad`] that accumulates a log of type `W`
+///
+/// The log is usually a [`Monoid`](monoid::Monoid). This trait allows
+/// writing functions that work with any monad (or stack of [monad
+/// transformers]) that provides such a log. Transformers that don't provide
+/// a log themselves pass the log of their base monad through.
+///
+/// [monad transformers]: MonadTrans
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadWriter;
+/// use fmap::option_t::OptionT;
+/// use fmap::writer_t::WriterT;
+///
+/// fn logged<'a, M>(x: i32) -> M
+/// where
+///     M: MonadWriter<'a, Vec<String>, Value = i32>,
+/// {
+///     M::writer(x, vec![format!("got {x}")])
+/// }
+///
+/// type Logged<A> = WriterT<Vec<String>, Vec<(A, Vec<String>)>, A>;
+/// let value: Logged<i32> = logged(5);
+/// let listened = value.censor(|log| vec![log.join(", ").to_uppercase()]);
+/// assert_eq!(listened.run_writer_t(), vec![(5, vec!["GOT 5".to_string()])]);
+///
+/// type Stack<A> = OptionT<Logged<Option<A>>, A>;
+/// let value: Stack<i32> = logged(7);
+/// assert_eq!(
+///     value.listen().run().run_writer_t(),
+///     vec![(Some((7, vec!["got 7".to_string()])), vec!["got 7".to_string()])],
+/// );
+/// ```
+pub trait MonadWriter<'a, W>
+where
+    Self: Sized,
+{
+    /// Result type of the computation (i.e. the [inner type])
+    ///
+    /// [inner type]: Functor::Inner
+    type Value;
+    /// Type of the computation returned by [`listen`](Self::listen), which
+    /// has the result type `(Self::Value, W)`
+    type Listened;
+
+    /// Create computation from a result and a log
+    fn writer(value: Self::Value, log: W) -> Self;
+
+    /// Add the log to the result
+    fn listen(self) -> Self::Listened;
+
+    /// Modify the log with a closure
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W;
+
+    /// Append to the log
+    fn tell(log: W) -> Self
+    where
+        Self: MonadWriter<'a, W, Value = ()>,
+    {
+        Self::writer((), log)
+    }
+}
+
+/// A [`Monad`] that allows capturing the current continuation
+///
+/// This trait exposes [`call_cc`](Self::call_cc) generically. It is
+/// implemented for the continuation monads [`Cont`](cont::Cont) and
+/// [`ContT`](cont_t::ContT), and lifted through other transformers.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadCont, Pure};
+/// use fmap::cont::Cont;
+/// use fmap::option_t::OptionT;
+///
+/// fn clamp<'a, M>(x: i32) -> M
+/// where
+///     M: MonadCont<'a, Value = i32> + Monad<'a, i32, Inner = i32, Mapped = M>,
+/// {
+///     M::call_cc(move |exit| {
+///         if x > 10 {
+///             M::escape(&exit, 10)
+///         } else {
+///             M::pure(x)
+///         }
+///         .bind(|x| M::pure(x + 1))
+///     })
+/// }
+///
+/// let small: Cont<'static, String, i32> = clamp(5);
+/// assert_eq!(small.run(|x| x.to_string()), "6");
+/// let large: Cont<'static, String, i32> = clamp(50);
+/// assert_eq!(large.run(|x| x.to_string()), "10");
+///
+/// type Stack<A> = OptionT<Cont<'static, String, Option<A>>, A>;
+/// let large: Stack<i32> = clamp(50);
+/// assert_eq!(large.run().run(|x| format!("{x:?}")), "Some(10)");
+/// ```
+pub trait MonadCont<'a>
+where
+    Self: Sized,
+{
+    /// Result type of the computation (i.e. the [inner type])
+    ///
+    /// [inner type]: Functor::Inner
+    type Value;
+    /// Escape continuation passed to the closure of
+    /// [`call_cc`](Self::call_cc)
+    type Escape;
+
+    /// Call with current continuation
+    ///
+    /// Calls `f` with an escape continuation that allows to leave the
+    /// computation early (see [`escape`](Self::escape)).
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self;
+
+    /// Computation that passes `value` to the captured continuation
+    fn escape(escape: &Self::Escape, value: Self::Value) -> Self;
+}
+
+/// A [`Monad`] that can perform side effects
+///
+/// [`lift_io`](Self::lift_io) lifts a side-effecting thunk into the monad
+/// with a single call, no matter how many transformer layers are stacked
+/// above the base monad. The trait is implemented for [`Io`](io::Io),
+/// boxed [`Future`](std::future::Future)s, and boxed [`FnOnce`] thunks,
+/// and lifted through other transformers.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadIo};
+/// use fmap::io::Io;
+/// use fmap::option_t::OptionT;
+/// use fmap::state_t::StateT;
+///
+/// type App<A> = StateT<
+///     'static,
+///     u32,
+///     OptionT<Io<'static, Option<(A, u32)>>, (A, u32)>,
+///     A,
+/// >;
+///
+/// let program = App::lift_io(|| 20).bind(|x| App::lift_io(move || x + 1));
+/// assert_eq!(program.run_state_t(7).run().run(), Some((21, 7)));
+/// ```
+pub trait MonadIo<'a>
+where
+    Self: Sized,
+{
+    /// Result type of the computation (i.e. the [inner type])
+    ///
+    /// [inner type]: Functor::Inner
+    type Value;
+
+    /// Lift a side-effecting thunk into the monad
+    ///
+    /// The thunk is executed when the computation is run.
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> Self::Value;
+}
+
+/// A [`Monad`] that can acquire and safely release resources
+///
+/// [`bracket`](Self::bracket) guarantees that the release action runs both
+/// when the body succeeds and when it fails. Failure means an [`Err`] for
+/// [`Result`], and a panic for [`Io`](io::Io) and boxed
+/// [`Future`](std::future::Future)s (where the release action is a future
+/// itself, which allows asynchronous cleanup).
+///
+/// The resource is passed to both the body and the release action, which
+/// is why it must implement [`Clone`]. Resources that can't be cloned may be
+/// wrapped in an [`Arc`].
+///
+/// # Examples
+///
+/// ```
+/// use fmap::MonadBracket;
+/// use std::sync::{Arc, Mutex};
+///
+/// let released = Arc::new(Mutex::new(false));
+/// let result: Result<i32, &str> = Result::bracket(
+///     Ok(released.clone()),
+///     |_| Err("failed"),
+///     |flag| {
+///         *flag.lock().unwrap() = true;
+///         Ok(())
+///     },
+/// );
+/// assert_eq!(result, Err("failed"));
+/// assert!(*released.lock().unwrap());
+/// ```
+pub trait MonadBracket<'a, B>
+where
+    Self: Monad<'a, B>,
+    Self: Functor<'a, ()>,
+    B: 'a,
+{
+    /// Acquire a resource, use it, and release it afterwards
+    ///
+    /// If acquiring the resource fails, neither `body` nor `release` is
+    /// executed. If `release` fails after `body` succeeded, the failure of
+    /// `release` is returned; otherwise the result of `body` is returned.
+    fn bracket<U, R>(
+        acquire: Self,
+        body: U,
+        release: R,
+    ) -> <Self as Functor<'a, B>>::Mapped
+    where
+        <Self as Functor<'a, B>>::Inner: Clone,
+        U: 'a
+            + Send
+            + FnOnce(
+                <Self as Functor<'a, B>>::Inner,
+            ) -> <Self as Functor<'a, B>>::Mapped,
+        R: 'a
+            + Send
+            + FnOnce(
+                <Self as Functor<'a, B>>::Inner,
+            ) -> <Self as Functor<'a, ()>>::Mapped;
+}
+
+/// A type with a choice operation and a neutral element for it
+///
+/// For types representing computations that may fail, [`or`] uses the
+/// second computation as fallback if the first one fails, and [`empty`] is
+/// the failing computation. For collections, [`or`] concatenates both
+/// collections.
+///
+/// [`or`]: Self::or
+/// [`empty`]: Self::empty
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Alternative;
+///
+/// fn first_some<T: Alternative>(values: Vec<T>) -> T {
+///     values.into_iter().fold(T::empty(), T::or)
+/// }
+///
+/// assert_eq!(first_some(vec![None, Some(2), Some(3)]), Some(2));
+/// assert_eq!(first_some(vec![vec![1], vec![], vec![2, 3]]), vec![1, 2, 3]);
+/// ```
+pub trait Alternative
+where
+    Self: Sized,
+{
+    /// Neutral element of [`or`](Self::or) (e.g. failure)
+    fn empty() -> Self;
+
+    /// Use `self` or `other`
+    fn or(self, other: Self) -> Self;
+}
+
+/// A [`Monad`] that is also [`Alternative`]
+///
+/// This trait is automatically implemented through a blanket
+/// implementation.
+pub trait MonadPlus<'a, B>
+where
+    Self: Monad<'a, B> + Alternative,
+    B: 'a,
+{
+}
+
+impl<'a, T, B> MonadPlus<'a, B> for T
+where
+    T: Monad<'a, B> + Alternative,
+    B: 'a,
+{
+}
+
+/// Type with a corresponding [`Applicative`] functor that runs effects in
+/// parallel (e.g. concurrently)
+///
+/// [`Monad`]s must run effects sequentially because later computations may
+/// depend on earlier results. Independent computations can be converted
+/// into the [parallel] counterpart, combined with [`Applicative::apply`], and
+/// converted back.
+///
+/// [parallel]: Self::Par
+pub trait Parallel
+where
+    Self: Sized,
+{
+    /// Counterpart running effects in parallel
+    type Par;
+
+    /// Convert into parallel counterpart
+    fn parallel(self) -> Self::Par;
+
+    /// Convert back from parallel counterpart
+    fn sequential(par: Self::Par) -> Self;
+}
+
+/// A [`Functor`] that provides an [`extract`] operation to retrieve a single
+/// inner value
+///
+/// This is the dual of [`Pure`].
+///
+/// [`extract`]: Self::extract
+pub trait Extract<'a, A>
+where
+    Self: FunctorSelf<'a, A>,
+    A: 'a,
+{
+    /// Extract the [inner value] in focus
+    ///
+    /// [inner value]: Functor::Inner
+    fn extract(self) -> A;
+}
+
+/// A [`Functor`] that is also a comonad
+///
+/// Comonads are dual to [`Monad`]s. Instead of wrapping a value with
+/// [`Pure::pure`], a value in focus can be [extracted]. Instead of binding a
+/// function that returns a wrapped value, a function that takes a comonad can
+/// be used to [extend] a comonad, i.e. the function is called for each
+/// position in the comonad with the comonad being focused on that position.
+///
+/// [extracted]: Extract::extract
+/// [extend]: Self::extend
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Extract};
+/// use fmap::store::Store;
+///
+/// let values = vec![1, 2, 3, 4];
+/// let store = Store::new(move |i: usize| values.get(i).copied(), 0);
+/// let sums = store.extend(|s| {
+///     let i = *s.pos();
+///     s.peek(i + 1).map(|next| s.extract().unwrap() + next)
+/// });
+/// assert_eq!(sums.peek(0), Some(3));
+/// assert_eq!(sums.peek(2), Some(7));
+/// assert_eq!(sums.peek(3), None);
+/// ```
+pub trait Comonad<'a, B>
+where
+    Self: Functor<'a, B>,
+    Self: Extract<'a, <Self as Functor<'a, B>>::Inner>,
+    B: 'a,
+{
+    /// Call function for each position, returning [mapped] version of `Self`
+    ///
+    /// The function `f` is called with `Self` focused on the respective
+    /// position.
+    ///
+    /// [mapped]: Functor::Mapped
+    fn extend<F>(self, f: F) -> <Self as Functor<'a, B>>::Mapped
+    where
+        F: 'a + Send + FnMut(Self) -> B;
+}
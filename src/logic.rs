@@ -0,0 +1,230 @@
+//! Backtracking with fair interleaving
+//!
+//! See [`Logic`].
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Backtracking monad (lazy stream of results)
+///
+/// A `Logic<'a, A>` lazily produces a (possibly infinite) sequence of
+/// results. [`Monad::bind`] explores all results of the first alternative
+/// before continuing with the next one (like the [`Vec`] monad, but lazily).
+/// In addition, `Logic` supports fair disjunction with
+/// [`Logic::interleave`] and fair conjunction with [`Logic::fair_bind`],
+/// such that a search doesn't get stuck in one infinite branch. The number
+/// of results can be limited with [`Logic::once`].
+///
+/// Results can be obtained with [`Logic::split`] or by iterating over a
+/// `Logic`.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::logic::Logic;
+///
+/// let odds = Logic::from_iterator((0..).map(|x| 2 * x + 1));
+/// let evens = Logic::from_iterator((0..).map(|x| 2 * x));
+/// let nats: Vec<i32> = odds.interleave(evens).into_iter().take(5).collect();
+/// assert_eq!(nats, vec![1, 0, 3, 2, 5]);
+///
+/// // first branch is infinite, but the second branch is still explored
+/// let results: Vec<i32> = Logic::from_iterator(vec![0, 1])
+///     .fair_bind(|x| match x {
+///         0 => Logic::from_iterator(std::iter::repeat(0)),
+///         _ => Logic::from_iterator([1]),
+///     })
+///     .into_iter()
+///     .take(4)
+///     .collect();
+/// assert_eq!(results, vec![0, 1, 0, 0]);
+/// ```
+pub struct Logic<'a, A>(
+    Box<dyn 'a + Send + FnOnce() -> Option<(A, Logic<'a, A>)>>,
+);
+
+impl<'a, A> Logic<'a, A>
+where
+    A: 'a + Send,
+{
+    fn defer<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> Option<(A, Self)>,
+    {
+        Logic(Box::new(f))
+    }
+
+    /// No results (failure)
+    pub fn empty() -> Self {
+        Logic::defer(|| None)
+    }
+
+    /// Lazily produce results from an iterator
+    pub fn from_iterator<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: 'a + Send,
+    {
+        let mut iter = iter.into_iter();
+        Logic::defer(move || {
+            iter.next().map(|a| (a, Logic::from_iterator(iter)))
+        })
+    }
+
+    /// Return the first result and the remaining results, or [`None`] if
+    /// there are no results
+    pub fn split(self) -> Option<(A, Self)> {
+        (self.0)()
+    }
+
+    /// Produce all results of `self` followed by all results of `other`
+    pub fn or(self, other: Self) -> Self {
+        Logic::defer(move || match self.split() {
+            None => other.split(),
+            Some((a, rest)) => Some((a, rest.or(other))),
+        })
+    }
+
+    /// Alternately produce results of `self` and `other` (fair disjunction)
+    pub fn interleave(self, other: Self) -> Self {
+        Logic::defer(move || match self.split() {
+            None => other.split(),
+            Some((a, rest)) => Some((a, other.interleave(rest))),
+        })
+    }
+
+    /// Keep only the first result (if any)
+    pub fn once(self) -> Self {
+        Logic::defer(move || {
+            self.split().map(|(a, _)| (a, Logic::empty()))
+        })
+    }
+
+    /// Like [`Monad::bind`] but interleaving the results for each input
+    /// (fair conjunction)
+    pub fn fair_bind<B, F>(self, f: F) -> Logic<'a, B>
+    where
+        B: 'a + Send,
+        F: 'a + Send + FnMut(A) -> Logic<'a, B>,
+    {
+        self.bind_shared(Arc::new(Mutex::new(f)), true)
+    }
+
+    fn bind_shared<B, F>(
+        self,
+        f: Arc<Mutex<F>>,
+        fair: bool,
+    ) -> Logic<'a, B>
+    where
+        B: 'a + Send,
+        F: 'a + Send + FnMut(A) -> Logic<'a, B>,
+    {
+        Logic::defer(move || {
+            let mut this = self;
+            loop {
+                let (a, rest) = this.split()?;
+                let results = (f.lock().unwrap())(a);
+                match results.split() {
+                    Some((b, more)) => {
+                        let rest = rest.bind_shared(f, fair);
+                        return Some(if fair {
+                            (b, rest.interleave(more))
+                        } else {
+                            (b, more.or(rest))
+                        });
+                    }
+                    None => this = rest,
+                }
+            }
+        })
+    }
+
+    fn fmap_shared<B, F>(self, f: Arc<Mutex<F>>) -> Logic<'a, B>
+    where
+        B: 'a + Send,
+        F: 'a + Send + FnMut(A) -> B,
+    {
+        Logic::defer(move || {
+            let (a, rest) = self.split()?;
+            let b = (f.lock().unwrap())(a);
+            Some((b, rest.fmap_shared(f)))
+        })
+    }
+}
+
+/// Iterator over the results of a [`Logic`]
+pub struct LogicIter<'a, A>(Option<Logic<'a, A>>);
+
+impl<'a, A> Iterator for LogicIter<'a, A>
+where
+    A: 'a + Send,
+{
+    type Item = A;
+    fn next(&mut self) -> Option<A> {
+        let (a, rest) = self.0.take()?.split()?;
+        self.0 = Some(rest);
+        Some(a)
+    }
+}
+
+impl<'a, A> IntoIterator for Logic<'a, A>
+where
+    A: 'a + Send,
+{
+    type Item = A;
+    type IntoIter = LogicIter<'a, A>;
+    fn into_iter(self) -> Self::IntoIter {
+        LogicIter(Some(self))
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Logic<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    type Inner = A;
+    type Mapped = Logic<'a, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.fmap_shared(Arc::new(Mutex::new(f)))
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Logic<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Logic::defer(move || Some((b, Logic::empty())))
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Logic<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.bind_shared(Arc::new(Mutex::new(f)), false)
+    }
+}
+
+impl<'a, A> Alternative for Logic<'a, A>
+where
+    A: 'a + Send,
+{
+    fn empty() -> Self {
+        Logic::empty()
+    }
+    fn or(self, other: Self) -> Self {
+        Logic::or(self, other)
+    }
+}
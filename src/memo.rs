@@ -0,0 +1,99 @@
+//! Memoized functions
+//!
+//! See [`Memo`].
+
+use super::*;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Function that caches its results
+///
+/// A `Memo<'a, X, A>` wraps a function `X -> A` along with a [`HashMap`]
+/// storing the result for each argument it has been [called] with. The
+/// function is only executed for arguments that are not cached yet.
+///
+/// Mapping a `Memo` maps the cached results too, so that the [mapped]
+/// function doesn't need to recompute them (unlike the [implementations]
+/// for boxed closures, which recompute everything on every call).
+///
+/// [called]: Memo::call
+/// [mapped]: Functor::fmap
+/// [implementations]: Functor#foreign-impls
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::memo::Memo;
+///
+/// let mut square = Memo::new(|x: u32| x * x);
+/// assert_eq!(*square.call(4), 16);
+/// assert!(square.is_cached(&4));
+///
+/// let mut text = square.fmap(|y| y.to_string());
+/// assert!(text.is_cached(&4));
+/// assert_eq!(text.call(4), "16");
+/// assert_eq!(text.call(5), "25");
+/// ```
+pub struct Memo<'a, X, A> {
+    function: Box<dyn 'a + Send + FnMut(X) -> A>,
+    cache: HashMap<X, A>,
+}
+
+impl<'a, X, A> Memo<'a, X, A>
+where
+    X: Eq + Hash + Clone,
+{
+    /// Create memoized function
+    pub fn new<F>(function: F) -> Self
+    where
+        F: 'a + Send + FnMut(X) -> A,
+    {
+        Memo {
+            function: Box::new(function),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Call function (if result isn't cached yet) and return reference to
+    /// result
+    pub fn call(&mut self, x: X) -> &A {
+        let function = &mut self.function;
+        self.cache
+            .entry(x)
+            .or_insert_with_key(|x| function(x.clone()))
+    }
+
+    /// Return whether the result for argument `x` is cached
+    pub fn is_cached(&self, x: &X) -> bool {
+        self.cache.contains_key(x)
+    }
+
+    /// Discard all cached results
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<'a, X, A, B> Functor<'a, B> for Memo<'a, X, A>
+where
+    X: 'a + Eq + Hash + Clone,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Memo<'a, X, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let cache =
+            self.cache.into_iter().map(|(x, a)| (x, f(a))).collect();
+        let mut function = self.function;
+        Memo {
+            function: Box::new(move |x| f(function(x))),
+            cache,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Maps whose values form a monad with a merge policy
+//!
+//! See [`MergeMap`].
+
+use super::*;
+
+use monoid::Semigroup;
+
+use std::collections::{btree_map, hash_map, BTreeMap, HashMap};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Policy for merging two values with the same key
+///
+/// Used by [`MergeMap`] when [binding] results in several values for the
+/// same key. Values are inserted in the iteration order of the original
+/// map, which is unspecified for a [`HashMap`] (see [`MergeMap`]).
+///
+/// [binding]: Monad::bind
+pub trait MergePolicy<V> {
+    /// Merge value `left`, which was inserted first, with value `right`
+    fn merge(left: V, right: V) -> V;
+}
+
+/// [`MergePolicy`] keeping the value that was inserted first
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct KeepLeft;
+
+impl<V> MergePolicy<V> for KeepLeft {
+    fn merge(left: V, _right: V) -> V {
+        left
+    }
+}
+
+/// [`MergePolicy`] keeping the value that was inserted last
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct KeepRight;
+
+impl<V> MergePolicy<V> for KeepRight {
+    fn merge(_left: V, right: V) -> V {
+        right
+    }
+}
+
+/// [`MergePolicy`] [combining] both values
+///
+/// [combining]: Semigroup::combine
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Combine;
+
+impl<V> MergePolicy<V> for Combine
+where
+    V: Semigroup,
+{
+    fn merge(left: V, right: V) -> V {
+        left.combine(right)
+    }
+}
+
+/// Wrapper for a [`HashMap`] or [`BTreeMap`] whose values form a monad
+///
+/// [Mapping] a `MergeMap<M, P>` maps the values and retains the keys.
+/// [`Monad::bind`] maps each value to another map with the same key type
+/// and merges all these maps into one map, where values with the same key
+/// are merged according to the [`MergePolicy`] `P`. The keys of the
+/// original map are discarded.
+/// [`Pure::pure`] creates a map with a single entry using the
+/// [default](Default) key.
+///
+/// *Note:* Because keys are discarded by `bind`, binding with
+/// [`Pure::pure`] doesn't result in the original map but in a map where all
+/// values have been merged into the default key.
+///
+/// *Note:* Values are merged in the iteration order of the original map.
+/// For a [`HashMap`], this order is unspecified, such that the result of
+/// [`KeepLeft`], [`KeepRight`], and [`Combine`] (unless combining is
+/// commutative) may differ between runs. Use a [`BTreeMap`] where a
+/// deterministic result is required.
+///
+/// [Mapping]: Functor::fmap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Monad;
+/// use fmap::merge_map::{Combine, KeepLeft, MergeMap};
+/// use std::collections::BTreeMap;
+///
+/// fn scores(name: &str) -> BTreeMap<&'static str, Vec<u32>> {
+///     match name {
+///         "alice" => BTreeMap::from([("math", vec![3]), ("art", vec![1])]),
+///         _ => BTreeMap::from([("math", vec![2])]),
+///     }
+/// }
+///
+/// let students = BTreeMap::from([("first", "alice"), ("second", "bob")]);
+///
+/// let combined = MergeMap::<_, Combine>::new(students.clone())
+///     .bind(|name| MergeMap::new(scores(name)));
+/// assert_eq!(
+///     combined.into_inner(),
+///     BTreeMap::from([("art", vec![1]), ("math", vec![3, 2])]),
+/// );
+///
+/// let first = MergeMap::<_, KeepLeft>::new(students)
+///     .bind(|name| MergeMap::new(scores(name)));
+/// assert_eq!(
+///     first.into_inner(),
+///     BTreeMap::from([("art", vec![1]), ("math", vec![3])]),
+/// );
+/// ```
+pub struct MergeMap<M, P> {
+    map: M,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<M, P> MergeMap<M, P> {
+    /// Wrap map
+    pub fn new(map: M) -> Self {
+        MergeMap {
+            map,
+            policy: PhantomData,
+        }
+    }
+
+    /// Unwrap map
+    pub fn into_inner(self) -> M {
+        self.map
+    }
+
+    /// Reference to the wrapped map
+    pub fn get_ref(&self) -> &M {
+        &self.map
+    }
+}
+
+impl<M, P> Clone for MergeMap<M, P>
+where
+    M: Clone,
+{
+    fn clone(&self) -> Self {
+        MergeMap::new(self.map.clone())
+    }
+}
+
+impl<M, P> PartialEq for MergeMap<M, P>
+where
+    M: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<M, P> Eq for MergeMap<M, P> where M: Eq {}
+
+impl<M, P> std::fmt::Debug for MergeMap<M, P>
+where
+    M: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MergeMap").field(&self.map).finish()
+    }
+}
+
+macro_rules! impl_merge_map {
+    ($map:ident, $entry:ident, $($key_bounds:tt)+) => {
+        impl<'a, K, A, B, P> Functor<'a, B> for MergeMap<$map<K, A>, P>
+        where
+            K: $($key_bounds)+,
+            A: 'a,
+            B: 'a,
+        {
+            type Inner = A;
+            type Mapped = MergeMap<$map<K, B>, P>;
+            fn fmap<F>(self, f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> B,
+            {
+                MergeMap::new(self.map.fmap(f))
+            }
+            fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.fmap_mut(f);
+                self
+            }
+        }
+
+        impl<'a, K, A, P> FunctorMut<'a, A> for MergeMap<$map<K, A>, P>
+        where
+            K: $($key_bounds)+,
+            A: 'a,
+        {
+            fn fmap_mut<F>(&mut self, f: F)
+            where
+                F: 'a + Send + FnMut(&mut Self::Inner),
+            {
+                self.map.fmap_mut(f)
+            }
+        }
+
+        impl<'a, K, A, B, P> Pure<'a, B> for MergeMap<$map<K, A>, P>
+        where
+            K: $($key_bounds)+ + Default,
+            A: 'a,
+            B: 'a,
+        {
+            fn pure(b: B) -> Self::Mapped {
+                let mut map = $map::new();
+                map.insert(K::default(), b);
+                MergeMap::new(map)
+            }
+        }
+
+        impl<'a, K, A, B, P> Monad<'a, B> for MergeMap<$map<K, A>, P>
+        where
+            K: $($key_bounds)+ + Default,
+            A: 'a,
+            B: 'a,
+            P: MergePolicy<B>,
+        {
+            fn bind<F>(self, mut f: F) -> Self::Mapped
+            where
+                F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+            {
+                let mut map = $map::<K, Option<B>>::new();
+                for (_, a) in self.map {
+                    for (key, b) in f(a).map {
+                        match map.entry(key) {
+                            $entry::Entry::Vacant(entry) => {
+                                entry.insert(Some(b));
+                            }
+                            $entry::Entry::Occupied(mut entry) => {
+                                let value = entry.get_mut();
+                                let left = value.take().unwrap();
+                                *value = Some(P::merge(left, b));
+                            }
+                        }
+                    }
+                }
+                MergeMap::new(
+                    map.into_iter()
+                        .map(|(key, b)| (key, b.unwrap()))
+                        .collect(),
+                )
+            }
+        }
+    };
+}
+
+impl_merge_map!(HashMap, hash_map, Eq + Hash);
+impl_merge_map!(BTreeMap, btree_map, Ord);
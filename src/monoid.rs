@@ -0,0 +1,471 @@
+//! Semigroups and monoids
+//!
+//! See [`Semigroup`] and [`Monoid`]. Types with several possible monoid
+//! instances (e.g. numbers, which can be added or multiplied) are wrapped
+//! in newtypes like [`Sum`] or [`Product`].
+
+use super::*;
+
+use std::cmp::Ordering;
+use std::collections::{LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+
+/// Type with an associative binary operation
+pub trait Semigroup {
+    /// Combine two values
+    ///
+    /// This operation must be associative, i.e.
+    /// `a.combine(b).combine(c)` must be equivalent to
+    /// `a.combine(b.combine(c))`.
+    fn combine(self, other: Self) -> Self;
+}
+
+/// [`Semigroup`] with an identity element
+///
+/// # Examples
+///
+/// ```
+/// use fmap::monoid::{Monoid, Semigroup};
+///
+/// let s = String::empty().combine("Hello".to_string());
+/// assert_eq!(s.combine(" World".to_string()), "Hello World");
+/// ```
+pub trait Monoid: Semigroup {
+    /// Identity element
+    ///
+    /// Combining any value with `empty()` (from either side) must result in
+    /// the same value.
+    fn empty() -> Self;
+}
+
+impl Semigroup for () {
+    fn combine(self, _: Self) -> Self {}
+}
+
+impl Monoid for () {
+    fn empty() -> Self {}
+}
+
+impl Semigroup for String {
+    fn combine(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+impl Monoid for String {
+    fn empty() -> Self {
+        String::new()
+    }
+}
+
+impl<A> Semigroup for Vec<A> {
+    fn combine(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
+impl<A> Monoid for Vec<A> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+}
+
+impl<A> Semigroup for VecDeque<A> {
+    fn combine(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
+impl<A> Monoid for VecDeque<A> {
+    fn empty() -> Self {
+        VecDeque::new()
+    }
+}
+
+impl<A> Semigroup for LinkedList<A> {
+    fn combine(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
+impl<A> Monoid for LinkedList<A> {
+    fn empty() -> Self {
+        LinkedList::new()
+    }
+}
+
+/// [`None`] is the identity element, and [`Some`] values are combined
+impl<A> Semigroup for Option<A>
+where
+    A: Semigroup,
+{
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+impl<A> Monoid for Option<A>
+where
+    A: Semigroup,
+{
+    fn empty() -> Self {
+        None
+    }
+}
+
+impl<A, B> Semigroup for (A, B)
+where
+    A: Semigroup,
+    B: Semigroup,
+{
+    fn combine(self, other: Self) -> Self {
+        (self.0.combine(other.0), self.1.combine(other.1))
+    }
+}
+
+impl<A, B> Monoid for (A, B)
+where
+    A: Monoid,
+    B: Monoid,
+{
+    fn empty() -> Self {
+        (A::empty(), B::empty())
+    }
+}
+
+/// Combine all values of an iterator
+///
+/// # Examples
+///
+/// ```
+/// use fmap::monoid::mconcat;
+///
+/// let words = vec!["Hello".to_string(), " World".to_string()];
+/// assert_eq!(mconcat(words), "Hello World");
+/// ```
+pub fn mconcat<M, I>(iter: I) -> M
+where
+    M: Monoid,
+    I: IntoIterator<Item = M>,
+{
+    iter.into_iter().fold(M::empty(), M::combine)
+}
+
+/// Map all values of an iterator to a [`Monoid`] and combine the results
+///
+/// # Examples
+///
+/// ```
+/// use fmap::monoid::{fold_map, Max, Sum};
+///
+/// let values = vec![3, 9, 4];
+/// assert_eq!(fold_map(values.clone(), Sum), Sum(16));
+/// assert_eq!(fold_map(values, Max), Max(9));
+/// ```
+pub fn fold_map<M, I, F>(iter: I, f: F) -> M
+where
+    M: Monoid,
+    I: IntoIterator,
+    F: FnMut(I::Item) -> M,
+{
+    mconcat(iter.into_iter().map(f))
+}
+
+/// Monoid under addition
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default,
+)]
+pub struct Sum<T>(pub T);
+
+impl<T> Semigroup for Sum<T>
+where
+    T: Add<Output = T>,
+{
+    fn combine(self, other: Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+/// Monoid under multiplication
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Product<T>(pub T);
+
+impl<T> Semigroup for Product<T>
+where
+    T: Mul<Output = T>,
+{
+    fn combine(self, other: Self) -> Self {
+        Product(self.0 * other.0)
+    }
+}
+
+macro_rules! number_impls {
+    ($zero:literal, $one:literal; $($ty:ty),*) => {
+        $(
+            impl Monoid for Sum<$ty> {
+                fn empty() -> Self {
+                    Sum($zero)
+                }
+            }
+            impl Monoid for Product<$ty> {
+                fn empty() -> Self {
+                    Product($one)
+                }
+            }
+        )*
+    };
+}
+
+number_impls!(0, 1; i8, i16, i32, i64, i128, isize);
+number_impls!(0, 1; u8, u16, u32, u64, u128, usize);
+number_impls!(0.0, 1.0; f32, f64);
+
+/// Semigroup (and monoid for bounded types) keeping the greater value
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Max<T>(pub T);
+
+impl<T> Semigroup for Max<T>
+where
+    T: Ord,
+{
+    fn combine(self, other: Self) -> Self {
+        match self.0.cmp(&other.0) {
+            Ordering::Less => other,
+            _ => self,
+        }
+    }
+}
+
+/// Semigroup (and monoid for bounded types) keeping the smaller value
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Min<T>(pub T);
+
+impl<T> Semigroup for Min<T>
+where
+    T: Ord,
+{
+    fn combine(self, other: Self) -> Self {
+        match self.0.cmp(&other.0) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+}
+
+macro_rules! bounded_impls {
+    ($($ty:ty),*) => {
+        $(
+            impl Monoid for Max<$ty> {
+                fn empty() -> Self {
+                    Max(<$ty>::MIN)
+                }
+            }
+            impl Monoid for Min<$ty> {
+                fn empty() -> Self {
+                    Min(<$ty>::MAX)
+                }
+            }
+        )*
+    };
+}
+
+bounded_impls!(i8, i16, i32, i64, i128, isize);
+bounded_impls!(u8, u16, u32, u64, u128, usize);
+bounded_impls!(char);
+
+impl Monoid for Max<bool> {
+    fn empty() -> Self {
+        Max(false)
+    }
+}
+
+impl Monoid for Min<bool> {
+    fn empty() -> Self {
+        Min(true)
+    }
+}
+
+/// Monoid under logical "or"
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default,
+)]
+pub struct Any(pub bool);
+
+impl Semigroup for Any {
+    fn combine(self, other: Self) -> Self {
+        Any(self.0 || other.0)
+    }
+}
+
+impl Monoid for Any {
+    fn empty() -> Self {
+        Any(false)
+    }
+}
+
+/// Monoid under logical "and"
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct All(pub bool);
+
+impl Semigroup for All {
+    fn combine(self, other: Self) -> Self {
+        All(self.0 && other.0)
+    }
+}
+
+impl Monoid for All {
+    fn empty() -> Self {
+        All(true)
+    }
+}
+
+/// Monoid keeping the first [`Some`] value
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default,
+)]
+pub struct First<T>(pub Option<T>);
+
+impl<T> Semigroup for First<T> {
+    fn combine(self, other: Self) -> Self {
+        First(self.0.or(other.0))
+    }
+}
+
+impl<T> Monoid for First<T> {
+    fn empty() -> Self {
+        First(None)
+    }
+}
+
+/// Monoid keeping the last [`Some`] value
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default,
+)]
+pub struct Last<T>(pub Option<T>);
+
+impl<T> Semigroup for Last<T> {
+    fn combine(self, other: Self) -> Self {
+        Last(other.0.or(self.0))
+    }
+}
+
+impl<T> Monoid for Last<T> {
+    fn empty() -> Self {
+        Last(None)
+    }
+}
+
+/// Semigroup or monoid with reversed order of combination
+///
+/// # Examples
+///
+/// ```
+/// use fmap::monoid::{Dual, Semigroup};
+///
+/// let a = Dual("a".to_string());
+/// let b = Dual("b".to_string());
+/// assert_eq!(a.combine(b).0, "ba");
+/// ```
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default,
+)]
+pub struct Dual<T>(pub T);
+
+impl<T> Semigroup for Dual<T>
+where
+    T: Semigroup,
+{
+    fn combine(self, other: Self) -> Self {
+        Dual(other.0.combine(self.0))
+    }
+}
+
+impl<T> Monoid for Dual<T>
+where
+    T: Monoid,
+{
+    fn empty() -> Self {
+        Dual(T::empty())
+    }
+}
+
+/// Monoid lifted through an [`Applicative`] functor
+///
+/// If `A` is a [`Semigroup`] (or [`Monoid`]), then `Ap<F, A>` is a
+/// semigroup (or monoid) for any applicative functor `F` with
+/// [inner type] `A`. Values are combined using [`Applicative::apply`], i.e.
+/// the effects of both values are combined as well (e.g. [`None`] is
+/// absorbing for [`Option`]).
+///
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::monoid::{mconcat, Ap};
+///
+/// let words = vec![Some("a".to_string()), Some("b".to_string())];
+/// let joined = mconcat(words.into_iter().map(Ap::new));
+/// assert_eq!(joined.into_inner(), Some("ab".to_string()));
+///
+/// let words = vec![Some("a".to_string()), None];
+/// let joined = mconcat(words.into_iter().map(Ap::new));
+/// assert_eq!(joined.into_inner(), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Ap<F, A> {
+    inner: F,
+    phantom: PhantomData<fn() -> A>,
+}
+
+impl<F, A> Ap<F, A> {
+    /// Wrap applicative functor
+    pub fn new(inner: F) -> Self {
+        Ap {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unwrap applicative functor
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+impl<'a, F, A> Semigroup for Ap<F, A>
+where
+    F: Functor<'a, A, Inner = A, Mapped = F>,
+    F: Functor<'a, BoxMapper<'a, F, A>, Inner = A>,
+    F: Applicative<'a, A>,
+    A: 'a + Semigroup + Clone + Send,
+{
+    fn combine(self, other: Self) -> Self {
+        let mappers = self.inner.fmap(|a: A| {
+            Box::new(move |b: A| a.clone().combine(b))
+                as BoxMapper<'a, F, A>
+        });
+        Ap::new(other.inner.apply(mappers))
+    }
+}
+
+impl<'a, F, A> Monoid for Ap<F, A>
+where
+    F: Functor<'a, A, Inner = A, Mapped = F>,
+    F: Functor<'a, BoxMapper<'a, F, A>, Inner = A>,
+    F: Applicative<'a, A>,
+    A: 'a + Monoid + Clone + Send,
+{
+    fn empty() -> Self {
+        Ap::new(F::pure(A::empty()))
+    }
+}
@@ -0,0 +1,108 @@
+//! Mapping the error of a [`Result`]
+//!
+//! See [`OnErr`].
+
+use super::*;
+
+/// Wrapper for a [`Result`] whose [inner type] is the error type
+///
+/// An `OnErr<Result<A, E>>` is a [`Functor`], [`Monad`], and
+/// [`Applicative`] functor over the error `E`, while an [`Ok`] value is
+/// passed through unchanged. This allows using generic code, which is
+/// written for the success value of a [`Result`], for its error instead.
+/// [`Monad::bind`] corresponds to [`Result::or_else`] and [`Pure::pure`]
+/// returns an [`Err`].
+///
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad};
+/// use fmap::on_err::OnErr;
+///
+/// let failed: Result<i32, &str> = Err("not found");
+/// let described = OnErr(failed).fmap(|e| format!("error: {e}"));
+/// assert_eq!(described.0, Err("error: not found".to_string()));
+///
+/// let recovered =
+///     OnErr(failed).bind(|e| OnErr(if e.is_empty() { Err(0) } else { Ok(7) }));
+/// assert_eq!(recovered.0, Ok(7));
+///
+/// let succeeded: Result<i32, &str> = Ok(1);
+/// assert_eq!(OnErr(succeeded).fmap(str::len).0, Ok(1));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct OnErr<R>(pub R);
+
+impl<'a, A, E, B> Functor<'a, B> for OnErr<Result<A, E>>
+where
+    E: 'a,
+    B: 'a,
+{
+    type Inner = E;
+    type Mapped = OnErr<Result<A, B>>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        OnErr(self.0.map_err(f))
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A, E> FunctorMut<'a, E> for OnErr<Result<A, E>>
+where
+    E: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        if let Err(inner) = &mut self.0 {
+            f(inner);
+        }
+    }
+}
+
+impl<'a, A, E, B> Pure<'a, B> for OnErr<Result<A, E>>
+where
+    E: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        OnErr(Err(b))
+    }
+}
+
+impl<'a, A, E, B> Monad<'a, B> for OnErr<Result<A, E>>
+where
+    E: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        OnErr(self.0.or_else(move |e| f(e).0))
+    }
+}
+
+impl<'a, A, E, B> Applicative<'a, B> for OnErr<Result<A, E>>
+where
+    E: 'a,
+    B: 'a,
+{
+    fn apply(
+        self,
+        f: OnErr<Result<A, BoxMapper<'a, Self, B>>>,
+    ) -> OnErr<Result<A, B>> {
+        OnErr(f.0.or_else(move |inner| self.0.map_err(inner)))
+    }
+}
@@ -0,0 +1,265 @@
+//! Monad transformer for optional values
+//!
+//! See [`OptionT`].
+
+use super::*;
+
+use std::marker::PhantomData;
+
+/// Monad transformer adding failure (like [`Option`]) to a base monad
+///
+/// An `OptionT<M, A>` wraps a base monad `M` whose [inner type] is
+/// `Option<A>`, e.g. `M = Vec<Option<A>>` or
+/// `M = Pin<Box<dyn Future<Output = Option<A>> + Send>>` (for "async
+/// optional" computations). [Binding] an `OptionT` only calls the closure
+/// for [`Some`] values and short-circuits on [`None`], such that nested
+/// matching on `Option` isn't needed.
+///
+/// [inner type]: Functor::Inner
+/// [Binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadTrans};
+/// use fmap::option_t::OptionT;
+/// use futures::executor::block_on;
+/// use futures::future::BoxFuture;
+///
+/// fn lookup(key: &'static str) -> OptionT<BoxFuture<'static, Option<i32>>, i32> {
+///     OptionT::new(Box::pin(async move {
+///         match key {
+///             "a" => Some(1),
+///             "b" => Some(2),
+///             _ => None,
+///         }
+///     }))
+/// }
+///
+/// let sum = lookup("a").bind(|a| lookup("b").bind(move |b| {
+///     OptionT::lift(Box::pin(async move { a + b }) as BoxFuture<_>)
+/// }));
+/// assert_eq!(block_on(sum.run()), Some(3));
+///
+/// let failed = lookup("a").bind(|_| lookup("x"));
+/// assert_eq!(block_on(failed.run()), None);
+/// ```
+pub struct OptionT<M, A> {
+    inner: M,
+    phantom: PhantomData<fn() -> A>,
+}
+
+impl<M, A> OptionT<M, A> {
+    /// Wrap base monad with [inner type] `Option<A>`
+    ///
+    /// [inner type]: Functor::Inner
+    pub fn new(inner: M) -> Self {
+        OptionT {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unwrap base monad
+    pub fn run(self) -> M {
+        self.inner
+    }
+
+    /// Modify the wrapped base monad with a closure
+    pub fn map_t<N, B, F>(self, f: F) -> OptionT<N, B>
+    where
+        F: FnOnce(M) -> N,
+    {
+        OptionT::new(f(self.inner))
+    }
+
+    /// Apply a natural transformation to the base monad
+    ///
+    /// Unlike [`map_t`](Self::map_t), the result type `A` is retained.
+    pub fn hoist<N, F>(self, nt: F) -> OptionT<N, A>
+    where
+        F: FnOnce(M) -> N,
+    {
+        OptionT::new(nt(self.inner))
+    }
+
+    /// Failed computation
+    pub fn none<'a>() -> Self
+    where
+        M: Pure<'a, Option<A>, Mapped = M>,
+        A: 'a,
+    {
+        OptionT::new(M::pure(None))
+    }
+}
+
+impl<'a, M, A, B> Functor<'a, B> for OptionT<M, A>
+where
+    M: Functor<'a, Option<B>, Inner = Option<A>>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = OptionT<M::Mapped, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        OptionT::new(self.inner.fmap(move |option| option.map(&mut f)))
+    }
+}
+
+impl<'a, M, A, B> Pure<'a, B> for OptionT<M, A>
+where
+    M: Pure<'a, Option<B>, Inner = Option<A>>,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        OptionT::new(M::pure(Some(b)))
+    }
+}
+
+impl<'a, M, A, B> Monad<'a, B> for OptionT<M, A>
+where
+    M: Monad<'a, Option<B>, Inner = Option<A>>,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        OptionT::new(self.inner.bind(move |option| match option {
+            Some(a) => f(a).inner,
+            None => M::pure(None),
+        }))
+    }
+}
+
+impl<'a, M, N, A> MonadTrans<'a, N> for OptionT<M, A>
+where
+    N: Functor<'a, Option<A>, Inner = A, Mapped = M>,
+    A: 'a,
+{
+    fn lift(base: N) -> Self {
+        OptionT::new(base.fmap(Some))
+    }
+}
+
+impl<'a, M, A> MonadError<'a, ()> for OptionT<M, A>
+where
+    M: Monad<'a, Option<A>, Inner = Option<A>, Mapped = M>,
+    A: 'a,
+{
+    fn throw_error(_: ()) -> Self {
+        OptionT::none()
+    }
+    fn catch_error<F>(self, mut handler: F) -> Self
+    where
+        F: 'a + Send + FnMut(()) -> Self,
+    {
+        OptionT::new(self.inner.bind(move |option| match option {
+            Some(a) => M::pure(Some(a)),
+            None => handler(()).inner,
+        }))
+    }
+}
+
+impl<'a, S, M, A> MonadState<'a, S> for OptionT<M, A>
+where
+    M: MonadState<'a, S, Value = Option<A>>,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        OptionT::new(M::state(move |s| {
+            let (a, s) = f(s);
+            (Some(a), s)
+        }))
+    }
+}
+
+impl<'a, R, M, A> MonadReader<'a, R> for OptionT<M, A>
+where
+    M: MonadReader<'a, R, Value = Option<A>>,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        OptionT::new(M::reader(move |r| Some(f(r))))
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        OptionT::new(self.inner.local(f))
+    }
+}
+
+impl<'a, W, M, A> MonadWriter<'a, W> for OptionT<M, A>
+where
+    W: 'a,
+    M: MonadWriter<'a, W, Value = Option<A>>,
+    M::Listened: Functor<'a, Option<(A, W)>, Inner = (Option<A>, W)>,
+    A: 'a,
+{
+    type Value = A;
+    type Listened = OptionT<
+        <M::Listened as Functor<'a, Option<(A, W)>>>::Mapped,
+        (A, W),
+    >;
+    fn writer(value: A, log: W) -> Self {
+        OptionT::new(M::writer(Some(value), log))
+    }
+    fn listen(self) -> Self::Listened {
+        OptionT::new(
+            self.inner
+                .listen()
+                .fmap(|(option, w)| option.map(|a| (a, w))),
+        )
+    }
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        OptionT::new(self.inner.censor(f))
+    }
+}
+
+impl<'a, M, A> MonadCont<'a> for OptionT<M, A>
+where
+    M: MonadCont<'a, Value = Option<A>>,
+    A: 'a,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        OptionT::new(M::call_cc(move |escape| f(escape).inner))
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        OptionT::new(M::escape(escape, Some(value)))
+    }
+}
+
+impl<'a, M, A> MonadIo<'a> for OptionT<M, A>
+where
+    M: MonadIo<'a, Value = Option<A>>,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        OptionT::new(M::lift_io(move || Some(thunk())))
+    }
+}
@@ -0,0 +1,178 @@
+//! Concurrent applicative functor for futures
+//!
+//! See [`Par`].
+
+use super::*;
+
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Boxed [`Future`] as used by [`Par`]
+pub type BoxFuture<'a, A> =
+    Pin<Box<dyn 'a + Future<Output = A> + Send>>;
+
+/// Boxed [`Future`] whose [`Applicative`] implementation polls futures
+/// concurrently
+///
+/// [`Applicative::apply`] for pinned boxed futures awaits the future of the
+/// function before the future of the argument (like [`Monad::bind`]). In
+/// contrast, [`Par::map2`], [`Par::map3`], and `Par`'s implementation of
+/// [`Applicative::apply`] poll all futures concurrently (join semantics).
+///
+/// `Par` is the [parallel] counterpart of
+/// `Pin<Box<dyn Future<Output = A> + Send>>`.
+///
+/// [parallel]: Parallel
+///
+/// # Examples
+///
+/// ```
+/// use fmap::par::Par;
+/// use futures::channel::oneshot;
+/// use futures::executor::block_on;
+///
+/// let (tx1, rx1) = oneshot::channel();
+/// let (tx2, rx2) = oneshot::channel();
+/// // each future waits for the other one
+/// let ping = Par::new(async move {
+///     tx1.send("ping").unwrap();
+///     rx2.await.unwrap()
+/// });
+/// let pong = Par::new(async move {
+///     let received = rx1.await.unwrap();
+///     tx2.send("pong").unwrap();
+///     received
+/// });
+/// let both = ping.map2(pong, |a, b| format!("{a} {b}"));
+/// assert_eq!(block_on(both.0), "pong ping");
+/// ```
+pub struct Par<'a, A>(pub BoxFuture<'a, A>);
+
+impl<'a, A> Par<'a, A>
+where
+    A: 'a,
+{
+    /// Box and wrap future
+    pub fn new<F>(future: F) -> Self
+    where
+        F: 'a + Future<Output = A> + Send,
+    {
+        Par(Box::pin(future))
+    }
+
+    /// Combine with another `Par`, polling both futures concurrently
+    pub fn map2<B, C, F>(self, other: Par<'a, B>, f: F) -> Par<'a, C>
+    where
+        A: Send,
+        B: 'a + Send,
+        C: 'a,
+        F: 'a + Send + FnOnce(A, B) -> C,
+    {
+        let mut left = MaybeDone::Pending(self.0);
+        let mut right = MaybeDone::Pending(other.0);
+        let mut f = Some(f);
+        Par::new(poll_fn(move |cx| {
+            let left_done = left.poll_step(cx);
+            let right_done = right.poll_step(cx);
+            if left_done && right_done {
+                let f = f.take().expect("polled after completion");
+                Poll::Ready(f(left.take(), right.take()))
+            } else {
+                Poll::Pending
+            }
+        }))
+    }
+
+    /// Combine with two other `Par`s, polling all three futures
+    /// concurrently
+    pub fn map3<B, C, D, F>(
+        self,
+        second: Par<'a, B>,
+        third: Par<'a, C>,
+        f: F,
+    ) -> Par<'a, D>
+    where
+        A: Send,
+        B: 'a + Send,
+        C: 'a + Send,
+        D: 'a,
+        F: 'a + Send + FnOnce(A, B, C) -> D,
+    {
+        self.map2(second, |a, b| (a, b))
+            .map2(third, |(a, b), c| f(a, b, c))
+    }
+}
+
+enum MaybeDone<'a, A> {
+    Pending(BoxFuture<'a, A>),
+    Done(Option<A>),
+}
+
+impl<'a, A> MaybeDone<'a, A> {
+    fn poll_step(&mut self, cx: &mut Context<'_>) -> bool {
+        if let MaybeDone::Pending(future) = self {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(a) => *self = MaybeDone::Done(Some(a)),
+                Poll::Pending => return false,
+            }
+        }
+        true
+    }
+    fn take(&mut self) -> A {
+        match self {
+            MaybeDone::Done(a) => {
+                a.take().expect("polled after completion")
+            }
+            MaybeDone::Pending(_) => unreachable!(),
+        }
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Par<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Par<'a, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Par(self.0.fmap(f))
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Par<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Par::new(std::future::ready(b))
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Par<'a, A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn apply(self, f: Par<'a, BoxMapper<'a, Self, B>>) -> Par<'a, B> {
+        f.map2(self, |mut mapper, a| mapper(a))
+    }
+}
+
+impl<'a, A> Parallel for BoxFuture<'a, A>
+where
+    A: 'a,
+{
+    type Par = Par<'a, A>;
+    fn parallel(self) -> Par<'a, A> {
+        Par(self)
+    }
+    fn sequential(par: Par<'a, A>) -> Self {
+        par.0
+    }
+}
@@ -0,0 +1,106 @@
+//! Parallel mapping of collections (requires feature `rayon`)
+//!
+//! See [`ParFunctor`].
+
+use super::*;
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A [`Functor`] whose inner values can be mapped in parallel
+///
+/// [`par_fmap`] distributes calls of the mapping function over rayon's
+/// global thread pool. Because the function may be called from several
+/// threads at once, it must implement [`Fn`] and [`Sync`] (instead of
+/// [`FnMut`] as for [`Functor::fmap`]). The order of elements is preserved.
+///
+/// [`par_fmap`]: Self::par_fmap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::par_functor::ParFunctor;
+///
+/// let squares = (1..=1000u64).collect::<Vec<_>>().par_fmap(|x| x * x);
+/// assert_eq!(squares[9], 100);
+/// assert_eq!(squares.iter().sum::<u64>(), 333_833_500);
+/// ```
+pub trait ParFunctor<'a, B>
+where
+    Self: Functor<'a, B>,
+    B: 'a + Send,
+{
+    /// Replace each inner value using a function that is called in
+    /// parallel
+    fn par_fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + Sync + Fn(Self::Inner) -> B;
+}
+
+impl<'a, A, B> ParFunctor<'a, B> for Vec<A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn par_fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + Sync + Fn(Self::Inner) -> B,
+    {
+        self.into_par_iter().map(f).collect()
+    }
+}
+
+impl<'a, A, B> ParFunctor<'a, B> for Box<[A]>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn par_fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + Sync + Fn(Self::Inner) -> B,
+    {
+        self.into_vec().par_fmap(f).into_boxed_slice()
+    }
+}
+
+impl<'a, A, B> ParFunctor<'a, B> for VecDeque<A>
+where
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn par_fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + Sync + Fn(Self::Inner) -> B,
+    {
+        self.into_par_iter().map(f).collect()
+    }
+}
+
+impl<'a, K, A, B> ParFunctor<'a, B> for HashMap<K, A>
+where
+    K: Eq + Hash + Send,
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn par_fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + Sync + Fn(Self::Inner) -> B,
+    {
+        self.into_par_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<'a, K, A, B> ParFunctor<'a, B> for BTreeMap<K, A>
+where
+    K: Ord + Send,
+    A: 'a + Send,
+    B: 'a + Send,
+{
+    fn par_fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + Sync + Fn(Self::Inner) -> B,
+    {
+        self.into_par_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
@@ -0,0 +1,195 @@
+//! Parser combinators
+//!
+//! See [`Parser`].
+
+use super::*;
+
+/// Backtracking parser for string slices
+///
+/// A `Parser<'a, A>` consumes a prefix of an input [`str`] and returns a
+/// value of type `A` along with the remaining input, or fails (returning
+/// [`None`]). Parsers are combined with [`Monad::bind`] (sequencing) and
+/// [`Alternative::or`] (choice with backtracking), and they can be repeated
+/// with [`Parser::many`] and [`Parser::some`].
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Alternative, Functor, Monad};
+/// use fmap::parser::Parser;
+///
+/// fn number() -> Parser<'static, u32> {
+///     Parser::satisfy(|c| c.is_ascii_digit())
+///         .some()
+///         .fmap(|digits| digits.into_iter().collect::<String>())
+///         .fmap(|digits| digits.parse().unwrap())
+/// }
+///
+/// let sum = number().bind(|first| {
+///     Parser::char('+')
+///         .bind(|_| number())
+///         .many()
+///         .fmap(move |rest| first + rest.into_iter().sum::<u32>())
+/// });
+/// let mut expr = sum.or(Parser::tag("zero").fmap(|_| 0));
+///
+/// assert_eq!(expr.parse("1+22+3"), Some((26, "")));
+/// assert_eq!(expr.parse("5+x"), Some((5, "+x")));
+/// assert_eq!(expr.parse_all("zero"), Some(0));
+/// assert_eq!(expr.parse_all("1+"), None);
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct Parser<'a, A>(
+    Box<dyn 'a + Send + for<'s> FnMut(&'s str) -> Option<(A, &'s str)>>,
+);
+
+impl<'a, A> Parser<'a, A>
+where
+    A: 'a,
+{
+    /// Create parser from function returning the result and the remaining
+    /// input
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + for<'s> FnMut(&'s str) -> Option<(A, &'s str)>,
+    {
+        Parser(Box::new(f))
+    }
+
+    /// Parse a prefix of `input` and return the result along with the
+    /// remaining input
+    pub fn parse<'s>(
+        &mut self,
+        input: &'s str,
+    ) -> Option<(A, &'s str)> {
+        (self.0)(input)
+    }
+
+    /// Parse the whole `input`
+    pub fn parse_all(&mut self, input: &str) -> Option<A> {
+        match self.parse(input)? {
+            (a, "") => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Apply parser repeatedly (zero or more times)
+    ///
+    /// Repetition stops when the parser fails or doesn't consume any input.
+    pub fn many(mut self) -> Parser<'a, Vec<A>> {
+        Parser::new(move |mut input| {
+            let mut results = Vec::new();
+            while let Some((a, rest)) = self.parse(input) {
+                if rest.len() == input.len() {
+                    break;
+                }
+                results.push(a);
+                input = rest;
+            }
+            Some((results, input))
+        })
+    }
+
+    /// Apply parser repeatedly (one or more times)
+    pub fn some(self) -> Parser<'a, Vec<A>> {
+        let mut many = self.many();
+        Parser::new(move |input| match many.parse(input)? {
+            (results, _) if results.is_empty() => None,
+            success => Some(success),
+        })
+    }
+}
+
+impl<'a> Parser<'a, char> {
+    /// Parse a single character that fulfills a predicate
+    pub fn satisfy<P>(mut predicate: P) -> Self
+    where
+        P: 'a + Send + FnMut(char) -> bool,
+    {
+        Parser::new(move |input| {
+            let c = input.chars().next()?;
+            predicate(c).then(|| (c, &input[c.len_utf8()..]))
+        })
+    }
+
+    /// Parse the given character
+    pub fn char(expected: char) -> Self {
+        Parser::satisfy(move |c| c == expected)
+    }
+}
+
+impl<'a> Parser<'a, String> {
+    /// Parse the given string
+    pub fn tag(expected: impl Into<String>) -> Self {
+        let expected = expected.into();
+        Parser::new(move |input| {
+            let rest = input.strip_prefix(expected.as_str())?;
+            Some((expected.clone(), rest))
+        })
+    }
+}
+
+impl<'a> Parser<'a, ()> {
+    /// Succeed only at the end of input
+    pub fn eof() -> Self {
+        Parser::new(|input| input.is_empty().then_some(((), input)))
+    }
+}
+
+impl<'a, A> Alternative for Parser<'a, A>
+where
+    A: 'a,
+{
+    fn empty() -> Self {
+        Parser::new(|_| None)
+    }
+    fn or(mut self, mut other: Self) -> Self {
+        Parser::new(move |input| {
+            self.parse(input).or_else(|| other.parse(input))
+        })
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Parser<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Parser<'a, B>;
+    fn fmap<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Parser::new(move |input| {
+            let (a, rest) = self.parse(input)?;
+            Some((f(a), rest))
+        })
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Parser<'a, A>
+where
+    A: 'a,
+    B: 'a + Clone + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Parser::new(move |input| Some((b.clone(), input)))
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Parser<'a, A>
+where
+    A: 'a,
+    B: 'a + Clone + Send,
+{
+    fn bind<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Parser::new(move |input| {
+            let (a, rest) = self.parse(input)?;
+            f(a).parse(rest)
+        })
+    }
+}
@@ -0,0 +1,56 @@
+//! Helpers for [`Poll`]
+//!
+//! [`Poll`] implements [`Functor`](crate::Functor) and
+//! [`Monad`](crate::Monad) on its own. The functions
+//! in this module operate on the `Poll<Option<A>>` shape returned by
+//! streams, where the item is wrapped twice.
+//!
+//! # Examples
+//!
+//! ```
+//! use fmap::poll::{bind_item, fmap_item};
+//! use std::task::Poll;
+//!
+//! let next: Poll<Option<i32>> = Poll::Ready(Some(4));
+//! assert_eq!(fmap_item(next, |x| x * 2), Poll::Ready(Some(8)));
+//!
+//! let checked = bind_item(next, |x| {
+//!     Poll::Ready((x > 5).then_some(x))
+//! });
+//! assert_eq!(checked, Poll::Ready(None));
+//!
+//! let pending: Poll<Option<i32>> = Poll::Pending;
+//! assert_eq!(fmap_item(pending, |x| x * 2), Poll::Pending);
+//! ```
+
+use std::task::Poll;
+
+/// Map the item of a `Poll<Option<A>>`
+///
+/// [`Poll::Pending`] and `Poll::Ready(None)` are returned unchanged.
+pub fn fmap_item<A, B, F>(
+    poll: Poll<Option<A>>,
+    f: F,
+) -> Poll<Option<B>>
+where
+    F: FnOnce(A) -> B,
+{
+    poll.map(|item| item.map(f))
+}
+
+/// Chain a computation that depends on the item of a `Poll<Option<A>>`
+///
+/// [`Poll::Pending`] and `Poll::Ready(None)` are returned unchanged.
+pub fn bind_item<A, B, F>(
+    poll: Poll<Option<A>>,
+    f: F,
+) -> Poll<Option<B>>
+where
+    F: FnOnce(A) -> Poll<Option<B>>,
+{
+    match poll {
+        Poll::Ready(Some(a)) => f(a),
+        Poll::Ready(None) => Poll::Ready(None),
+        Poll::Pending => Poll::Pending,
+    }
+}
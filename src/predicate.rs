@@ -0,0 +1,155 @@
+//! Predicates as contravariant functors
+//!
+//! See [`Predicate`].
+
+use super::*;
+
+use std::convert::Infallible;
+
+/// Boxed closure testing a value by reference
+///
+/// A `Predicate<'a, A>` is a [`Contravariant`] functor: a predicate for
+/// `B` can be [adapted] to a predicate for `A` with a closure converting
+/// `A` into `B`. Predicates are also [`Divisible`], where both parts must
+/// satisfy their predicate, and [`Decidable`].
+///
+/// Since the closures passed to [`Contravariant::contramap`] and
+/// [`Divisible::divide`] take their argument by value, the tested value is
+/// cloned when testing an adapted predicate. Use [`Predicate::by_ref`] to
+/// avoid cloning.
+///
+/// [adapted]: Contravariant::contramap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Contravariant;
+/// use fmap::predicate::Predicate;
+///
+/// let short = Predicate::new(|s: &String| s.len() < 4);
+/// let mut short_number = short.contramap(|n: i32| n.to_string());
+/// assert!(short_number.test(&123));
+/// assert!(!short_number.test(&-123));
+///
+/// let mut in_range = Predicate::new(|x: &i32| *x >= 0)
+///     .and(Predicate::new(|x: &i32| *x < 10));
+/// let digits: Vec<i32> = vec![-1, 3, 12, 7]
+///     .into_iter()
+///     .filter(|x| in_range.test(x))
+///     .collect();
+/// assert_eq!(digits, vec![3, 7]);
+/// ```
+pub struct Predicate<'a, A>(Box<dyn 'a + Send + FnMut(&A) -> bool>);
+
+impl<'a, A> Predicate<'a, A>
+where
+    A: 'a,
+{
+    /// Create predicate from a closure
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&A) -> bool,
+    {
+        Predicate(Box::new(f))
+    }
+
+    /// Test value
+    pub fn test(&mut self, a: &A) -> bool {
+        (self.0)(a)
+    }
+
+    /// Predicate that is satisfied if both `self` and `other` are satisfied
+    pub fn and(mut self, mut other: Self) -> Self {
+        Predicate::new(move |a| self.test(a) && other.test(a))
+    }
+
+    /// Predicate that is satisfied if `self` or `other` is satisfied
+    pub fn or(mut self, mut other: Self) -> Self {
+        Predicate::new(move |a| self.test(a) || other.test(a))
+    }
+
+    /// Negated predicate
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        Predicate::new(move |a| !self.test(a))
+    }
+
+    /// Adapt predicate with a closure that borrows from the tested value
+    ///
+    /// Unlike [`Contravariant::contramap`], this doesn't clone the tested
+    /// value.
+    pub fn by_ref<T, F>(mut self, mut f: F) -> Predicate<'a, T>
+    where
+        T: 'a,
+        F: 'a + Send + FnMut(&T) -> &A,
+    {
+        Predicate::new(move |t| self.test(f(t)))
+    }
+}
+
+impl<'a, A, B> Contravariant<'a, A> for Predicate<'a, B>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+{
+    type Inner = B;
+    type Mapped = Predicate<'a, A>;
+    fn contramap<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Self::Inner,
+    {
+        Predicate::new(move |a: &A| self.test(&f(a.clone())))
+    }
+}
+
+impl<'a, A, B, C> Divisible<'a, A, C> for Predicate<'a, B>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+    C: 'a,
+{
+    type Other = Predicate<'a, C>;
+    fn divide<F>(
+        mut self,
+        mut other: Self::Other,
+        mut f: F,
+    ) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> (Self::Inner, C),
+    {
+        Predicate::new(move |a: &A| {
+            let (b, c) = f(a.clone());
+            self.test(&b) && other.test(&c)
+        })
+    }
+    fn conquer() -> Self::Mapped {
+        Predicate::new(|_| true)
+    }
+}
+
+impl<'a, A, B, C> Decidable<'a, A, C> for Predicate<'a, B>
+where
+    A: 'a + Clone,
+    B: 'a + Clone,
+    C: 'a,
+{
+    fn choose<F>(
+        mut self,
+        mut other: Self::Other,
+        mut f: F,
+    ) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Result<Self::Inner, C>,
+    {
+        Predicate::new(move |a: &A| match f(a.clone()) {
+            Ok(b) => self.test(&b),
+            Err(c) => other.test(&c),
+        })
+    }
+    fn lose<F>(mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Infallible,
+    {
+        Predicate::new(move |a: &A| match f(a.clone()) {})
+    }
+}
@@ -0,0 +1,66 @@
+//! Profunctors
+//!
+//! A profunctor is a type with an input and an output type (like a
+//! function), which is contravariant in its input and covariant in its
+//! output. See [`Profunctor`], [`Strong`], and [`Choice`], and see
+//! [`Star`](crate::star::Star) for an implementation.
+
+/// Type with an input and an output that can be adapted on both sides
+///
+/// Type parameters `C` and `D` specify the new input and output types
+/// *after* the [`dimap`] operation.
+///
+/// [`dimap`]: Self::dimap
+pub trait Profunctor<'a, C, D>
+where
+    Self: Sized,
+{
+    /// Input type
+    type Input;
+    /// Output type
+    type Output;
+    /// Type with input `C` and output `D`
+    type Dimapped;
+
+    /// Convert input with `f` (before) and output with `g` (afterwards)
+    fn dimap<F, G>(self, f: F, g: G) -> Self::Dimapped
+    where
+        F: 'a + Send + FnMut(C) -> Self::Input,
+        G: 'a + Send + FnMut(Self::Output) -> D;
+}
+
+/// Profunctor that can pass an additional value of type `C` through
+/// unchanged (as part of a pair)
+pub trait Strong<'a, C>
+where
+    Self: Sized,
+{
+    /// Type with input `(A, C)` and output `(B, C)`
+    type First;
+    /// Type with input `(C, A)` and output `(C, B)`
+    type Second;
+
+    /// Act on the first component of a pair
+    fn first(self) -> Self::First;
+
+    /// Act on the second component of a pair
+    fn second(self) -> Self::Second;
+}
+
+/// Profunctor that can pass an alternative value of type `C` through
+/// unchanged (as one variant of a [`Result`])
+pub trait Choice<'a, C>
+where
+    Self: Sized,
+{
+    /// Type with input `Result<A, C>` and output `Result<B, C>`
+    type OnOk;
+    /// Type with input `Result<C, A>` and output `Result<C, B>`
+    type OnErr;
+
+    /// Act on [`Ok`] values
+    fn on_ok(self) -> Self::OnOk;
+
+    /// Act on [`Err`] values
+    fn on_err(self) -> Self::OnErr;
+}
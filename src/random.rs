@@ -0,0 +1,163 @@
+//! Generation of random values (requires feature `rand`)
+//!
+//! See [`Gen`].
+
+use super::*;
+
+use rand::{Rng, RngCore};
+
+use std::ops::Range;
+
+/// Random-generation monad
+///
+/// A `Gen<'a, A>` wraps a function that generates a value of type `A` from
+/// a random number generator. Generators can be combined with
+/// [`Functor::fmap`] and [`Monad::bind`] and with the combinators
+/// [`Gen::choose`], [`Gen::vec_of`], and [`Gen::frequency`], which is
+/// useful for building test data (e.g. for property testing).
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad};
+/// use fmap::random::Gen;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Shape {
+///     Circle(u32),
+///     Square(u32),
+/// }
+///
+/// let size = || Gen::choose(1..=10);
+/// let shape = Gen::frequency(vec![
+///     (3, size().fmap(Shape::Circle)),
+///     (1, size().fmap(Shape::Square)),
+/// ]);
+/// let mut shapes = shape.vec_of(2..5);
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let generated = shapes.generate(&mut rng);
+/// assert!((2..5).contains(&generated.len()));
+///
+/// let mut pairs = Gen::choose(0..3)
+///     .bind(|a| Gen::choose(a..3).fmap(move |b| (a, b)));
+/// let (a, b) = pairs.generate(&mut rng);
+/// assert!(a <= b);
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct Gen<'a, A>(
+    Box<dyn 'a + Send + FnMut(&mut dyn RngCore) -> A>,
+);
+
+impl<'a, A> Gen<'a, A>
+where
+    A: 'a,
+{
+    /// Create generator from function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut dyn RngCore) -> A,
+    {
+        Gen(Box::new(f))
+    }
+
+    /// Generate value using random number generator `rng`
+    pub fn generate(&mut self, rng: &mut dyn RngCore) -> A {
+        (self.0)(rng)
+    }
+
+    /// Choose one of the given values with equal probability
+    ///
+    /// # Panics
+    ///
+    /// The generator panics if there are no values.
+    pub fn choose<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Clone + Send,
+    {
+        let values: Vec<A> = values.into_iter().collect();
+        Gen::new(move |rng| {
+            values[rng.gen_range(0..values.len())].clone()
+        })
+    }
+
+    /// Choose one of the given generators, with probabilities proportional
+    /// to the given weights
+    ///
+    /// The weights are summed up as [`u64`], such that the sum doesn't
+    /// overflow even if several weights are close to [`u32::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// The generator panics if the sum of weights is zero.
+    pub fn frequency(mut generators: Vec<(u32, Gen<'a, A>)>) -> Self {
+        let total: u64 =
+            generators.iter().map(|(w, _)| u64::from(*w)).sum();
+        Gen::new(move |rng| {
+            let mut choice = rng.gen_range(0..total);
+            for (weight, generator) in generators.iter_mut() {
+                let weight = u64::from(*weight);
+                if choice < weight {
+                    return generator.generate(rng);
+                }
+                choice -= weight;
+            }
+            unreachable!()
+        })
+    }
+
+    /// Generate vectors whose length is chosen uniformly from `len`
+    ///
+    /// # Panics
+    ///
+    /// The generator panics if `len` is empty.
+    pub fn vec_of(mut self, len: Range<usize>) -> Gen<'a, Vec<A>> {
+        Gen::new(move |rng| {
+            let n = rng.gen_range(len.clone());
+            (0..n).map(|_| self.generate(rng)).collect()
+        })
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Gen<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Gen<'a, B>;
+    fn fmap<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Gen::new(move |rng| f(self.generate(rng)))
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Gen<'a, A>
+where
+    A: 'a,
+    B: 'a + Clone + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Gen::new(move |_| b.clone())
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Gen<'a, A>
+where
+    A: 'a,
+    B: 'a + Clone + Send,
+{
+    fn bind<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        Gen::new(move |rng| {
+            let a = self.generate(rng);
+            f(a).generate(rng)
+        })
+    }
+}
@@ -0,0 +1,269 @@
+//! Reader monad transformer
+//!
+//! See [`ReaderT`].
+
+use super::*;
+
+use std::marker::PhantomData;
+
+/// Monad transformer adding a read-only environment of type `R` to a base
+/// monad
+///
+/// A `ReaderT<'a, R, M, A>` wraps a function `R -> M`, where the base monad
+/// `M` has the [inner type] `A`. [Binding] passes the same environment to
+/// all computations, which allows injecting configuration into a monadic
+/// stack without passing it manually.
+///
+/// Plain reader functions (i.e. functions `R -> A` that don't return a
+/// monad) can be converted with [`ReaderT::reader`].
+///
+/// [inner type]: Functor::Inner
+/// [Binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Monad, MonadTrans};
+/// use fmap::reader_t::ReaderT;
+///
+/// struct Config {
+///     verbose: bool,
+///     limit: i32,
+/// }
+/// type App<A> = ReaderT<'static, &'static Config, Option<A>, A>;
+///
+/// fn limit() -> App<i32> {
+///     ReaderT::reader(|config: &Config| config.limit)
+/// }
+/// fn checked(x: i32) -> App<i32> {
+///     limit().bind(move |limit| ReaderT::lift((x <= limit).then_some(x)))
+/// }
+///
+/// static CONFIG: Config = Config { verbose: false, limit: 10 };
+/// assert_eq!(checked(5).run_reader_t(&CONFIG), Some(5));
+/// assert_eq!(checked(50).run_reader_t(&CONFIG), None);
+///
+/// let config: App<&Config> = ReaderT::ask();
+/// let verbose = config.bind(|config| ReaderT::lift(Some(config.verbose)));
+/// static OTHER: Config = Config { verbose: true, limit: 0 };
+/// let local: App<bool> = verbose.local(|_| &OTHER);
+/// assert_eq!(local.run_reader_t(&CONFIG), Some(true));
+/// ```
+pub struct ReaderT<'a, R, M, A>(
+    Box<dyn 'a + Send + FnOnce(R) -> M>,
+    PhantomData<fn() -> A>,
+);
+
+impl<'a, R, M, A> ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a,
+    A: 'a,
+{
+    /// Create computation from a function returning the base monad
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> M,
+    {
+        ReaderT(Box::new(f), PhantomData)
+    }
+
+    /// Create computation from a plain reader function
+    pub fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+        M: Pure<'a, A, Mapped = M>,
+    {
+        ReaderT::new(move |r| M::pure(f(r)))
+    }
+
+    /// Run computation with environment
+    pub fn run_reader_t(self, env: R) -> M {
+        (self.0)(env)
+    }
+
+    /// Apply a natural transformation to the base monad
+    pub fn hoist<N, F>(self, nt: F) -> ReaderT<'a, R, N, A>
+    where
+        N: 'a,
+        F: 'a + Send + FnOnce(M) -> N,
+    {
+        ReaderT::new(move |r| nt(self.run_reader_t(r)))
+    }
+
+    /// Run computation with an environment modified by a closure
+    pub fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        ReaderT::new(move |r| self.run_reader_t(f(r)))
+    }
+}
+
+impl<'a, R, M> ReaderT<'a, R, M, R>
+where
+    R: 'a,
+    M: 'a + Pure<'a, R, Mapped = M>,
+{
+    /// Retrieve environment
+    pub fn ask() -> Self {
+        ReaderT::new(M::pure)
+    }
+}
+
+impl<'a, R, M, A, B> Functor<'a, B> for ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a + Functor<'a, B, Inner = A>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = ReaderT<'a, R, M::Mapped, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        ReaderT::new(move |r| self.run_reader_t(r).fmap(f))
+    }
+}
+
+impl<'a, R, M, A, B> Pure<'a, B> for ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a + Pure<'a, B, Inner = A>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        ReaderT::new(move |_| M::pure(b))
+    }
+}
+
+impl<'a, R, M, A, B> Monad<'a, B> for ReaderT<'a, R, M, A>
+where
+    R: 'a + Clone + Send,
+    M: 'a + Monad<'a, B, Inner = A>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        ReaderT::new(move |r: R| {
+            let env = r.clone();
+            self.run_reader_t(r)
+                .bind(move |a| f(a).run_reader_t(env.clone()))
+        })
+    }
+}
+
+impl<'a, R, M, A> MonadTrans<'a, M> for ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a + Send,
+    A: 'a,
+{
+    fn lift(base: M) -> Self {
+        ReaderT::new(move |_| base)
+    }
+}
+
+impl<'a, S, R, M, A> MonadState<'a, S> for ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a + MonadState<'a, S, Value = A>,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        ReaderT::new(move |_| M::state(f))
+    }
+}
+
+impl<'a, R, M, A> MonadReader<'a, R> for ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a + Pure<'a, A, Mapped = M>,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        ReaderT::reader(f)
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        ReaderT::local(self, f)
+    }
+}
+
+impl<'a, W, R, M, A> MonadWriter<'a, W> for ReaderT<'a, R, M, A>
+where
+    W: 'a + Send,
+    R: 'a,
+    M: 'a + MonadWriter<'a, W, Value = A>,
+    M::Listened: 'a,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Listened = ReaderT<'a, R, M::Listened, (A, W)>;
+    fn writer(value: A, log: W) -> Self {
+        ReaderT::new(move |_| M::writer(value, log))
+    }
+    fn listen(self) -> Self::Listened {
+        ReaderT::new(move |r| self.run_reader_t(r).listen())
+    }
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        ReaderT::new(move |r| self.run_reader_t(r).censor(f))
+    }
+}
+
+impl<'a, R, M, A> MonadCont<'a> for ReaderT<'a, R, M, A>
+where
+    R: 'a + Send,
+    M: 'a + MonadCont<'a, Value = A>,
+    M::Escape: Clone + Send,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        ReaderT::new(move |r| {
+            M::call_cc(move |escape| f(escape).run_reader_t(r))
+        })
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        let escape = escape.clone();
+        ReaderT::new(move |_| M::escape(&escape, value))
+    }
+}
+
+impl<'a, R, M, A> MonadIo<'a> for ReaderT<'a, R, M, A>
+where
+    R: 'a,
+    M: 'a + MonadIo<'a, Value = A>,
+    A: 'a,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        ReaderT::new(move |_| M::lift_io(thunk))
+    }
+}
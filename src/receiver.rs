@@ -0,0 +1,186 @@
+//! Mapping values received from a channel
+//!
+//! See [`MappedReceiver`].
+
+use super::*;
+
+use std::sync::mpsc::Receiver;
+
+/// Receiving end of a channel whose received values are mapped
+///
+/// A `MappedReceiver` wraps a [`Receiver`] of the [`std::sync::mpsc`]
+/// module (or, with feature `crossbeam`, a `Receiver` of the
+/// `crossbeam-channel` crate). [Mapping] applies a closure to every
+/// received value. When [binding], each received value is mapped to another
+/// `MappedReceiver`, which is drained (i.e. received from until its sender
+/// has been dropped) before the next value is received from the original
+/// receiver.
+///
+/// Values are received lazily when calling [`MappedReceiver::recv`] or when
+/// using the `MappedReceiver` as an [`Iterator`]. Both block until a value
+/// is available or all senders have been dropped.
+///
+/// [Mapping]: Functor::fmap
+/// [binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad};
+/// use fmap::receiver::MappedReceiver;
+/// use std::sync::mpsc::channel;
+/// use std::thread::spawn;
+///
+/// let (tx, rx) = channel();
+/// spawn(move || {
+///     for i in 1..=3 {
+///         tx.send(i).unwrap();
+///     }
+/// });
+///
+/// let doubled = MappedReceiver::new(rx)
+///     .fmap(|x| x * 2)
+///     .bind(|x| {
+///         let (tx, rx) = channel();
+///         tx.send(x).unwrap();
+///         tx.send(x + 1).unwrap();
+///         MappedReceiver::new(rx)
+///     });
+/// assert_eq!(doubled.collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7]);
+/// ```
+pub struct MappedReceiver<'a, A> {
+    next: Box<dyn 'a + Send + FnMut() -> Option<A>>,
+}
+
+impl<'a, A> MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    /// Wrap a [`Receiver`]
+    pub fn new(receiver: Receiver<A>) -> Self {
+        MappedReceiver {
+            next: Box::new(move || receiver.recv().ok()),
+        }
+    }
+}
+
+impl<'a, A> MappedReceiver<'a, A> {
+    /// Block until a (mapped) value is received
+    ///
+    /// Returns [`None`] if all senders have been dropped.
+    pub fn recv(&mut self) -> Option<A> {
+        (self.next)()
+    }
+}
+
+impl<'a, A> Iterator for MappedReceiver<'a, A> {
+    type Item = A;
+    fn next(&mut self) -> Option<A> {
+        self.recv()
+    }
+}
+
+impl<'a, A> From<Receiver<A>> for MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    fn from(receiver: Receiver<A>) -> Self {
+        MappedReceiver::new(receiver)
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<'a, A> MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    /// Wrap a [`crossbeam_channel::Receiver`] (requires feature
+    /// `crossbeam`)
+    pub fn crossbeam(receiver: crossbeam_channel::Receiver<A>) -> Self {
+        MappedReceiver {
+            next: Box::new(move || receiver.recv().ok()),
+        }
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<'a, A> From<crossbeam_channel::Receiver<A>>
+    for MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    fn from(receiver: crossbeam_channel::Receiver<A>) -> Self {
+        MappedReceiver::crossbeam(receiver)
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for MappedReceiver<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = MappedReceiver<'a, B>;
+    fn fmap<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        MappedReceiver {
+            next: Box::new(move || self.recv().map(&mut f)),
+        }
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for MappedReceiver<'a, A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::replace(
+            self,
+            MappedReceiver {
+                next: Box::new(|| panic!("poisoned FunctorMut")),
+            },
+        );
+        *self = this.fmap_fn_mutref(f);
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for MappedReceiver<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        let mut b = Some(b);
+        MappedReceiver {
+            next: Box::new(move || b.take()),
+        }
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for MappedReceiver<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        let mut current: Option<MappedReceiver<'a, B>> = None;
+        MappedReceiver {
+            next: Box::new(move || loop {
+                if let Some(inner) = &mut current {
+                    if let Some(b) = inner.recv() {
+                        return Some(b);
+                    }
+                }
+                current = Some(f(self.recv()?));
+            }),
+        }
+    }
+}
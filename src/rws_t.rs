@@ -0,0 +1,361 @@
+//! Reader-writer-state monad transformer
+//!
+//! See [`RWST`].
+
+use super::*;
+
+use monoid::Monoid;
+
+use std::marker::PhantomData;
+
+/// Monad transformer combining [`ReaderT`], [`WriterT`], and [`StateT`]
+///
+/// An `RWST<'a, R, W, S, M, A>` wraps a function `(R, S) -> M`, where the
+/// base monad `M` has the [inner type] `(A, S, W)`. It provides a read-only
+/// environment of type `R`, an accumulated log of type `W` (which is a
+/// [`Monoid`]), and a state of type `S` in a single layer, which avoids
+/// deep transformer stacks and repeated lifting.
+///
+/// [`ReaderT`]: reader_t::ReaderT
+/// [`WriterT`]: writer_t::WriterT
+/// [`StateT`]: state_t::StateT
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad};
+/// use fmap::rws_t::RWST;
+///
+/// type App<A> = RWST<'static, i32, Vec<String>, u32, Option<(A, u32, Vec<String>)>, A>;
+///
+/// fn step() -> App<()> {
+///     App::<i32>::ask().bind(|increment| {
+///         App::<()>::modify(move |n| n + increment as u32).bind(move |()| {
+///             App::<()>::tell(vec![format!("added {increment}")])
+///         })
+///     })
+/// }
+///
+/// let program = step().bind(|()| step()).bind(|()| App::<u32>::get());
+/// assert_eq!(
+///     program.run_rws_t(5, 1),
+///     Some((11, 11, vec!["added 5".to_string(), "added 5".to_string()])),
+/// );
+///
+/// let local = step().local(|increment| increment * 2).fmap(|()| "done");
+/// assert_eq!(
+///     local.run_rws_t(5, 0),
+///     Some(("done", 10, vec!["added 10".to_string()])),
+/// );
+/// ```
+#[allow(clippy::upper_case_acronyms)]
+pub struct RWST<'a, R, W, S, M, A>(
+    Box<dyn 'a + Send + FnOnce(R, S) -> M>,
+    PhantomData<fn() -> (A, W)>,
+);
+
+impl<'a, R, W, S, M, A> RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a,
+    S: 'a,
+    M: 'a,
+    A: 'a,
+{
+    /// Create computation from a function returning the base monad
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R, S) -> M,
+    {
+        RWST(Box::new(f), PhantomData)
+    }
+
+    /// Run computation with environment and initial state
+    ///
+    /// The returned base monad contains the result, the final state, and
+    /// the log.
+    pub fn run_rws_t(self, env: R, state: S) -> M {
+        (self.0)(env, state)
+    }
+
+    /// Apply a natural transformation to the base monad
+    pub fn hoist<N, F>(self, nt: F) -> RWST<'a, R, W, S, N, A>
+    where
+        N: 'a,
+        F: 'a + Send + FnOnce(M) -> N,
+    {
+        RWST::new(move |r, s| nt(self.run_rws_t(r, s)))
+    }
+
+    /// Run computation with an environment modified by a closure
+    pub fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        RWST::new(move |r, s| self.run_rws_t(f(r), s))
+    }
+}
+
+impl<'a, R, W, S, M> RWST<'a, R, W, S, M, R>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a,
+    M: 'a + Pure<'a, (R, S, W), Mapped = M>,
+{
+    /// Retrieve environment
+    pub fn ask() -> Self {
+        RWST::new(|r, s| M::pure((r, s, W::empty())))
+    }
+}
+
+impl<'a, R, W, S, M> RWST<'a, R, W, S, M, S>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a + Clone,
+    M: 'a + Pure<'a, (S, S, W), Mapped = M>,
+{
+    /// Retrieve current state
+    pub fn get() -> Self {
+        RWST::new(|_, s: S| M::pure((s.clone(), s, W::empty())))
+    }
+}
+
+impl<'a, R, W, S, M> RWST<'a, R, W, S, M, ()>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a,
+    M: 'a + Pure<'a, ((), S, W), Mapped = M>,
+{
+    /// Append to the log
+    pub fn tell(log: W) -> Self
+    where
+        W: Send,
+    {
+        RWST::new(move |_, s| M::pure(((), s, log)))
+    }
+
+    /// Replace state
+    pub fn put(state: S) -> Self
+    where
+        S: Send,
+    {
+        RWST::new(move |_, _| M::pure(((), state, W::empty())))
+    }
+
+    /// Modify state with a closure
+    pub fn modify<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> S,
+    {
+        RWST::new(move |_, s| M::pure(((), f(s), W::empty())))
+    }
+}
+
+impl<'a, R, W, S, M, A, B> Functor<'a, B> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a,
+    S: 'a,
+    M: 'a + Functor<'a, (B, S, W), Inner = (A, S, W)>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = RWST<'a, R, W, S, M::Mapped, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        RWST::new(move |r, s| {
+            self.run_rws_t(r, s).fmap(move |(a, s, w)| (f(a), s, w))
+        })
+    }
+}
+
+impl<'a, R, W, S, M, A, B> Pure<'a, B> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a,
+    M: 'a + Pure<'a, (B, S, W), Inner = (A, S, W)>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        RWST::new(move |_, s| M::pure((b, s, W::empty())))
+    }
+}
+
+impl<'a, R, W, S, M, A, B> Monad<'a, B> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a + Clone + Send,
+    W: 'a + Monoid + Clone + Send,
+    S: 'a,
+    M: 'a + Monad<'a, (B, S, W), Inner = (A, S, W)>,
+    M::Mapped: FunctorSelf<'a, (B, S, W)>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        RWST::new(move |r: R, s| {
+            let env = r.clone();
+            self.run_rws_t(r, s).bind(move |(a, s, w1)| {
+                f(a).run_rws_t(env.clone(), s).fmap(
+                    move |(b, s, w2)| (b, s, w1.clone().combine(w2)),
+                )
+            })
+        })
+    }
+}
+
+impl<'a, R, W, S, M, N, A> MonadTrans<'a, N> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a + Clone + Send,
+    M: 'a,
+    N: 'a + Send + Functor<'a, (A, S, W), Inner = A, Mapped = M>,
+    A: 'a,
+{
+    fn lift(base: N) -> Self {
+        RWST::new(move |_, s: S| {
+            base.fmap(move |a| (a, s.clone(), W::empty()))
+        })
+    }
+}
+
+impl<'a, R, W, S, M, A> MonadState<'a, S> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a,
+    M: 'a + Pure<'a, (A, S, W), Mapped = M>,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        RWST::new(move |_, s| {
+            let (a, s) = f(s);
+            M::pure((a, s, W::empty()))
+        })
+    }
+}
+
+impl<'a, R, W, S, M, A> MonadReader<'a, R> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a,
+    M: 'a + Pure<'a, (A, S, W), Mapped = M>,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        RWST::new(move |r, s| M::pure((f(r), s, W::empty())))
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        RWST::local(self, f)
+    }
+}
+
+impl<'a, R, W, S, M, A> MonadWriter<'a, W> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a + Clone + Send,
+    S: 'a,
+    M: 'a + Pure<'a, (A, S, W), Inner = (A, S, W), Mapped = M>,
+    M: Functor<'a, ((A, W), S, W), Inner = (A, S, W)>,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Listened = RWST<
+        'a,
+        R,
+        W,
+        S,
+        <M as Functor<'a, ((A, W), S, W)>>::Mapped,
+        (A, W),
+    >;
+    fn writer(value: A, log: W) -> Self {
+        RWST::new(move |_, s| M::pure((value, s, log)))
+    }
+    fn listen(self) -> Self::Listened {
+        RWST::new(move |r, s| {
+            self.run_rws_t(r, s)
+                .fmap(|(a, s, w): (A, S, W)| ((a, w.clone()), s, w))
+        })
+    }
+    fn censor<F>(self, mut f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        RWST::new(move |r, s| {
+            <M as Functor<'a, (A, S, W)>>::fmap(
+                self.run_rws_t(r, s),
+                move |(a, s, w)| (a, s, f(w)),
+            )
+        })
+    }
+}
+
+impl<'a, R, W, S, M, A> MonadCont<'a> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a + Send,
+    W: 'a + Monoid,
+    S: 'a + Send,
+    M: 'a + MonadCont<'a, Value = (A, S, W)>,
+    M::Escape: Clone + Send,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        RWST::new(move |r, s| {
+            M::call_cc(move |escape| f(escape).run_rws_t(r, s))
+        })
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        let escape = escape.clone();
+        RWST::new(move |_, s| {
+            M::escape(&escape, (value, s, W::empty()))
+        })
+    }
+}
+
+impl<'a, R, W, S, M, A> MonadIo<'a> for RWST<'a, R, W, S, M, A>
+where
+    R: 'a,
+    W: 'a + Monoid,
+    S: 'a + Send,
+    M: 'a + MonadIo<'a, Value = (A, S, W)>,
+    A: 'a,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        RWST::new(move |_, s| {
+            M::lift_io(move || (thunk(), s, W::empty()))
+        })
+    }
+}
@@ -0,0 +1,198 @@
+//! Mapping responses and requests of services (requires feature `tower`)
+//!
+//! See [`MappedService`].
+
+use super::*;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+use tower::Service;
+
+type BoxFuture<'a, T> = Pin<Box<dyn 'a + Send + Future<Output = T>>>;
+
+trait DynService<'a, Req, Res, E> {
+    fn poll_ready_dyn(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>>;
+    fn call_dyn(&mut self, req: Req) -> BoxFuture<'a, Result<Res, E>>;
+}
+
+impl<'a, S, Req> DynService<'a, Req, S::Response, S::Error> for S
+where
+    S: Service<Req>,
+    S::Future: 'a + Send,
+{
+    fn poll_ready_dyn(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), S::Error>> {
+        Service::poll_ready(self, cx)
+    }
+    fn call_dyn(
+        &mut self,
+        req: Req,
+    ) -> BoxFuture<'a, Result<S::Response, S::Error>> {
+        Box::pin(Service::call(self, req))
+    }
+}
+
+/// Boxed [`Service`] whose responses and requests can be mapped
+///
+/// A `MappedService<'a, Req, Res, E>` is a [`Functor`] over its response
+/// type `Res` and a [`Contravariant`] functor over its request type `Req`.
+/// [Mapping] applies a closure to each successful response, while
+/// [contramapping] converts each request before it is passed to the wrapped
+/// service. Errors are passed through unchanged.
+///
+/// [Mapping]: Functor::fmap
+/// [contramapping]: Contravariant::contramap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Contravariant, Functor};
+/// use fmap::service::MappedService;
+/// use futures::executor::block_on;
+/// use std::convert::Infallible;
+/// use tower::{service_fn, Service};
+///
+/// let double =
+///     service_fn(|x: i32| async move { Ok::<_, Infallible>(x * 2) });
+/// let mut service = MappedService::new(double)
+///     .fmap(|x| format!("result: {x}"))
+///     .contramap(|s: &str| s.parse().unwrap());
+/// let response = block_on(service.call("21")).unwrap();
+/// assert_eq!(response, "result: 42");
+/// ```
+pub struct MappedService<'a, Req, Res, E> {
+    inner: Box<dyn 'a + Send + DynService<'a, Req, Res, E>>,
+}
+
+impl<'a, Req, Res, E> MappedService<'a, Req, Res, E> {
+    /// Box and wrap service
+    pub fn new<S>(service: S) -> Self
+    where
+        S: 'a + Send + Service<Req, Response = Res, Error = E>,
+        S::Future: 'a + Send,
+    {
+        MappedService {
+            inner: Box::new(service),
+        }
+    }
+}
+
+impl<'a, Req, Res, E> Service<Req> for MappedService<'a, Req, Res, E> {
+    type Response = Res;
+    type Error = E;
+    type Future = BoxFuture<'a, Result<Res, E>>;
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        self.inner.poll_ready_dyn(cx)
+    }
+    fn call(&mut self, req: Req) -> Self::Future {
+        self.inner.call_dyn(req)
+    }
+}
+
+struct MapResponse<'a, Req, A, E, F> {
+    service: MappedService<'a, Req, A, E>,
+    f: Arc<Mutex<F>>,
+}
+
+impl<'a, Req, A, B, E, F> Service<Req> for MapResponse<'a, Req, A, E, F>
+where
+    A: 'a,
+    E: 'a,
+    F: 'a + Send + FnMut(A) -> B,
+{
+    type Response = B;
+    type Error = E;
+    type Future = BoxFuture<'a, Result<B, E>>;
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        self.service.poll_ready(cx)
+    }
+    fn call(&mut self, req: Req) -> Self::Future {
+        let future = self.service.call(req);
+        let f = self.f.clone();
+        Box::pin(async move {
+            let a = future.await?;
+            let mut f =
+                f.lock().unwrap_or_else(PoisonError::into_inner);
+            Ok(f(a))
+        })
+    }
+}
+
+struct MapRequest<'a, Req, Res, E, F> {
+    service: MappedService<'a, Req, Res, E>,
+    f: F,
+}
+
+impl<'a, A, Req, Res, E, F> Service<A>
+    for MapRequest<'a, Req, Res, E, F>
+where
+    F: FnMut(A) -> Req,
+{
+    type Response = Res;
+    type Error = E;
+    type Future = BoxFuture<'a, Result<Res, E>>;
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        self.service.poll_ready(cx)
+    }
+    fn call(&mut self, req: A) -> Self::Future {
+        self.service.call((self.f)(req))
+    }
+}
+
+impl<'a, Req, A, B, E> Functor<'a, B> for MappedService<'a, Req, A, E>
+where
+    Req: 'a,
+    A: 'a,
+    B: 'a,
+    E: 'a,
+{
+    type Inner = A;
+    type Mapped = MappedService<'a, Req, B, E>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        MappedService {
+            inner: Box::new(MapResponse {
+                service: self,
+                f: Arc::new(Mutex::new(f)),
+            }),
+        }
+    }
+}
+
+impl<'a, A, Req, Res, E> Contravariant<'a, A>
+    for MappedService<'a, Req, Res, E>
+where
+    A: 'a,
+    Req: 'a,
+    Res: 'a,
+    E: 'a,
+{
+    type Inner = Req;
+    type Mapped = MappedService<'a, A, Res, E>;
+    fn contramap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(A) -> Self::Inner,
+    {
+        MappedService {
+            inner: Box::new(MapRequest { service: self, f }),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Functions into a functor, as profunctors
+//!
+//! See [`Star`].
+
+use super::*;
+
+use kleisli::Kleisli;
+use profunctor::{Choice, Profunctor, Strong};
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// Function returning a [`Functor`], as [`Profunctor`]
+///
+/// A `Star<'a, A, B, M>` wraps a function `A -> M`, where `M` is a functor
+/// with [inner type] `B` (e.g. `M = Option<B>`). It implements
+/// [`Profunctor`], [`Strong`], and [`Choice`], which allows adapting
+/// effectful functions to larger input and output types. It can be
+/// converted from and into a [`Kleisli`] arrow.
+///
+/// [inner type]: Functor::Inner
+///
+/// # Examples
+///
+/// ```
+/// use fmap::profunctor::{Choice, Profunctor, Strong};
+/// use fmap::star::Star;
+///
+/// let parse = Star::new(|s: String| s.parse::<i32>().ok());
+/// let mut double = parse.dimap(|n: u8| n.to_string(), |x| x * 2);
+/// assert_eq!(double.run(21), Some(42));
+///
+/// let mut tagged = Star::new(|x: i32| (x > 0).then_some(x)).first();
+/// assert_eq!(tagged.run((5, "tag")), Some((5, "tag")));
+/// assert_eq!(tagged.run((-5, "tag")), None);
+///
+/// let mut checked = Star::new(|x: i32| (x > 0).then_some(x)).on_ok();
+/// assert_eq!(checked.run(Ok(5)), Some(Ok::<i32, &str>(5)));
+/// assert_eq!(checked.run(Err("skipped")), Some(Err("skipped")));
+/// ```
+pub struct Star<'a, A, B, M> {
+    function: Box<dyn 'a + Send + FnMut(A) -> M>,
+    phantom: PhantomData<fn() -> B>,
+}
+
+impl<'a, A, B, M> Star<'a, A, B, M>
+where
+    A: 'a,
+    M: 'a,
+{
+    /// Create `Star` from function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnMut(A) -> M,
+    {
+        Star {
+            function: Box::new(f),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Call function
+    pub fn run(&mut self, a: A) -> M {
+        (self.function)(a)
+    }
+
+    /// Unwrap boxed function
+    pub fn into_fn(self) -> Box<dyn 'a + Send + FnMut(A) -> M> {
+        self.function
+    }
+
+    /// Convert into [`Kleisli`] arrow
+    pub fn into_kleisli(self) -> Kleisli<'a, A, M> {
+        Kleisli::new(self.function)
+    }
+}
+
+impl<'a, A, B, M> From<Kleisli<'a, A, M>> for Star<'a, A, B, M>
+where
+    A: 'a,
+    M: 'a,
+{
+    fn from(kleisli: Kleisli<'a, A, M>) -> Self {
+        Star::new(kleisli.into_fn())
+    }
+}
+
+impl<'a, A, B, M, D> Functor<'a, D> for Star<'a, A, B, M>
+where
+    A: 'a,
+    B: 'a,
+    M: 'a + Functor<'a, D, Inner = B>,
+    D: 'a,
+{
+    type Inner = B;
+    type Mapped = Star<'a, A, D, M::Mapped>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> D,
+    {
+        self.dimap(|a| a, f)
+    }
+}
+
+impl<'a, A, B, M, C, D> Profunctor<'a, C, D> for Star<'a, A, B, M>
+where
+    A: 'a,
+    B: 'a,
+    M: 'a + Functor<'a, D, Inner = B>,
+    C: 'a,
+    D: 'a,
+{
+    type Input = A;
+    type Output = B;
+    type Dimapped = Star<'a, C, D, M::Mapped>;
+    fn dimap<F, G>(self, mut f: F, g: G) -> Self::Dimapped
+    where
+        F: 'a + Send + FnMut(C) -> A,
+        G: 'a + Send + FnMut(B) -> D,
+    {
+        let mut h = self.function;
+        let g = Arc::new(Mutex::new(g));
+        Star::new(move |c| {
+            let g = g.clone();
+            h(f(c)).fmap(move |b| (g.lock().unwrap())(b))
+        })
+    }
+}
+
+impl<'a, A, B, M, C> Strong<'a, C> for Star<'a, A, B, M>
+where
+    A: 'a,
+    B: 'a,
+    M: 'a + Functor<'a, (B, C), Inner = B>,
+    M: Functor<'a, (C, B), Inner = B>,
+    C: 'a + Clone + Send,
+{
+    type First =
+        Star<'a, (A, C), (B, C), <M as Functor<'a, (B, C)>>::Mapped>;
+    type Second =
+        Star<'a, (C, A), (C, B), <M as Functor<'a, (C, B)>>::Mapped>;
+    fn first(self) -> Self::First {
+        let mut f = self.function;
+        Star::new(move |(a, c): (A, C)| {
+            f(a).fmap(move |b| (b, c.clone()))
+        })
+    }
+    fn second(self) -> Self::Second {
+        let mut f = self.function;
+        Star::new(move |(c, a): (C, A)| {
+            f(a).fmap(move |b| (c.clone(), b))
+        })
+    }
+}
+
+impl<'a, A, B, M, C> Choice<'a, C> for Star<'a, A, B, M>
+where
+    A: 'a,
+    B: 'a,
+    M: 'a + Functor<'a, Result<B, C>, Inner = B>,
+    M: Pure<'a, Result<B, C>>,
+    M: Functor<'a, Result<C, B>, Inner = B>,
+    M: Pure<'a, Result<C, B>>,
+    C: 'a,
+{
+    type OnOk = Star<
+        'a,
+        Result<A, C>,
+        Result<B, C>,
+        <M as Functor<'a, Result<B, C>>>::Mapped,
+    >;
+    type OnErr = Star<
+        'a,
+        Result<C, A>,
+        Result<C, B>,
+        <M as Functor<'a, Result<C, B>>>::Mapped,
+    >;
+    fn on_ok(self) -> Self::OnOk {
+        let mut f = self.function;
+        Star::new(move |r: Result<A, C>| match r {
+            Ok(a) => <M as Functor<'a, Result<B, C>>>::fmap(f(a), Ok),
+            Err(c) => M::pure(Err(c)),
+        })
+    }
+    fn on_err(self) -> Self::OnErr {
+        let mut f = self.function;
+        Star::new(move |r: Result<C, A>| match r {
+            Ok(c) => M::pure(Ok(c)),
+            Err(a) => <M as Functor<'a, Result<C, B>>>::fmap(f(a), Err),
+        })
+    }
+}
@@ -0,0 +1,382 @@
+//! State monad transformer
+//!
+//! See [`StateT`].
+
+use super::*;
+
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// Monad transformer adding a state of type `S` to a base monad
+///
+/// A `StateT<'a, S, M, A>` wraps a function `S -> M`, where the base monad
+/// `M` has the [inner type] `(A, S)`, i.e. it contains the result and the
+/// new state. [Binding] threads the state through the computations, such
+/// that stateful workflows over a fallible or asynchronous base monad don't
+/// require passing the state manually.
+///
+/// [inner type]: Functor::Inner
+/// [Binding]: Monad::bind
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad, MonadTrans};
+/// use fmap::state_t::StateT;
+///
+/// type Counter<A> = StateT<'static, u32, Option<(A, u32)>, A>;
+///
+/// fn tick() -> Counter<()> {
+///     StateT::modify(|n| n + 1)
+/// }
+/// fn count() -> Counter<u32> {
+///     StateT::get()
+/// }
+/// fn checked(x: i32) -> Counter<i32> {
+///     StateT::lift((x >= 0).then_some(x))
+/// }
+///
+/// let program = tick()
+///     .bind(|()| checked(5))
+///     .bind(|x| tick().bind(move |()| count().fmap(move |n| (x, n))));
+/// assert_eq!(program.run_state_t(10), Some(((5, 12), 12)));
+///
+/// let failing = tick().bind(|()| checked(-1));
+/// assert_eq!(failing.run_state_t(0), None);
+/// ```
+pub struct StateT<'a, S, M, A>(
+    Box<dyn 'a + Send + FnOnce(S) -> M>,
+    PhantomData<fn() -> A>,
+);
+
+impl<'a, S, M, A> StateT<'a, S, M, A>
+where
+    S: 'a,
+    M: 'a,
+{
+    /// Create computation from a function returning the base monad
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> M,
+    {
+        StateT(Box::new(f), PhantomData)
+    }
+
+    /// Run computation with initial state
+    ///
+    /// The returned base monad contains the result and the final state.
+    pub fn run_state_t(self, state: S) -> M {
+        (self.0)(state)
+    }
+
+    /// Apply a natural transformation to the base monad
+    pub fn hoist<N, F>(self, nt: F) -> StateT<'a, S, N, A>
+    where
+        N: 'a,
+        A: 'a,
+        F: 'a + Send + FnOnce(M) -> N,
+    {
+        StateT::new(move |s| nt(self.run_state_t(s)))
+    }
+}
+
+impl<'a, S, M> StateT<'a, S, M, S>
+where
+    S: 'a + Clone,
+    M: 'a + Pure<'a, (S, S), Mapped = M>,
+{
+    /// Retrieve current state
+    pub fn get() -> Self {
+        StateT::new(|s: S| M::pure((s.clone(), s)))
+    }
+}
+
+impl<'a, S, M> StateT<'a, S, M, ()>
+where
+    S: 'a,
+    M: 'a + Pure<'a, ((), S), Mapped = M>,
+{
+    /// Replace state
+    pub fn put(state: S) -> Self
+    where
+        S: Send,
+    {
+        StateT::new(move |_| M::pure(((), state)))
+    }
+
+    /// Modify state with a closure
+    pub fn modify<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> S,
+    {
+        StateT::new(move |s| M::pure(((), f(s))))
+    }
+}
+
+/// [`StateT`] over boxed [`Future`]s
+///
+/// The inherent methods of `AsyncState` take async closures directly. Each
+/// of them boxes a single future, instead of wrapping an already boxed
+/// future into another [`StateT`] layer as [`Monad::bind`] would.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::state_t::AsyncState;
+/// use futures::executor::block_on;
+///
+/// async fn fetch(id: u32) -> String {
+///     format!("item {id}")
+/// }
+///
+/// let program = AsyncState::<u32, u32>::get()
+///     .then_async(|id, next_id| async move { (fetch(id).await, next_id + 1) })
+///     .and_then_async(|first| async move {
+///         AsyncState::from_async(|id| async move {
+///             (vec![first, fetch(id).await], id + 1)
+///         })
+///     });
+/// assert_eq!(
+///     block_on(program.run_state_t(7)),
+///     (vec!["item 7".to_string(), "item 8".to_string()], 9),
+/// );
+///
+/// let doubled = AsyncState::modify_async(|n: u32| async move { n * 2 });
+/// assert_eq!(block_on(doubled.run_state_t(21)), ((), 42));
+/// ```
+pub type AsyncState<'a, S, A> =
+    StateT<'a, S, Pin<Box<dyn 'a + Future<Output = (A, S)> + Send>>, A>;
+
+impl<'a, S, A> AsyncState<'a, S, A>
+where
+    S: 'a + Send,
+    A: 'a + Send,
+{
+    /// Create computation from an async closure
+    pub fn from_async<F, Fut>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> Fut,
+        Fut: 'a + Send + Future<Output = (A, S)>,
+    {
+        StateT::new(move |s| Box::pin(f(s)) as Pin<Box<_>>)
+    }
+
+    /// Modify result with an async closure
+    pub fn map_async<B, F, Fut>(self, f: F) -> AsyncState<'a, S, B>
+    where
+        B: 'a + Send,
+        F: 'a + Send + FnOnce(A) -> Fut,
+        Fut: 'a + Send + Future<Output = B>,
+    {
+        AsyncState::from_async(move |s| async move {
+            let (a, s) = self.run_state_t(s).await;
+            (f(a).await, s)
+        })
+    }
+
+    /// Chain an async closure that receives the result and the state
+    pub fn then_async<B, F, Fut>(self, f: F) -> AsyncState<'a, S, B>
+    where
+        B: 'a + Send,
+        F: 'a + Send + FnOnce(A, S) -> Fut,
+        Fut: 'a + Send + Future<Output = (B, S)>,
+    {
+        AsyncState::from_async(move |s| async move {
+            let (a, s) = self.run_state_t(s).await;
+            f(a, s).await
+        })
+    }
+
+    /// Chain an async closure that returns the next computation
+    pub fn and_then_async<B, F, Fut>(self, f: F) -> AsyncState<'a, S, B>
+    where
+        B: 'a + Send,
+        F: 'a + Send + FnOnce(A) -> Fut,
+        Fut: 'a + Send + Future<Output = AsyncState<'a, S, B>>,
+    {
+        AsyncState::from_async(move |s| async move {
+            let (a, s) = self.run_state_t(s).await;
+            f(a).await.run_state_t(s).await
+        })
+    }
+}
+
+impl<'a, S> AsyncState<'a, S, ()>
+where
+    S: 'a + Send,
+{
+    /// Modify state with an async closure
+    pub fn modify_async<F, Fut>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> Fut,
+        Fut: 'a + Send + Future<Output = S>,
+    {
+        AsyncState::from_async(move |s| async move { ((), f(s).await) })
+    }
+}
+
+impl<'a, S, M, A, B> Functor<'a, B> for StateT<'a, S, M, A>
+where
+    S: 'a,
+    M: 'a + Functor<'a, (B, S), Inner = (A, S)>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = StateT<'a, S, M::Mapped, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        StateT::new(move |s| {
+            self.run_state_t(s).fmap(move |(a, s)| (f(a), s))
+        })
+    }
+}
+
+impl<'a, S, M, A, B> Pure<'a, B> for StateT<'a, S, M, A>
+where
+    S: 'a,
+    M: 'a + Pure<'a, (B, S), Inner = (A, S)>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        StateT::new(move |s| M::pure((b, s)))
+    }
+}
+
+impl<'a, S, M, A, B> Monad<'a, B> for StateT<'a, S, M, A>
+where
+    S: 'a,
+    M: 'a + Monad<'a, (B, S), Inner = (A, S)>,
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        StateT::new(move |s| {
+            self.run_state_t(s).bind(move |(a, s)| f(a).run_state_t(s))
+        })
+    }
+}
+
+impl<'a, S, M, N, A> MonadTrans<'a, N> for StateT<'a, S, M, A>
+where
+    S: 'a + Clone + Send,
+    M: 'a,
+    N: 'a + Send + Functor<'a, (A, S), Inner = A, Mapped = M>,
+    A: 'a,
+{
+    fn lift(base: N) -> Self {
+        StateT::new(move |s: S| base.fmap(move |a| (a, s.clone())))
+    }
+}
+
+impl<'a, S, M, A> MonadState<'a, S> for StateT<'a, S, M, A>
+where
+    S: 'a,
+    M: 'a + Pure<'a, (A, S), Mapped = M>,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        StateT::new(move |s| M::pure(f(s)))
+    }
+}
+
+impl<'a, R, S, M, A> MonadReader<'a, R> for StateT<'a, S, M, A>
+where
+    S: 'a + Send,
+    M: 'a + MonadReader<'a, R, Value = (A, S)>,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        StateT::new(move |s| M::reader(move |r| (f(r), s)))
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        StateT::new(move |s| self.run_state_t(s).local(f))
+    }
+}
+
+impl<'a, W, S, M, A> MonadWriter<'a, W> for StateT<'a, S, M, A>
+where
+    W: 'a + Send,
+    S: 'a + Send,
+    M: 'a + MonadWriter<'a, W, Value = (A, S)>,
+    M::Listened: Functor<'a, ((A, W), S), Inner = ((A, S), W)>,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Listened = StateT<
+        'a,
+        S,
+        <M::Listened as Functor<'a, ((A, W), S)>>::Mapped,
+        (A, W),
+    >;
+    fn writer(value: A, log: W) -> Self {
+        StateT::new(move |s| M::writer((value, s), log))
+    }
+    fn listen(self) -> Self::Listened {
+        StateT::new(move |s| {
+            self.run_state_t(s).listen().fmap(|((a, s), w)| ((a, w), s))
+        })
+    }
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        StateT::new(move |s| self.run_state_t(s).censor(f))
+    }
+}
+
+impl<'a, S, M, A> MonadCont<'a> for StateT<'a, S, M, A>
+where
+    S: 'a + Send,
+    M: 'a + MonadCont<'a, Value = (A, S)>,
+    M::Escape: Clone + Send,
+    A: 'a + Send,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        StateT::new(move |s| {
+            M::call_cc(move |escape| f(escape).run_state_t(s))
+        })
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        let escape = escape.clone();
+        StateT::new(move |s| M::escape(&escape, (value, s)))
+    }
+}
+
+impl<'a, S, M, A> MonadIo<'a> for StateT<'a, S, M, A>
+where
+    S: 'a + Send,
+    M: 'a + MonadIo<'a, Value = (A, S)>,
+    A: 'a,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        StateT::new(move |s| M::lift_io(move || (thunk(), s)))
+    }
+}
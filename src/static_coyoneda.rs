@@ -0,0 +1,116 @@
+//! Statically fused mapping of functors
+//!
+//! See [`StaticCoyoneda`].
+
+use super::*;
+
+use coyoneda::Coyoneda;
+
+/// [`Coyoneda`] with an unboxed mapping function
+///
+/// `StaticCoyoneda<T, F>` wraps a [`Functor`] `T` along with a mapping
+/// function `F` that is applied when calling [`StaticCoyoneda::lower`].
+/// Successive calls of [`StaticCoyoneda::map`] compose mapping functions
+/// eagerly and without boxing, such that the wrapped functor is mapped only
+/// once and no intermediate allocations are required.
+///
+/// This provides the same fusion as the Yoneda encoding
+/// `forall b. (a -> b) -> f b` known from Haskell, which requires rank-2
+/// types that can't be stored in a Rust value.
+///
+/// As the type of the mapping function is part of the type of the wrapper
+/// and changes with every call of `map`, `StaticCoyoneda<T, F>` doesn't
+/// implement the [`Functor`] trait. Use [`StaticCoyoneda::into_coyoneda`] to
+/// box the mapping function and obtain a [`Coyoneda`], which implements
+/// `Functor`.
+///
+/// # Examples
+///
+/// ```
+/// use fmap::static_coyoneda::StaticCoyoneda;
+///
+/// let vec = vec![1, 2, 3];
+/// let lowered = StaticCoyoneda::lift(vec)
+///     .map(|x| x * 10)
+///     .map(|x| x + 1)
+///     .map(|x| x.to_string())
+///     .lower();
+/// assert_eq!(lowered, vec!["11", "21", "31"]);
+/// ```
+#[doc(alias = "Yoneda")]
+pub struct StaticCoyoneda<T, F> {
+    functor: T,
+    mapper: F,
+}
+
+impl<T, A> StaticCoyoneda<T, fn(A) -> A> {
+    /// Wrap a functor
+    pub fn lift<'a>(functor: T) -> Self
+    where
+        T: Functor<'a, A, Inner = A>,
+        A: 'a,
+    {
+        StaticCoyoneda {
+            functor,
+            mapper: std::convert::identity,
+        }
+    }
+}
+
+impl<T, F> StaticCoyoneda<T, F> {
+    /// Compose mapping function with `g`
+    ///
+    /// This does not touch the wrapped functor.
+    pub fn map<X, A, B, G>(
+        self,
+        mut g: G,
+    ) -> StaticCoyoneda<T, impl Send + FnMut(X) -> B>
+    where
+        F: Send + FnMut(X) -> A,
+        G: Send + FnMut(A) -> B,
+    {
+        let mut mapper = self.mapper;
+        StaticCoyoneda {
+            functor: self.functor,
+            mapper: move |x| g(mapper(x)),
+        }
+    }
+
+    /// Apply composed mapping function to the wrapped functor
+    pub fn lower<'a, B>(self) -> T::Mapped
+    where
+        T: Functor<'a, B>,
+        B: 'a,
+        F: 'a + Send + FnMut(T::Inner) -> B,
+    {
+        self.functor.fmap(self.mapper)
+    }
+
+    /// Apply composed mapping function followed by `g` to the wrapped functor
+    ///
+    /// `wrapper.run(g)` is equivalent to `wrapper.map(g).lower()`.
+    pub fn run<'a, A, B, G>(self, mut g: G) -> T::Mapped
+    where
+        T: Functor<'a, B>,
+        A: 'a,
+        B: 'a,
+        F: 'a + Send + FnMut(T::Inner) -> A,
+        G: 'a + Send + FnMut(A) -> B,
+    {
+        let mut mapper = self.mapper;
+        self.functor.fmap(move |x| g(mapper(x)))
+    }
+
+    /// Box the mapping function, which turns the wrapper into a
+    /// [`Coyoneda`]
+    pub fn into_coyoneda<'a, X, A>(self) -> Coyoneda<'a, T, A>
+    where
+        T: Functor<'a, X, Inner = X>,
+        T: Functor<'a, A, Inner = X>,
+        X: 'a,
+        A: 'a,
+        F: 'a + Send + FnMut(X) -> A,
+    {
+        Coyoneda::lift(self.functor).fmap(self.mapper)
+    }
+}
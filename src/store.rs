@@ -0,0 +1,190 @@
+//! Store comonad
+//!
+//! See [`Store`].
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Store comonad
+///
+/// A `Store<'a, S, A>` consists of an accessor function, which returns a
+/// value of type `A` for each position of type `S`, and a current position.
+/// The value at the current position can be [extracted], and values at other
+/// positions can be [peeked].
+///
+/// [extracted]: Extract::extract
+/// [peeked]: Store::peek
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Extract, Functor};
+/// use fmap::store::Store;
+///
+/// // one-dimensional cellular automaton (rule 90) on a ring of 8 cells
+/// fn rule(s: Store<'static, usize, bool>) -> bool {
+///     let pos = *s.pos();
+///     s.peek((pos + 7) % 8) ^ s.peek((pos + 1) % 8)
+/// }
+///
+/// let cells = Store::new(|pos: usize| pos == 3, 0);
+/// let cells = cells.extend(rule).extend(rule);
+/// let row: Vec<bool> = (0..8).map(|pos| cells.peek(pos)).collect();
+/// assert_eq!(
+///     row,
+///     vec![false, true, false, false, false, true, false, false],
+/// );
+///
+/// let counts = cells.fmap(|x| x as u8);
+/// assert_eq!(counts.extract(), 0);
+/// ```
+pub struct Store<'a, S, A> {
+    accessor: Arc<dyn 'a + Send + Sync + Fn(S) -> A>,
+    pos: S,
+}
+
+impl<'a, S, A> Clone for Store<'a, S, A>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Store {
+            accessor: self.accessor.clone(),
+            pos: self.pos.clone(),
+        }
+    }
+}
+
+impl<'a, S, A> Store<'a, S, A> {
+    /// Create store from accessor function and initial position
+    pub fn new<F>(accessor: F, pos: S) -> Self
+    where
+        F: 'a + Send + Sync + Fn(S) -> A,
+    {
+        Store {
+            accessor: Arc::new(accessor),
+            pos,
+        }
+    }
+
+    /// Current position
+    pub fn pos(&self) -> &S {
+        &self.pos
+    }
+
+    /// Value at the given position
+    pub fn peek(&self, pos: S) -> A {
+        (self.accessor)(pos)
+    }
+
+    /// Value at a position relative to the current position
+    pub fn peeks<F>(&self, f: F) -> A
+    where
+        F: FnOnce(&S) -> S,
+    {
+        (self.accessor)(f(&self.pos))
+    }
+
+    /// Move to the given position
+    pub fn seek(self, pos: S) -> Self {
+        Store {
+            accessor: self.accessor,
+            pos,
+        }
+    }
+
+    /// Move to a position relative to the current position
+    pub fn seeks<F>(self, f: F) -> Self
+    where
+        F: FnOnce(S) -> S,
+    {
+        Store {
+            accessor: self.accessor,
+            pos: f(self.pos),
+        }
+    }
+
+    /// Retrieve values at several positions
+    ///
+    /// The function `f` returns a [`Functor`] of positions relative to the
+    /// current position. The positions are replaced with the values at these
+    /// positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fmap::store::Store;
+    ///
+    /// let store = Store::new(|pos: i32| pos * 10, 5);
+    /// let neighbors = store.experiment(|pos| vec![pos - 1, pos + 1]);
+    /// assert_eq!(neighbors, vec![40, 60]);
+    /// ```
+    pub fn experiment<T, F>(&self, f: F) -> T::Mapped
+    where
+        S: 'a,
+        A: 'a,
+        T: Functor<'a, A, Inner = S>,
+        F: FnOnce(&S) -> T,
+    {
+        let accessor = self.accessor.clone();
+        f(&self.pos).fmap(move |pos| accessor(pos))
+    }
+}
+
+impl<'a, S, A, B> Functor<'a, B> for Store<'a, S, A>
+where
+    S: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Store<'a, S, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let accessor = self.accessor;
+        let f = Mutex::new(f);
+        Store {
+            accessor: Arc::new(move |pos| {
+                (f.lock().unwrap())(accessor(pos))
+            }),
+            pos: self.pos,
+        }
+    }
+}
+
+impl<'a, S, A> Extract<'a, A> for Store<'a, S, A>
+where
+    S: 'a,
+    A: 'a,
+{
+    fn extract(self) -> A {
+        (self.accessor)(self.pos)
+    }
+}
+
+impl<'a, S, A, B> Comonad<'a, B> for Store<'a, S, A>
+where
+    S: 'a,
+    A: 'a,
+    B: 'a,
+{
+    fn extend<F>(self, f: F) -> <Self as Functor<'a, B>>::Mapped
+    where
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        let accessor = self.accessor;
+        let f = Mutex::new(f);
+        Store {
+            accessor: Arc::new(move |pos| {
+                (f.lock().unwrap())(Store {
+                    accessor: accessor.clone(),
+                    pos,
+                })
+            }),
+            pos: self.pos,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Supply of fresh identifiers
+//!
+//! See [`Supply`].
+
+use super::*;
+
+/// Monad threading a counter of fresh (unique) identifiers through a
+/// computation
+///
+/// A `Supply<'a, A>` is a computation that may request [fresh]
+/// identifiers (of type [`u64`]) and that finally results in a value of
+/// type `A`. Each identifier is returned only once during a [run].
+///
+/// [fresh]: Supply::fresh
+/// [run]: Supply::run
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::supply::Supply;
+///
+/// #[derive(PartialEq, Debug)]
+/// enum Expr {
+///     Var(u64),
+///     Lambda(u64, Box<Expr>),
+/// }
+///
+/// fn lambda<F>(body: F) -> Supply<'static, Expr>
+/// where
+///     F: 'static + Send + FnOnce(u64) -> Supply<'static, Expr>,
+/// {
+///     Supply::fresh().and_then(move |var| {
+///         body(var).fmap(move |expr| Expr::Lambda(var, Box::new(expr)))
+///     })
+/// }
+///
+/// let identity = lambda(|x| lambda(move |_| Supply::pure(Expr::Var(x))));
+/// assert_eq!(
+///     identity.run(),
+///     Expr::Lambda(0, Box::new(Expr::Lambda(1, Box::new(Expr::Var(0))))),
+/// );
+/// ```
+pub struct Supply<'a, A>(Box<dyn 'a + Send + FnOnce(u64) -> (A, u64)>);
+
+impl<'a, A> Supply<'a, A>
+where
+    A: 'a,
+{
+    /// Create computation from a function receiving the next fresh
+    /// identifier and returning the result along with the next fresh
+    /// identifier afterwards
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(u64) -> (A, u64),
+    {
+        Supply(Box::new(f))
+    }
+
+    /// Computation without side effects
+    pub fn pure(a: A) -> Self
+    where
+        A: Send,
+    {
+        Supply::new(move |next| (a, next))
+    }
+
+    /// Run computation with identifiers starting at zero
+    pub fn run(self) -> A {
+        self.run_from(0).0
+    }
+
+    /// Run computation with identifiers starting at `start`, and return
+    /// the result along with the next unused identifier
+    pub fn run_from(self, start: u64) -> (A, u64) {
+        (self.0)(start)
+    }
+
+    /// Modify result with a closure
+    pub fn map<B, F>(self, f: F) -> Supply<'a, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnOnce(A) -> B,
+    {
+        Supply::new(move |next| {
+            let (a, next) = self.run_from(next);
+            (f(a), next)
+        })
+    }
+
+    /// Chain a computation that depends on the result
+    pub fn and_then<B, F>(self, f: F) -> Supply<'a, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnOnce(A) -> Supply<'a, B>,
+    {
+        Supply::new(move |next| {
+            let (a, next) = self.run_from(next);
+            f(a).run_from(next)
+        })
+    }
+}
+
+impl<'a> Supply<'a, u64> {
+    /// Request a fresh identifier
+    pub fn fresh() -> Self {
+        Supply::new(|next| (next, next + 1))
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Supply<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Supply<'a, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.map(f)
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Supply<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Supply::pure(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Supply<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.and_then(f)
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Supply<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn apply(
+        self,
+        f: Supply<'a, BoxMapper<'a, Self, B>>,
+    ) -> Supply<'a, B> {
+        f.and_then(move |mapper| self.map(mapper))
+    }
+}
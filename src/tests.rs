@@ -36,6 +36,349 @@ fn test_result() {
     assert_eq!(ok, Ok(150));
     let err: Result<i32, i32> = Err(0);
     assert_eq!(err.fmap(|x| x + 1), Err(0));
+    let ok: Result<i32, &str> = Ok(3);
+    assert_eq!(ok.bimap(|e| e, |x| x + 1), ok.fmap(|x| x + 1));
+    assert_eq!(ok.bimap(str::len, |x| x + 1), Ok(4));
+    let err: Result<i32, &str> = Err("bad");
+    assert_eq!(err.bimap(str::len, |x| x + 1), Err(3));
+}
+
+#[test]
+fn test_array() {
+    use universal::UniversalFunctor;
+    let a: [i32; 3] = [1, 2, 3];
+    let mut b: [String; 3] = a.fmap(|x| x.to_string());
+    assert_eq!(b, ["1", "2", "3"]);
+    b.fmap_mut(|s| s.push('!'));
+    assert_eq!(b, ["1!", "2!", "3!"]);
+    let repeated: [char; 4] = <[(); 4]>::pure('x');
+    assert_eq!(repeated, ['x'; 4]);
+    fn round_trip<'a, T>(functor: T) -> T
+    where
+        T: UniversalFunctor<'a, u8, Inner = u8>,
+    {
+        let functor = functor
+            .change_functor_target()
+            .fmap(|x| format!("{x}"))
+            .change_functor_target()
+            .fmap(|x| x.parse::<u8>().unwrap() + 1);
+        T::from_mapped_functor(functor)
+    }
+    assert_eq!(round_trip([1, 2]), [2, 3]);
+}
+
+#[test]
+fn test_bound() {
+    use std::ops::{Bound, RangeBounds};
+    let bound: Bound<i32> = Bound::Included(3);
+    assert_eq!(bound.fmap(|x| x * 2), Bound::Included(6));
+    assert_eq!(
+        Bound::<i32>::Unbounded.fmap(|x| x * 2),
+        Bound::Unbounded
+    );
+    let map = BTreeMap::from([("a", 1), ("b", 2), ("c", 3), ("d", 4)]);
+    let range = (2..4).start_bound().cloned();
+    let range = (range, (2..4).end_bound().cloned());
+    let keys: (Bound<&str>, Bound<&str>) =
+        range.fmap(|i| ["", "a", "b", "c", "d"][i as usize]);
+    assert_eq!(keys, (Bound::Included("b"), Bound::Excluded("d")));
+    let values: Vec<i32> =
+        map.range::<&str, _>(keys).map(|(_, v)| *v).collect();
+    assert_eq!(values, [2, 3]);
+    let mut bounds = (Bound::Excluded(1), Bound::Included(5));
+    bounds.fmap_mut(|x| *x += 10);
+    assert_eq!(bounds, (Bound::Excluded(11), Bound::Included(15)));
+}
+
+#[test]
+fn test_box() {
+    let mut boxed: Box<i32> = Box::new(2);
+    let address = &*boxed as *const i32;
+    boxed = boxed.fmap_fn_mutref(|x| *x *= 5);
+    assert_eq!(&*boxed as *const i32, address);
+    boxed.fmap_mut(|x| *x += 1);
+    assert_eq!(*boxed, 11);
+    let text: Box<String> = boxed.bind(|x| Box::new(x.to_string()));
+    assert_eq!(*text, "11");
+    fn double<'a, M>(m: M) -> M
+    where
+        M: Monad<'a, i32, Inner = i32, Mapped = M>,
+    {
+        m.bind(|x| M::pure(x * 2))
+    }
+    assert_eq!(*double(Box::new(4)), 8);
+    let f: Box<BoxMapper<Box<i32>, i32>> =
+        Box::new(Box::new(|x| x - 1));
+    assert_eq!(*Box::new(4).apply(f), 3);
+}
+
+#[test]
+fn test_rc() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+    let unique = Rc::new(vec![1, 2]);
+    let address = unique.as_ptr();
+    let mapped: Rc<Vec<i32>> = unique.fmap(|mut v| {
+        v.push(3);
+        v
+    });
+    assert_eq!(mapped.as_ptr(), address);
+    let shared = mapped.clone();
+    let mut modified = mapped.fmap_fn_mutref(|v| v.push(4));
+    assert_eq!(*shared, [1, 2, 3]);
+    assert_eq!(*modified, [1, 2, 3, 4]);
+    modified.fmap_mut(|v| v.clear());
+    assert!(modified.is_empty());
+    let arc = Arc::new(5);
+    let other = arc.clone();
+    assert_eq!(*arc.fmap(|x| x * 2), 10);
+    assert_eq!(*other, 5);
+}
+
+#[test]
+fn test_slices() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+    let boxed: Box<[i32]> = vec![1, 2].into_boxed_slice();
+    let mut mapped: Box<[String]> = boxed.fmap(|x| x.to_string());
+    mapped.fmap_mut(|s| s.push('!'));
+    assert_eq!(&*mapped, ["1!", "2!"]);
+    let rc: Rc<[i32]> = Rc::from([3, 4]);
+    let shared = rc.clone();
+    assert_eq!(&*rc.fmap(|x| x * 2), [6, 8]);
+    assert_eq!(&*shared, [3, 4]);
+    let arc: Arc<[i32]> = Arc::from([5, 6]);
+    assert_eq!(&*arc.fmap(|x| x > 5), [false, true]);
+}
+
+#[test]
+fn test_cells() {
+    use std::cell::{Cell, RefCell};
+    fn sanitize<'a, T>(value: &mut T)
+    where
+        T: FunctorMut<'a, String>,
+    {
+        value.fmap_mut(|s| s.retain(|c| c.is_alphanumeric()));
+    }
+    let mut cell = Cell::new(String::from("a-b"));
+    sanitize(&mut cell);
+    assert_eq!(cell.into_inner(), "ab");
+    let mut ref_cell = RefCell::new(String::from("c d!"));
+    sanitize(&mut ref_cell);
+    assert_eq!(*ref_cell.borrow(), "cd");
+    let lengths: RefCell<usize> = ref_cell.fmap(|s| s.len());
+    assert_eq!(lengths.into_inner(), 2);
+}
+
+#[test]
+fn test_locks() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::{Mutex, RwLock};
+    let mut mutex = Mutex::new(vec![1, 2]);
+    mutex.fmap_mut(|v| v.push(3));
+    assert_eq!(*mutex.lock().unwrap(), [1, 2, 3]);
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let _guard = mutex.lock().unwrap();
+        panic!("poison");
+    }));
+    assert!(mutex.is_poisoned());
+    mutex.fmap_mut(|v| v.push(4));
+    assert!(mutex.is_poisoned());
+    let lengths: Mutex<usize> = mutex.fmap(|v| v.len());
+    assert!(!lengths.is_poisoned());
+    assert_eq!(*lengths.lock().unwrap(), 4);
+    let mut lock = RwLock::new(5);
+    lock.fmap_mut(|x| *x += 1);
+    assert_eq!(*lock.fmap(|x| x * 2).read().unwrap(), 12);
+}
+
+#[test]
+fn test_thunk_monad() {
+    use std::sync::{Arc, Mutex};
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let log2 = log.clone();
+    let thunk: Box<dyn Send + FnOnce() -> i32> = Box::new(move || {
+        log2.lock().unwrap().push("first");
+        2
+    });
+    let log3 = log.clone();
+    let composed = thunk.bind(move |x| {
+        let log = log3.clone();
+        Box::new(move || {
+            log.lock().unwrap().push("second");
+            x * 10
+        }) as Box<dyn Send + FnOnce() -> i32>
+    });
+    assert!(log.lock().unwrap().is_empty());
+    assert_eq!(composed(), 20);
+    assert_eq!(*log.lock().unwrap(), ["first", "second"]);
+    let local: Box<dyn FnOnce() -> String> =
+        Box::new(|| "a".to_string());
+    let joined = local.bind(|a| {
+        Box::new(move || a + "b") as Box<dyn FnOnce() -> String>
+    });
+    assert_eq!(joined(), "ab");
+}
+
+#[test]
+fn test_function_reader() {
+    type Ask<A> = Box<dyn Send + FnOnce(i32) -> A>;
+    let doubled: Ask<i32> = Box::new(|r| r * 2);
+    let program = doubled.bind(|d| -> Ask<String> {
+        Ask::<i32>::reader(move |r| r + d).fmap(|s| s.to_string())
+    });
+    assert_eq!(program(5), "15");
+    let local = Ask::<i32>::reader(|r| r).local(|r| r - 1);
+    assert_eq!(local(5), 4);
+    let mut counter: Box<dyn FnMut(u8) -> u8> = Box::new(|x| x + 1);
+    counter.fmap_mut(|y| *y *= 2);
+    let mut sum = counter.bind(|y| Box::new(move |x| x + y));
+    assert_eq!(sum(1), 5);
+    assert_eq!(sum(2), 8);
+}
+
+#[test]
+fn test_comparison() {
+    use comparison::Comparison;
+    use std::cmp::Ordering::*;
+    let mut pairs = Comparison::<u8>::natural()
+        .reverse()
+        .divide(Comparison::natural(), |(a, b): (u8, char)| (a, b));
+    assert_eq!(pairs.compare(&(1, 'a'), &(2, 'a')), Greater);
+    assert_eq!(pairs.compare(&(1, 'a'), &(1, 'b')), Less);
+    let mut either = Comparison::<i32>::natural().choose(
+        Comparison::<String>::natural(),
+        |x: Result<i32, String>| x,
+    );
+    assert_eq!(either.compare(&Ok(5), &Err("a".to_string())), Less);
+    assert_eq!(either.compare(&Err("b".to_string()), &Ok(1)), Greater);
+    assert_eq!(either.compare(&Ok(1), &Ok(1)), Equal);
+    let mut all = <Comparison<()> as Divisible<i32, ()>>::conquer();
+    assert_eq!(all.compare(&1, &2), Equal);
+}
+
+#[test]
+fn test_predicate() {
+    use predicate::Predicate;
+    let long = Predicate::new(|s: &String| s.len() > 2);
+    let mut long_name = long
+        .by_ref(|(name, _): &(String, u8)| name)
+        .or(Predicate::new(|(_, age): &(String, u8)| *age > 60));
+    assert!(long_name.test(&("Ann".to_string(), 3)));
+    assert!(long_name.test(&("Al".to_string(), 70)));
+    assert!(!long_name.test(&("Al".to_string(), 7)));
+    let mut odd = Predicate::new(|x: &u8| x & 1 == 0)
+        .not()
+        .contramap(|x: u16| x as u8);
+    assert!(odd.test(&257));
+    let mut always = <Predicate<()> as Divisible<i32, ()>>::conquer();
+    assert!(always.test(&0));
+}
+
+#[test]
+fn test_control_flow() {
+    use std::ops::ControlFlow;
+    fn step(acc: i32, x: i32) -> ControlFlow<i32, i32> {
+        if acc + x > 10 {
+            ControlFlow::Break(acc)
+        } else {
+            ControlFlow::Continue(acc + x)
+        }
+    }
+    let continued = step(0, 3).bind(|acc| step(acc, 4));
+    assert_eq!(continued, ControlFlow::Continue(7));
+    let broken =
+        continued.bind(|acc| step(acc, 5)).fmap(|acc| acc * 100);
+    assert_eq!(broken, ControlFlow::Break(7));
+    let recovered = broken.catch_error(ControlFlow::Continue);
+    assert_eq!(recovered, ControlFlow::Continue(7));
+    let mut flow: ControlFlow<(), i32> = ControlFlow::Continue(1);
+    flow.fmap_mut(|x| *x += 1);
+    assert_eq!(flow, ControlFlow::Continue(2));
+    let f: ControlFlow<(), BoxMapper<ControlFlow<(), i32>, i32>> =
+        ControlFlow::Continue(Box::new(|x| x * 3));
+    assert_eq!(flow.apply(f), ControlFlow::Continue(6));
+}
+
+#[test]
+fn test_cow() {
+    use std::borrow::Cow;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+    #[derive(Debug, PartialEq)]
+    struct Counted(i32);
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Counted(self.0)
+        }
+    }
+    let owned: Cow<Counted> = Cow::Owned(Counted(1));
+    let mapped: Cow<Counted> = owned.fmap(|Counted(x)| Counted(x + 1));
+    assert_eq!(mapped, Cow::<Counted>::Owned(Counted(2)));
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+    let value = Counted(10);
+    let mut borrowed: Cow<Counted> = Cow::Borrowed(&value);
+    borrowed.fmap_mut(|Counted(x)| *x *= 2);
+    assert_eq!(borrowed.0, 20);
+    assert_eq!(value, Counted(10));
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_phantom_data() {
+    use std::marker::PhantomData;
+    fn never_called<'a, M>(m: M) -> M
+    where
+        M: Monad<'a, i32, Inner = i32, Mapped = M>,
+    {
+        m.bind(|x| M::pure(x + 1))
+            .fmap(|x| -> i32 { panic!("{x}") })
+    }
+    let proxy: PhantomData<i32> = never_called(PhantomData);
+    assert_eq!(proxy, PhantomData);
+    let mapped: PhantomData<String> = proxy.fmap(|x| x.to_string());
+    assert_eq!(mapped, PhantomData::<String>);
+    assert_eq!(<PhantomData<()>>::pure(5), PhantomData::<i32>);
+}
+
+#[test]
+fn test_poll() {
+    use std::task::Poll;
+    let ready: Poll<i32> = Poll::Ready(2);
+    assert_eq!(ready.fmap(|x| x + 1), Poll::Ready(3));
+    assert_eq!(ready.bind(|_| Poll::<i32>::Pending), Poll::Pending);
+    let pending: Poll<i32> = Poll::Pending;
+    assert_eq!(pending.bind(|x| Poll::Ready(x * 2)), Poll::Pending);
+    let mut polled = Poll::Ready(5);
+    polled.fmap_mut(|x| *x *= 2);
+    assert_eq!(polled, Poll::Ready(10));
+    let item: Poll<Option<i32>> = Poll::Ready(Some(1));
+    assert_eq!(poll::fmap_item(item, |x| x + 1), Poll::Ready(Some(2)));
+    let end: Poll<Option<i32>> = Poll::Ready(None);
+    assert_eq!(
+        poll::bind_item(end, |_| Poll::<Option<()>>::Pending),
+        Poll::Ready(None)
+    );
+}
+
+#[test]
+fn test_wrappers() {
+    use std::cmp::Reverse;
+    use std::num::{Saturating, Wrapping};
+    let wrapping = Wrapping(250u8).fmap(|x| x as u16 + 10);
+    assert_eq!(wrapping, Wrapping(260u16));
+    let mut saturating = Saturating(100u8);
+    saturating.fmap_mut(|x| *x /= 2);
+    assert_eq!(saturating, Saturating(50));
+    let mut sorted = vec![Reverse(1), Reverse(3), Reverse(2)];
+    sorted.sort();
+    let sorted: Vec<Reverse<String>> =
+        sorted.fmap(|r| r.fmap(|x| x.to_string()));
+    assert_eq!(
+        sorted,
+        ["3", "2", "1"].fmap(|s| Reverse(s.to_string()))
+    );
 }
 
 #[test]
@@ -118,6 +461,118 @@ fn test_btree_set() {
     assert!(b.contains("48!"));
 }
 
+#[test]
+fn test_set_monad() {
+    let a: HashSet<i32> = HashSet::from_iter([-2, 1, 2]);
+    let squares = a.fmap(|x| x * x);
+    assert_eq!(squares, HashSet::from_iter([1, 4]));
+    let b: HashSet<i32> =
+        squares.bind(|x| HashSet::from_iter([x, x + 3]));
+    assert_eq!(b, HashSet::from_iter([1, 4, 7]));
+    let c: BTreeSet<i32> = BTreeSet::from_iter([1, 2, 3]);
+    let d = c.bind(|x| BTreeSet::from_iter([x / 2, x * 2]));
+    assert_eq!(d.into_iter().collect::<Vec<_>>(), [0, 1, 2, 4, 6]);
+    assert_eq!(
+        HashSet::<()>::pure(5).bind(|x| HashSet::from_iter([x])),
+        HashSet::from_iter([5])
+    );
+}
+
+#[test]
+fn test_merge_map() {
+    use crate::merge_map::{Combine, KeepLeft, KeepRight, MergeMap};
+    use crate::monoid::Sum;
+    fn split(x: i32) -> HashMap<&'static str, Sum<i32>> {
+        let parity = if x % 2 == 0 { "even" } else { "odd" };
+        HashMap::from([(parity, Sum(x)), ("all", Sum(x))])
+    }
+    let map: HashMap<&str, i32> = HashMap::from([("a", 1), ("b", 4)]);
+    let mut wrapped = MergeMap::<_, Combine>::new(map.clone());
+    wrapped.fmap_mut(|x| *x += 10);
+    let combined = wrapped.bind(|x| MergeMap::new(split(x)));
+    assert_eq!(
+        combined.into_inner(),
+        HashMap::from([
+            ("odd", Sum(11)),
+            ("even", Sum(14)),
+            ("all", Sum(25))
+        ])
+    );
+    let sorted: BTreeMap<&str, i32> = map.into_iter().collect();
+    let left = MergeMap::<_, KeepLeft>::new(sorted.clone())
+        .bind(|x| MergeMap::new(BTreeMap::from([("x", x)])));
+    assert_eq!(left.into_inner(), BTreeMap::from([("x", 1)]));
+    let right = MergeMap::<_, KeepRight>::new(sorted)
+        .bind(|x| MergeMap::new(BTreeMap::from([("x", x)])));
+    assert_eq!(right.into_inner(), BTreeMap::from([("x", 4)]));
+    let pure = MergeMap::<BTreeMap<u8, ()>, KeepLeft>::pure("x");
+    assert_eq!(
+        pure.fmap(str::len).into_inner(),
+        BTreeMap::from([(0, 1)])
+    );
+}
+
+#[test]
+fn test_on_err() {
+    use crate::on_err::OnErr;
+    let failed: Result<u8, String> = Err("bad".to_string());
+    let mut wrapped = OnErr(failed).fmap(|e| e.len());
+    assert_eq!(wrapped, OnErr(Err(3)));
+    wrapped.fmap_mut(|n| *n *= 2);
+    assert_eq!(wrapped, OnErr(Err(6)));
+    assert_eq!(
+        wrapped.bind(|n| OnErr(Ok::<u8, ()>(n as u8))),
+        OnErr(Ok(6))
+    );
+    let ok: OnErr<Result<u8, usize>> = OnErr(Ok(1));
+    assert_eq!(ok.bind(|_| OnErr(Err("never"))), OnErr(Ok(1)));
+    assert_eq!(OnErr::<Result<u8, ()>>::pure(5), OnErr(Err(5)));
+    type Errs = OnErr<Result<u8, i32>>;
+    let mapper: OnErr<Result<u8, BoxMapper<Errs, i32>>> =
+        OnErr(Err(Box::new(|e| e + 1)));
+    assert_eq!(OnErr(Err(1)).apply(mapper), OnErr(Err(2)));
+}
+
+#[test]
+fn test_lazy_iter() {
+    use crate::lazy_iter::LazyIter;
+    let words = LazyIter(["ab", "c"].into_iter())
+        .fmap(str::chars)
+        .bind(|chars| chars.map(|c| c.to_ascii_uppercase()));
+    assert_eq!(words.size_hint().0, 0);
+    let boxed = words.boxed().fmap(String::from);
+    assert_eq!(boxed.collect::<Vec<_>>(), ["A", "B", "C"]);
+    assert_eq!(
+        LazyIter(0..3).fmap(|x| x * 2).into_inner().sum::<i32>(),
+        6
+    );
+}
+
+#[test]
+fn test_mapped_receiver() {
+    use crate::receiver::MappedReceiver;
+    use std::sync::mpsc::channel;
+    let (tx, rx) = channel();
+    tx.send("a").unwrap();
+    tx.send("bc").unwrap();
+    drop(tx);
+    let mut lengths: MappedReceiver<usize> =
+        MappedReceiver::from(rx).fmap(str::len);
+    lengths.fmap_mut(|n| *n *= 10);
+    let mut flattened = lengths.bind(|n| {
+        let (tx, rx) = channel();
+        for i in 0..n / 10 {
+            tx.send(n + i).unwrap();
+        }
+        MappedReceiver::new(rx)
+    });
+    assert_eq!(flattened.recv(), Some(10));
+    assert_eq!(flattened.collect::<Vec<_>>(), [20, 21]);
+    let mut single = MappedReceiver::<()>::pure(5);
+    assert_eq!(single.recv(), Some(5));
+    assert_eq!(single.recv(), None);
+}
+
 #[test]
 fn test_binary_heap() {
     let a: BinaryHeap<i32> = BinaryHeap::from_iter([5, 6]);
@@ -127,6 +582,24 @@ fn test_binary_heap() {
     assert_eq!(b.into_sorted_vec(), ["40!", "48!"]);
 }
 
+#[test]
+fn test_binary_heap_bind() {
+    use std::cmp::Reverse;
+    fn tasks(priority: u8) -> BinaryHeap<(u8, String)> {
+        (0..priority)
+            .map(|i| (priority - i, format!("{priority}.{i}")))
+            .collect()
+    }
+    let priorities: BinaryHeap<u8> = BinaryHeap::from_iter([2, 3]);
+    let merged = priorities.bind(tasks);
+    assert_eq!(merged.len(), 5);
+    let order: Vec<String> = merged
+        .fmap(|(p, name)| Reverse((p, name)))
+        .into_sorted_vec()
+        .fmap(|Reverse((_, name))| name);
+    assert_eq!(order, ["3.0", "3.1", "2.0", "3.2", "2.1"]);
+}
+
 #[test]
 fn test_boxed_fn() {
     let mut f: Box<dyn FnMut() -> String> =
@@ -152,6 +625,7 @@ fn test_contravariant() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_boxed_iterator() {
     use std::sync::Mutex;
     let strings: Vec<String> = vec!["A".to_string(), "B".to_string()];
@@ -237,6 +711,41 @@ fn test_future_monad() {
     assert_eq!(block_on(fut2), 14);
 }
 
+#[test]
+fn test_future_send_sync() {
+    use futures::executor::block_on;
+    use std::future::Future;
+    use std::pin::Pin;
+    type SyncFuture<A> =
+        Pin<Box<dyn 'static + Future<Output = A> + Send + Sync>>;
+    fn assert_sync<T: Sync>(value: T) -> T {
+        value
+    }
+    let fut = SyncFuture::<()>::pure(3);
+    let mut fut = assert_sync(fut.fmap(|x| x * 2));
+    fut.fmap_mut(|x| *x += 1);
+    let fut = fut.bind(|x| SyncFuture::<()>::pure(x.to_string()));
+    assert_eq!(block_on(assert_sync(fut)), "7");
+    let mapper: SyncFuture<BoxMapper<SyncFuture<i32>, i32>> =
+        SyncFuture::<()>::pure(Box::new(|x| x + 1));
+    let applied = SyncFuture::<()>::pure(1).apply(mapper);
+    assert_eq!(block_on(applied), 2);
+}
+
+#[test]
+fn test_fut() {
+    use fut::Fut;
+    use futures::executor::block_on;
+    use std::future::ready;
+    let pipeline = Fut(ready(2))
+        .bind(|x| Box::pin(async move { x * 3 }))
+        .fmap(|x| vec![x; 2]);
+    assert_eq!(block_on(pipeline), [6, 6]);
+    let boxed = Fut(ready("a")).fmap(str::len).boxed().fmap(|n| n + 1);
+    assert_eq!(block_on(boxed), 2);
+    assert_eq!(block_on(Fut(ready(1)).into_inner()), 1);
+}
+
 #[test]
 fn test_nested_monad_trait() {
     fn func1<'a, T: NestedMonad<'a, A>, A>(x: T) -> A
@@ -254,6 +763,56 @@ fn test_nested_monad_trait() {
     let nested = vec![vec![1, 3], vec![2, 9, 9]];
     assert_eq!(func1(nested.clone()), vec![1, 3, 2, 9, 9]);
     assert_eq!(func2(nested), vec![1, 3, 2, 9, 9]);
+    assert_eq!(Some(Some(4)).mjoin(), Some(4));
+    assert_eq!(Ok::<Result<i32, ()>, ()>(Err(())).mjoin(), Err(()));
+    type BoxFut<T> =
+        std::pin::Pin<Box<dyn std::future::Future<Output = T>>>;
+    let inner: BoxFut<i32> = Box::pin(std::future::ready(5));
+    let future: BoxFut<BoxFut<i32>> =
+        Box::pin(std::future::ready(inner));
+    assert_eq!(futures::executor::block_on(future.mjoin()), 5);
+}
+
+#[test]
+fn test_zip_apply() {
+    let mut offset = 10;
+    let fs = [|a: i32| a * 2, |a: i32| a * 3];
+    assert_eq!(zip_apply(fs, [4, 5]), [8, 15]);
+    let fs: [Box<dyn FnOnce(i32) -> i32>; 2] = [
+        Box::new(|a| a - 1),
+        Box::new(|a| {
+            offset += a;
+            offset
+        }),
+    ];
+    assert_eq!(zip_apply(fs, [1, 2]), [0, 12]);
+    let empty: [fn(i32) -> i32; 0] = [];
+    assert_eq!(zip_apply(empty, []), [0; 0]);
+}
+
+#[test]
+fn test_monad_join_monad() {
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    let nested = vec![vec![1, 3], Vec::new(), vec![2, 9, 9]];
+    assert_eq!(nested.join_monad(), vec![1, 3, 2, 9, 9]);
+    assert_eq!(Some(Some(4)).join_monad(), Some(4));
+    assert_eq!(Some(None::<i32>).join_monad(), None);
+    let nested: Result<Result<i32, &str>, &str> = Ok(Err("inner"));
+    assert_eq!(nested.join_monad(), Err("inner"));
+    let nested: Result<Result<i32, &str>, &str> = Err("outer");
+    assert_eq!(nested.join_monad(), Err("outer"));
+    let future: BoxFuture<BoxFuture<i32>> =
+        std::future::ready(std::future::ready(6).boxed()).boxed();
+    assert_eq!(block_on(future.join_monad()), 6);
+    type BoxFut<T> =
+        std::pin::Pin<Box<dyn std::future::Future<Output = T>>>;
+    let inner: BoxFut<i32> = Box::pin(std::future::ready(7));
+    let future: BoxFut<BoxFut<i32>> =
+        Box::pin(std::future::ready(inner));
+    assert_eq!(block_on(future.join_monad()), 7);
+    let iter: Box<dyn Iterator<Item = Vec<i32>>> =
+        Box::new(vec![vec![1], vec![2, 3]].into_iter());
+    assert_eq!(iter.flatten().collect::<Vec<_>>(), vec![1, 2, 3]);
 }
 
 #[test]
@@ -263,6 +822,146 @@ fn test_applicative_fmap() {
     assert_eq!(b, vec![45, 33, 9]);
 }
 
+#[test]
+fn test_monad_then() {
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    let mut calls = 0;
+    let a = vec![1, 2, 3].mthen(|| {
+        calls += 1;
+        vec![calls; calls]
+    });
+    assert_eq!(a, vec![1, 2, 2, 3, 3, 3]);
+    assert_eq!(None::<i32>.mthen(|| -> Option<u8> { panic!() }), None);
+    assert_eq!(Ok::<_, ()>(1).followed_by(Ok("a")), Ok("a"));
+    assert_eq!(Err::<i32, _>(1).followed_by(Ok("a")), Err(1));
+    let fut: BoxFuture<'_, i32> = Box::pin(async { 1 });
+    let fut = fut.mthen(|| async { 2 }.boxed());
+    assert_eq!(block_on(fut), 2);
+    let fut: BoxFuture<'_, i32> = Box::pin(async { 3 });
+    let fut = fut.then(|x| async move { x * 2 });
+    assert_eq!(block_on(fut), 6);
+}
+
+#[test]
+fn test_map2_map3() {
+    assert_eq!(map2(Some(2), Some(5), |a, b| a + b), Some(7));
+    assert_eq!(map2(None, Some(5), |a: i32, b| a + b), None);
+    let pairs = map2(vec![1, 2], vec!['x', 'y'], |n, c| (n, c));
+    assert_eq!(pairs, [(1, 'x'), (1, 'y'), (2, 'x'), (2, 'y')]);
+    let mut calls = 0;
+    let sums = map3(vec![1, 2], vec![10], vec![100, 200], |a, b, c| {
+        calls += 1;
+        a + b + c
+    });
+    assert_eq!(sums, [111, 211, 112, 212]);
+    assert_eq!(calls, 4);
+    let failed =
+        map3(Ok(1), Err("e"), Ok(3), |a: i32, b: i32, c| a + b + c);
+    assert_eq!(failed, Err("e"));
+}
+
+#[test]
+fn test_sequence() {
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    use std::sync::{Arc, Mutex};
+    assert_eq!(sequence(Vec::<Option<i32>>::new()), Some(vec![]));
+    assert_eq!(sequence((1..4).map(Some)), Some(vec![1, 2, 3]));
+    assert_eq!(
+        sequence([Ok(1), Err(2), Err(3)]),
+        Err::<Vec<i32>, _>(2)
+    );
+    let products = sequence(VecDeque::from([vec![1, 2], vec![3, 4]]));
+    assert_eq!(products, [[1, 3], [1, 4], [2, 3], [2, 4]]);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let futures = (0..3).map(|i| {
+        let log = log.clone();
+        async move {
+            log.lock().unwrap().push(i);
+            i * 10
+        }
+        .boxed()
+    });
+    let fut: BoxFuture<'_, Vec<i32>> = sequence(futures);
+    assert!(log.lock().unwrap().is_empty());
+    assert_eq!(block_on(fut), vec![0, 10, 20]);
+    assert_eq!(*log.lock().unwrap(), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_traverse() {
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    let checked = traverse(1..=3, |x| (x > 0).then_some(x * 2));
+    assert_eq!(checked, Some(vec![2, 4, 6]));
+    let mut visited = Vec::new();
+    let failed = traverse(vec![1, -1, 2], |x| {
+        visited.push(x);
+        (x > 0).then_some(x)
+    });
+    assert_eq!(failed, None);
+    assert_eq!(visited, vec![1, -1, 2]);
+    let pairs =
+        traverse(["ab", "c"], |s| s.chars().collect::<Vec<_>>());
+    assert_eq!(pairs, [['a', 'c'], ['b', 'c']]);
+    let fut: BoxFuture<'_, Vec<usize>> =
+        traverse(["x", "yy"], |s| async move { s.len() }.boxed());
+    assert_eq!(block_on(fut), vec![1, 2]);
+}
+
+#[test]
+fn test_replicate_m() {
+    assert_eq!(replicate_m(0, None::<i32>), Some(vec![]));
+    assert_eq!(replicate_m(2, None::<i32>), None);
+    assert_eq!(replicate_m(2, Ok::<_, ()>('x')), Ok(vec!['x', 'x']));
+    let all = replicate_m(3, vec![false, true]);
+    assert_eq!(all.len(), 8);
+    assert_eq!(all[1], [false, false, true]);
+    let lazy: Vec<Vec<bool>> =
+        replicate_m_iter(3, vec![false, true]).collect();
+    assert_eq!(lazy, all);
+    assert_eq!(
+        replicate_m_iter(0, 0..0).collect::<Vec<_>>(),
+        [Vec::<i32>::new()]
+    );
+    assert_eq!(replicate_m_iter(2, 0..0).count(), 0);
+    let mut infinite = replicate_m_iter(2, 0..);
+    assert_eq!(infinite.nth(3), Some(vec![0, 3]));
+}
+
+#[test]
+fn test_fold_m() {
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    use std::sync::{Arc, Mutex};
+    let mut calls = 0;
+    let failed = fold_m([1, 0, 2], 10, |acc, x| {
+        calls += 1;
+        if x == 0 {
+            Err(acc)
+        } else {
+            Ok(acc / x)
+        }
+    });
+    assert_eq!(failed, Err(10));
+    assert_eq!(calls, 2);
+    assert_eq!(fold_m(Vec::<i32>::new(), 7, |_, _| None), Some(7));
+    let paths = fold_m(["a", "b"], String::new(), |acc, s| {
+        vec![acc.clone() + s, acc + &s.to_uppercase()]
+    });
+    assert_eq!(paths, ["ab", "aB", "Ab", "AB"]);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let log2 = log.clone();
+    let fut: BoxFuture<'_, i32> = fold_m(1..=3, 0, move |acc, x| {
+        let log = log2.clone();
+        async move {
+            log.lock().unwrap().push(x);
+            acc * 10 + x
+        }
+        .boxed()
+    });
+    assert!(log.lock().unwrap().is_empty());
+    assert_eq!(block_on(fut), 123);
+    assert_eq!(*log.lock().unwrap(), vec![1, 2, 3]);
+}
+
 #[test]
 fn test_monad_apply() {
     let f: Vec<Box<dyn Send + FnMut(i32) -> i32>> =
@@ -299,3 +998,1853 @@ fn test_apply_result() {
         Ok::<i32, i32>(12)
     );
 }
+
+#[test]
+fn test_cont() {
+    use cont::{call_cc, Cont};
+    fn safe_div(x: i32, y: i32) -> Cont<'static, String, i32> {
+        call_cc(move |exit| {
+            if y == 0 {
+                exit.escape(0)
+            } else {
+                Cont::new(move |k| k(x / y))
+            }
+            .fmap(|z| z + 1)
+        })
+    }
+    assert_eq!(safe_div(12, 4).run(|x| x.to_string()), "4");
+    assert_eq!(safe_div(12, 0).run(|x| x.to_string()), "0");
+    let c: Cont<'_, i32, i32> = Cont::new(|k| k(3));
+    let mapper: Box<dyn Send + FnMut(i32) -> i32> = Box::new(|x| x * 5);
+    let f = Cont::new(|k| k(mapper));
+    let c = c.apply(f);
+    assert_eq!(c.eval(), 15);
+}
+
+#[test]
+fn test_free() {
+    use free::{fold_free, Free};
+    use universal::UniversalFunctor;
+    type VecTyCon =
+        <Vec<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    let free: Free<'static, VecTyCon, i32> = Free::lift_f(vec![1, 2])
+        .bind(|x| Free::lift_f(vec![x, 10 * x]))
+        .fmap(|x| x + 1);
+    let result: Vec<i32> = fold_free(free, |x| x);
+    assert_eq!(result, vec![2, 11, 3, 21]);
+    let free: Free<'static, VecTyCon, i32> = Free::lift_f(vec![1, 2])
+        .bind(|x| Free::lift_f(vec![x, 10 * x]));
+    assert_eq!(free.run(|mut layer| layer.pop().unwrap()), 20);
+}
+
+#[test]
+fn test_free_stack_safety() {
+    use free::{fold_free, Free};
+    use trampoline::Trampoline;
+    use universal::UniversalFunctor;
+    type OptionTyCon =
+        <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    type FreeOption<A> = Free<'static, OptionTyCon, A>;
+    fn program(n: i32) -> FreeOption<i32> {
+        let mut free: FreeOption<i32> = Free::lift_f(Some(0));
+        for _ in 0..n {
+            free = free.bind(|x| Free::lift_f(Some(x + 1)));
+        }
+        free
+    }
+    assert_eq!(program(100_000).run(|layer| layer.unwrap()), 100_000);
+    let result: Trampoline<i32> =
+        fold_free(program(100_000), |layer| {
+            Trampoline::done(layer.unwrap())
+        });
+    assert_eq!(result.run(), 100_000);
+    let mut free = program(50_000);
+    for _ in 0..50_000 {
+        free = free.fmap(|x| x + 1);
+    }
+    assert_eq!(free.run(|layer| layer.unwrap()), 100_000);
+    fn count_down(n: i32) -> FreeOption<i32> {
+        Free::lift_f(Some(n)).bind(|n| {
+            if n == 0 {
+                Free::Pure(0)
+            } else {
+                count_down(n - 1).fmap(|x| x + 1)
+            }
+        })
+    }
+    assert_eq!(
+        count_down(100_000).run(|layer| layer.unwrap()),
+        100_000
+    );
+    drop(program(100_000));
+    let mut free = program(100_000).resume().unwrap_err().unwrap();
+    for _ in 0..50_000 {
+        free = free.resume().unwrap_err().unwrap();
+    }
+    drop(free);
+    let stopped: Option<i32> =
+        fold_free(program(100_000), |layer| layer.filter(|_| false));
+    assert_eq!(stopped, None);
+}
+
+#[test]
+fn test_free_ap() {
+    use free_ap::{fold_ap, FreeAp};
+    use universal::UniversalFunctor;
+    type OptionTyCon =
+        <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    let a: FreeAp<'static, OptionTyCon, i32> = FreeAp::lift(Some(3));
+    let b: FreeAp<'static, OptionTyCon, i32> = FreeAp::lift(None);
+    let mapper: Box<dyn Send + FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    let c = a.apply(FreeAp::<OptionTyCon, ()>::pure(mapper));
+    assert_eq!(c.analyze(|effect| effect.is_some()), vec![true]);
+    let result: Option<i32> = fold_ap(c, |x| x);
+    assert_eq!(result, Some(4));
+    let d = b.apply(FreeAp::lift(Some(
+        Box::new(|x| x * 2) as Box<dyn Send + FnMut(i32) -> i32>
+    )));
+    assert_eq!(d.analyze(|effect| effect.is_some()), vec![true, false]);
+    let result: Result<i32, ()> = fold_ap(d, |x| x.ok_or(()));
+    assert_eq!(result, Err(()));
+}
+
+#[test]
+fn test_coyoneda() {
+    use coyoneda::Coyoneda;
+    let set: HashSet<i32> = HashSet::from_iter([1, 2, 3]);
+    let lowered: HashSet<i32> = Coyoneda::lift(set)
+        .fmap(|x| x % 2)
+        .fmap_fn_mutref(|x| *x += 10)
+        .lower();
+    assert_eq!(lowered, HashSet::from_iter([10, 11]));
+    let lowered =
+        Coyoneda::lift(None::<i32>).fmap(|_| -> u8 { panic!() });
+    assert_eq!(lowered.lower(), None);
+}
+
+#[test]
+fn test_static_coyoneda() {
+    use static_coyoneda::StaticCoyoneda;
+    let lowered = StaticCoyoneda::lift(Some(4)).map(|x| x * 2).lower();
+    assert_eq!(lowered, Some(8));
+    let lowered: Vec<String> = StaticCoyoneda::lift(vec![1, 2])
+        .map(|x| x + 1)
+        .run(|x| x.to_string());
+    assert_eq!(lowered, vec!["2", "3"]);
+    let boxed = StaticCoyoneda::lift(vec![1, 2])
+        .map(|x| x * 3)
+        .into_coyoneda();
+    let lowered = boxed.fmap(|x| x + 1).fmap(|x| x.to_string()).lower();
+    assert_eq!(lowered, vec!["4", "7"]);
+}
+
+#[test]
+fn test_codensity() {
+    use codensity::Codensity;
+    let c: Codensity<'_, Vec<i32>, i32> = Codensity::lift(vec![1, 2]);
+    let c = c
+        .bind(|x| Codensity::lift(vec![x, x * 10]))
+        .fmap(|x| x + 1)
+        .bind(|x| Codensity::<_, ()>::pure(x * 2));
+    assert_eq!(c.lower(), vec![4, 22, 6, 42]);
+}
+
+#[test]
+fn test_store() {
+    use store::Store;
+    let store = Store::new(|pos: i32| pos * pos, 3);
+    assert_eq!(store.peeks(|pos| pos + 1), 16);
+    let store = store.seeks(|pos| pos - 1);
+    assert_eq!(*store.pos(), 2);
+    assert_eq!(store.clone().extract(), 4);
+    let diffs = store.extend(|s| s.peeks(|pos| pos + 1) - s.extract());
+    assert_eq!(diffs.clone().extract(), 5);
+    assert_eq!(diffs.seek(10).fmap(|x| x * 2).extract(), 42);
+}
+
+#[test]
+fn test_env() {
+    use env::Env;
+    let mut x = Env::new(7u8, vec![1, 2]);
+    x.fmap_mut(|v| v.push(3));
+    let y = x.local_env(|e| e as i32 * 2).fmap(|v| v.len());
+    assert_eq!(y, Env::new(14, 3));
+    let z = y.extend(|e| *e.ask_env() as usize + e.extract());
+    assert_eq!(z.into_parts(), (14, 17));
+}
+
+#[test]
+fn test_traced() {
+    use traced::Traced;
+    let t = Traced::new(|s: String| s.len());
+    assert_eq!(t.clone().extract(), 0);
+    let u = t.fmap(|n| n * 10).extend(|t| t.trace("ab".to_string()));
+    assert_eq!(u.trace("xyz".to_string()), 50);
+    assert_eq!(u.extract(), 20);
+}
+
+#[test]
+fn test_vec_zipper() {
+    use zipper::VecZipper;
+    assert!(VecZipper::from_vec(Vec::<i32>::new(), 0).is_none());
+    let mut z = VecZipper::from_vec(vec![10, 20, 30], 1).unwrap();
+    assert_eq!(z.get(-1), Some(&10));
+    assert_eq!(z.get(2), None);
+    assert!(z.move_right());
+    assert!(!z.move_right());
+    assert_eq!((z.pos(), *z.focus()), (2, 30));
+    z.fmap_mut(|x| *x += 1);
+    let z = z.extend(|z| z.pos() as i32 * 100 + z.extract());
+    assert_eq!(z.pos(), 2);
+    assert_eq!(z.fmap(|x| x * 2).into_vec(), vec![22, 242, 462]);
+}
+
+#[test]
+fn test_cofree() {
+    use cofree::Cofree;
+    use universal::UniversalFunctor;
+    type VecTyCon =
+        <Vec<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    // binary tree of depth 2 labeled with depths
+    let tree: Cofree<'static, VecTyCon, u8> = Cofree::unfold(0, |d| {
+        (d, if d < 2 { vec![d + 1; 2] } else { vec![] })
+    });
+    fn size(tree: Cofree<'static, VecTyCon, u8>) -> u8 {
+        1 + tree.tail().into_iter().map(size).sum::<u8>()
+    }
+    let sizes = tree.fmap(|d| d * 10).extend(|t| (*t.head(), size(t)));
+    assert_eq!(sizes.clone().extract(), (0, 7));
+    let children: Vec<_> =
+        sizes.tail().into_iter().map(|t| t.extract()).collect();
+    assert_eq!(children, vec![(10, 3), (10, 3)]);
+}
+
+#[test]
+fn test_tree() {
+    use tree::Tree;
+    let tree = Tree {
+        value: 1,
+        children: vec![Tree::leaf(2), Tree::leaf(3)],
+    };
+    let bound = tree.clone().bind(|x| Tree {
+        value: x,
+        children: vec![Tree::leaf(-x)],
+    });
+    assert_eq!(
+        bound.clone().fmap(|x| x * 2).children[0],
+        Tree::leaf(-2)
+    );
+    assert_eq!(bound.children.len(), 3);
+    let mappers: Tree<Box<dyn Send + FnMut(i32) -> i32>> =
+        Tree::leaf(Box::new(|x| x + 1));
+    assert_eq!(
+        tree.clone().apply(mappers),
+        tree.clone().fmap(|x| x + 1)
+    );
+    let depths = tree.clone().extend(|t| t.children.len());
+    assert_eq!(depths.extract(), 2);
+    let traversed = tree.traverse(|x| vec![x, x * 10]);
+    assert_eq!(traversed.len(), 8);
+    assert_eq!(
+        traversed[7].clone().fmap(|x| x.to_string()).children[1].value,
+        "30"
+    );
+}
+
+#[test]
+fn test_fix() {
+    use fix::{ana, cata, hylo, Fix};
+    use universal::UniversalFunctor;
+    type VecTyCon =
+        <Vec<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    let tree: Fix<'static, VecTyCon> =
+        ana(3u32, |n| (0..n).collect::<Vec<_>>());
+    assert_eq!(tree.unfix().len(), 3);
+    let tree: Fix<'static, VecTyCon> = ana(3u32, |n| (0..n).collect());
+    let size =
+        cata(tree, |sizes: Vec<u32>| 1 + sizes.iter().sum::<u32>());
+    assert_eq!(size, 8);
+    let depth = hylo::<VecTyCon, _, _, _, _>(
+        3u32,
+        |n| (0..n).collect(),
+        |depths: Vec<u32>| 1 + depths.into_iter().max().unwrap_or(0),
+    );
+    assert_eq!(depth, 4);
+}
+
+#[test]
+fn test_lazy() {
+    use lazy::Lazy;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    let count = Arc::new(AtomicUsize::new(0));
+    let count2 = count.clone();
+    let a = Lazy::new(move || {
+        count2.fetch_add(1, Ordering::SeqCst);
+        3
+    })
+    .fmap(|x| x * 2);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    assert_eq!(*a.force(), 6);
+    assert_eq!(*a.force(), 6);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(format!("{a:?}"), "Lazy(6)");
+    let mapper: Box<dyn Send + FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    let b = a.apply(Lazy::<()>::pure(mapper));
+    assert_eq!(format!("{b:?}"), "Lazy(<unevaluated>)");
+    assert_eq!(b.into_inner(), 7);
+}
+
+#[test]
+fn test_monad_error() {
+    assert_eq!(Some(1).catch_error(|()| Some(2)), Some(1));
+    assert_eq!(
+        Option::<i32>::throw_error(()).catch_error(|()| Some(2)),
+        Some(2)
+    );
+    let e: Result<i32, &str> = MonadError::throw_error("e");
+    assert_eq!(e.catch_error(|_| Err("f")), Err("f"));
+}
+
+#[test]
+fn test_io() {
+    use io::{Io, Panic};
+    use std::sync::{Arc, Mutex};
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let log2 = log.clone();
+    let io =
+        Io::new(move || log2.lock().unwrap().push("run")).fmap(|()| 5);
+    assert!(log.lock().unwrap().is_empty());
+    let mapper: Box<dyn Send + FnMut(i32) -> i32> = Box::new(|x| x * 2);
+    let io = io
+        .apply(Io::<()>::pure(mapper))
+        .bind(|x| Io::<()>::pure(x + 1));
+    assert_eq!(io.run(), 11);
+    assert_eq!(*log.lock().unwrap(), vec!["run"]);
+    let payload: Panic = Box::new("boom");
+    let failed = Io::<i32>::throw_error(payload);
+    let message = failed.try_run().unwrap_err();
+    assert_eq!(message.downcast_ref::<&str>(), Some(&"boom"));
+}
+
+#[test]
+fn test_kleisli() {
+    use kleisli::Kleisli;
+    let split = Kleisli::new(|x: i32| vec![x, -x]);
+    let mut k = split.and_then(Kleisli::arr(|x: i32| x * 10)).first();
+    assert_eq!(k.run((1, 'a')), vec![(10, 'a'), (-10, 'a')]);
+    let mut k = Kleisli::new(|s: &str| s.parse::<u8>().ok())
+        .fmap(|x| x + 1)
+        .second();
+    assert_eq!(k.run(("n", "41")), Some(("n", 42)));
+    assert_eq!(k.run(("n", "?")), None);
+}
+
+#[test]
+fn test_compose_m() {
+    use kleisli::compose_m;
+    let split = |x: i32| vec![x, x + 1];
+    let mut k = compose_m(split, |x: i32| vec![x * 10; x as usize]);
+    assert_eq!(k(1), vec![10, 20, 20]);
+    let mut k = compose_m(compose_m(split, split), |x| vec![-x]);
+    assert_eq!(k(0), vec![0, -1, -1, -2]);
+}
+
+#[test]
+fn test_day() {
+    use day::Day;
+    let day = Day::new(Some(2), Some("ab"), |n, s: &str| s.repeat(n));
+    assert_eq!(day.swap().fmap(|s| s.len()).lower(), Some(4));
+    let day = Day::new(None, Some(1), |a: i32, b| a + b);
+    assert_eq!(day.lower(), None);
+}
+
+#[test]
+fn test_backwards() {
+    use backwards::Backwards;
+    let a: Result<i32, &str> = Err("argument");
+    let f: Result<Box<dyn Send + FnMut(i32) -> i32>, &str> =
+        Err("mapper");
+    assert_eq!(a.apply(f), Err("mapper"));
+    let f: Result<Box<dyn Send + FnMut(i32) -> i32>, &str> =
+        Err("mapper");
+    assert_eq!(Backwards(a).apply(Backwards(f)).0, Err("argument"));
+    let b = Backwards(Some(4)).fmap(|x| x * 2);
+    let f: Box<dyn Send + FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    assert_eq!(b.apply(Backwards(Some(f))), Backwards(Some(9)));
+}
+
+#[test]
+fn test_monoid_newtypes() {
+    use monoid::*;
+    assert_eq!(mconcat(vec![Product(2), Product(5)]), Product(10));
+    assert_eq!(mconcat(Vec::<Sum<f64>>::new()), Sum(0.0));
+    assert_eq!(fold_map(vec![3u8, 1, 2], Min), Min(1));
+    assert_eq!(mconcat(Vec::<Max<i64>>::new()), Max(i64::MIN));
+    assert_eq!(fold_map([false, true], Any), Any(true));
+    assert_eq!(fold_map([false, true], All), All(false));
+    assert_eq!(mconcat(Vec::<All>::new()), All(true));
+    let opts = vec![None, Some(1), Some(2), None];
+    assert_eq!(fold_map(opts.clone(), First), First(Some(1)));
+    assert_eq!(fold_map(opts, Last), Last(Some(2)));
+    assert_eq!(
+        fold_map(vec![vec![1], vec![2]], Dual),
+        Dual(vec![2, 1])
+    );
+}
+
+#[test]
+fn test_ap_monoid() {
+    use monoid::{mconcat, Ap, Monoid, Semigroup};
+    let a = Ap::new(vec!["a".to_string(), "b".to_string()]);
+    let b = Ap::new(vec!["x".to_string(), "y".to_string()]);
+    assert_eq!(a.combine(b).into_inner(), vec!["ax", "ay", "bx", "by"]);
+    let empty: Ap<Result<String, ()>, String> = Monoid::empty();
+    assert_eq!(empty.into_inner(), Ok(String::new()));
+    let results = vec![Ok(vec![1]), Err("e"), Ok(vec![2])];
+    let combined = mconcat(results.into_iter().map(Ap::new));
+    assert_eq!(combined.into_inner(), Err("e"));
+}
+
+#[test]
+fn test_trampoline() {
+    use free::{fold_free, Free};
+    use trampoline::Trampoline;
+    use universal::UniversalFunctor;
+    type OptionTyCon =
+        <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    let mut t = Trampoline::done(0);
+    for _ in 0..100_000 {
+        t = t.fmap(|x| x + 1);
+    }
+    let mapper: Box<dyn Send + FnMut(i32) -> i32> = Box::new(|x| x * 2);
+    let t = t.apply(Trampoline::done(mapper));
+    assert_eq!(
+        t.bind(|x| Trampoline::<i32>::pure(x + 1)).run(),
+        200_001
+    );
+    let mut free: Free<'static, OptionTyCon, i32> = Free::Pure(0);
+    for _ in 0..10_000 {
+        free = Free::wrap(Some(free));
+    }
+    let result: Trampoline<i32> =
+        fold_free(free, |layer| Trampoline::done(layer.unwrap()));
+    assert_eq!(result.run(), 0);
+}
+
+#[test]
+fn test_freer() {
+    use freer::{interpret, Freer};
+    struct Choose(i32);
+    fn choose(n: i32) -> Freer<'static, Choose, i32, i32> {
+        Freer::lift(Choose(n))
+    }
+    let program = || {
+        choose(2)
+            .bind(|x| choose(3).fmap(move |y| (x, y)))
+            .fmap(|(x, y)| x * 10 + y)
+    };
+    let result: Vec<i32> =
+        interpret(program(), |Choose(n)| (0..n).collect::<Vec<_>>());
+    assert_eq!(result, vec![0, 1, 2, 10, 11, 12]);
+    assert_eq!(program().run(|Choose(n)| n - 1), 12);
+    let mut counter = Freer::Pure(0);
+    for _ in 0..1000 {
+        counter = counter.bind(|x| choose(1).fmap(move |y| x + y + 1));
+    }
+    assert_eq!(counter.run(|Choose(_)| 0), 1000);
+}
+
+#[test]
+fn test_logic() {
+    use logic::Logic;
+    let pairs = Logic::from_iterator(1..=3)
+        .bind(|x| Logic::from_iterator(x..=3).fmap(move |y| (x, y)));
+    assert_eq!(
+        pairs.into_iter().collect::<Vec<_>>(),
+        vec![(1, 1), (1, 2), (1, 3), (2, 2), (2, 3), (3, 3)]
+    );
+    let triples = Logic::from_iterator(1..).fair_bind(|c: u32| {
+        Logic::from_iterator(1..c).bind(move |b| {
+            Logic::from_iterator(1..b).bind(move |a| {
+                if a * a + b * b == c * c {
+                    Logic::<()>::pure((a, b, c))
+                } else {
+                    Logic::empty()
+                }
+            })
+        })
+    });
+    assert_eq!(
+        triples.once().into_iter().collect::<Vec<_>>(),
+        [(3, 4, 5)]
+    );
+    let filtered = Logic::from_iterator(0..100_000).bind(|x| {
+        if x == 99_999 {
+            Logic::<()>::pure(x)
+        } else {
+            Logic::empty()
+        }
+    });
+    assert_eq!(filtered.split().map(|(x, _)| x), Some(99_999));
+    assert!(Logic::<()>::empty().split().is_none());
+}
+
+#[test]
+fn test_dist() {
+    use dist::Dist;
+    let coin = Dist::new([(true, 1.0), (false, 1.0)]);
+    let two = coin
+        .clone()
+        .bind(move |a| coin.clone().fmap(move |b| a && b));
+    assert_eq!(two.probability(|&x| x), 0.25);
+    type Mapper = Box<dyn Send + FnMut(i32) -> i32>;
+    let id: Mapper = Box::new(|x| x);
+    let neg: Mapper = Box::new(|x| -x);
+    let mappers = Dist::new([(id, 3.0), (neg, 1.0)]);
+    let applied = Dist::uniform([1, 2]).apply(mappers).collapse();
+    assert_eq!(
+        applied.into_outcomes(),
+        vec![(1, 0.375), (2, 0.375), (-1, 0.125), (-2, 0.125)]
+    );
+    let dist = Dist::uniform(["a", "b"]);
+    let samples: Vec<_> = dist.samples([0.1, 0.6, 0.49, 1.0]).collect();
+    assert_eq!(samples, vec![&"a", &"b", &"a", &"b"]);
+    let mut dist = Dist::<i32>::pure(5);
+    dist.fmap_mut(|x| *x += 1);
+    assert_eq!(dist.expected_value(|&x| x as f64), 6.0);
+}
+
+#[test]
+#[should_panic]
+fn test_dist_negative_weight() {
+    use dist::Dist;
+    let _ = Dist::new([("a", 2.0), ("b", -1.0)]);
+}
+
+#[cfg(feature = "crossbeam")]
+#[test]
+fn test_crossbeam_receiver() {
+    use receiver::MappedReceiver;
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let mapped = MappedReceiver::from(rx)
+        .fmap(|x: i32| x + 1)
+        .bind(|x| MappedReceiver::<()>::pure(x * 10));
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    drop(tx);
+    assert_eq!(mapped.collect::<Vec<_>>(), vec![20, 30]);
+}
+
+#[cfg(feature = "dashmap")]
+#[test]
+fn test_dashmap() {
+    use ::dashmap::DashMap;
+    let mut map: DashMap<i32, String> = DashMap::new();
+    for i in 0..100 {
+        map.insert(i, format!(" {i} "));
+    }
+    map.fmap_mut(|s| *s = s.trim().to_string());
+    assert_eq!(*map.get(&42).unwrap(), "42");
+    let lengths = map.fmap(|s| s.len());
+    assert_eq!(*lengths.get(&7).unwrap(), 1);
+    let sums = lengths.fmap_with_key(|k, v| k + v as i32);
+    assert_eq!(*sums.get(&10).unwrap(), 12);
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn test_either() {
+    use ::either::Either::{self, Left, Right};
+    let right: Either<&str, i32> = Right(4);
+    let mut mapped = right.fmap(|x| x * 2);
+    mapped.fmap_mut(|x| *x += 1);
+    assert_eq!(mapped, Right(9));
+    let bound =
+        mapped.bind(|x| if x > 5 { Left("big") } else { Right(x) });
+    assert_eq!(bound, Left("big"));
+    assert_eq!(bound.catch_error(|e| Right(e.len() as i32)), Right(3));
+    assert_eq!(
+        Either::<(), ()>::pure(1).bind(|x| Right::<(), _>(x + 1)),
+        Right(2)
+    );
+    let left: Either<&str, i32> = Left("x");
+    assert_eq!(left.bimap(str::len, |x| x + 1), Left(1));
+    let right: Either<&str, i32> = Right(2);
+    assert_eq!(right.bimap(|l| l, |x| x + 1), right.fmap(|x| x + 1));
+}
+
+#[cfg(feature = "higher")]
+#[test]
+fn test_higher() {
+    use higher_interop::Higher;
+    type HigherOption<A> = Higher<'static, Option<()>, A>;
+    let wrapped: HigherOption<i32> = Higher(Some(3));
+    let mut calls = 0;
+    let mapped = wrapped.fmap(move |x| {
+        calls += 1;
+        x + calls
+    });
+    assert_eq!(mapped.0, Some(4));
+    let bound = mapped.bind(|x| Higher(Some(x.to_string())));
+    assert_eq!(bound.0, Some("4".to_string()));
+    let failed = bound.bind(|_| -> HigherOption<u8> { Higher(None) });
+    assert_eq!(failed.0, None);
+    let pure: HigherOption<u8> = HigherOption::<()>::pure(7);
+    assert_eq!(pure.0, Some(7));
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec() {
+    use ::arrayvec::ArrayVec;
+    let a: ArrayVec<i32, 4> = [1, 2].into_iter().collect();
+    let mut b: ArrayVec<String, 4> = a.fmap(|x| x.to_string());
+    b.fmap_mut(|s| s.push('!'));
+    let c = b.bind(|s| [s.clone(), s].into_iter().collect());
+    assert_eq!(c.as_slice(), ["1!", "1!", "2!", "2!"]);
+    assert_eq!(ArrayVec::<(), 1>::pure(3).as_slice(), [3]);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+#[should_panic]
+fn test_arrayvec_bind_overflow() {
+    use ::arrayvec::ArrayVec;
+    let a: ArrayVec<i32, 2> = [1, 2].into_iter().collect();
+    let _ = a.bind(|x| [x, x].into_iter().collect());
+}
+
+#[test]
+fn test_fmap_with_key() {
+    let map = HashMap::from([(1, 10), (2, 20)]);
+    let sums = map.fmap_with_key(|k, v| k + v);
+    assert_eq!(sums, HashMap::from([(1, 11), (2, 22)]));
+    let map = BTreeMap::from([("x", 1)]);
+    let pairs = map.fmap_with_key(|k, v| (k.to_string(), v));
+    assert_eq!(pairs["x"], ("x".to_string(), 1));
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn test_stream() {
+    use futures::executor::block_on;
+    use futures::stream::{self, Stream, StreamExt};
+    use std::pin::Pin;
+    type BoxStream<'a, A> = Pin<Box<dyn 'a + Send + Stream<Item = A>>>;
+    let s: BoxStream<i32> = Box::pin(stream::iter(vec![1, 2, 3]));
+    let mut s = s.fmap(|x| x * 10);
+    s.fmap_mut(|x| *x += 1);
+    let s = s.bind(|x| -> BoxStream<i32> {
+        Box::pin(stream::iter([x, -x]))
+    });
+    let collected: Vec<i32> = block_on(s.collect());
+    assert_eq!(collected, vec![11, -11, 21, -21, 31, -31]);
+    type LocalStream<'a, A> = Pin<Box<dyn 'a + Stream<Item = A>>>;
+    let s = LocalStream::<()>::pure(4);
+    let s = s.bind(|x| LocalStream::<()>::pure(x + 1));
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![5]);
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn test_hashbrown() {
+    use ::hashbrown::{HashMap, HashSet};
+    let map: HashMap<&str, i32> =
+        [("a", 1), ("b", 2)].into_iter().collect();
+    let mut mapped = map.fmap(|x| x * 10);
+    mapped.fmap_mut(|x| *x += 1);
+    let keyed = mapped.fmap_with_key(|k, v| format!("{k}{v}"));
+    assert_eq!(keyed["b"], "b21");
+    let set: HashSet<i32> = [1, 2, 3].into_iter().collect();
+    let parity = set.clone().fmap(|x| x & 1);
+    assert_eq!(parity.len(), 2);
+    let bound = set.bind(|x| [x, x * 10].into_iter().collect());
+    assert_eq!(bound.len(), 6);
+    assert!(HashSet::<()>::pure(4).contains(&4));
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn test_im() {
+    use ::im::{hashmap, ordmap, vector, Vector};
+    let vec = vector![1, 2, 3];
+    let mut strings = vec.clone().fmap(|x| x.to_string());
+    strings.fmap_mut(|s| s.push('!'));
+    assert_eq!(
+        strings,
+        vector!["1!".to_string(), "2!".into(), "3!".into()]
+    );
+    let bound = vec.clone().bind(|x| (0..x).map(|_| x).collect());
+    assert_eq!(bound, vector![1, 2, 2, 3, 3, 3]);
+    assert_eq!(Vector::<()>::pure(5), vector![5]);
+    assert_eq!(vec.or(vector![4]), vector![1, 2, 3, 4]);
+    let map = hashmap! {1 => 10, 2 => 20};
+    assert_eq!(map.fmap(|x| x + 1), hashmap! {1 => 11, 2 => 21});
+    let mut map = ordmap! {"a" => 1, "b" => 2};
+    map.fmap_mut(|x| *x *= 3);
+    let keyed = map.fmap_with_key(|k, v| format!("{k}{v}"));
+    assert_eq!(keyed, ordmap! {"a" => "a3".into(), "b" => "b6".into()});
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn test_indexmap() {
+    use ::indexmap::{IndexMap, IndexSet};
+    let map: IndexMap<&str, i32> =
+        [("z", 1), ("a", 2)].into_iter().collect();
+    let mut mapped = map.fmap(|x| x * 10);
+    mapped.fmap_mut(|x| *x += 1);
+    let keyed = mapped.fmap_with_key(|k, v| format!("{k}{v}"));
+    assert_eq!(keyed.values().collect::<Vec<_>>(), ["z11", "a21"]);
+    let set: IndexSet<i32> = [3, 1, 4].into_iter().collect();
+    let parity = set.clone().fmap(|x| x & 1);
+    assert_eq!(parity.into_iter().collect::<Vec<_>>(), [1, 0]);
+    let bound = set.bind(|x| [x, x + 1].into_iter().collect());
+    assert_eq!(bound.into_iter().collect::<Vec<_>>(), [3, 4, 1, 2, 5]);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray() {
+    use ::ndarray::{array, Array1, ArrayD, IxDyn};
+    let a: Array1<i32> = array![1, 2, 3];
+    let mut b = a.clone().fmap(|x| x * 10);
+    b.fmap_mut(|x| *x += 1);
+    assert_eq!(b, array![11, 21, 31]);
+    let m = array![[1, 2], [3, 4]].fmap(|x| x.to_string());
+    assert_eq!(m[[1, 0]], "3");
+    let funcs: Array1<BoxMapper<Array1<i32>, i32>> = array![
+        Box::new(|x: i32| x + 1) as _,
+        Box::new(|x: i32| x * 2) as _,
+        Box::new(|x: i32| -x) as _
+    ];
+    assert_eq!(a.clone().apply(funcs), array![2, 4, -3]);
+    let single = Array1::<()>::pure(
+        Box::new(|x: i32| x * x) as BoxMapper<Array1<i32>, i32>
+    );
+    assert_eq!(a.apply(single), array![1, 4, 9]);
+    let d: ArrayD<i32> =
+        ArrayD::from_shape_vec(IxDyn(&[2, 1]), vec![1, 2]).unwrap();
+    let row: ArrayD<BoxMapper<ArrayD<i32>, i32>> =
+        ArrayD::from_shape_vec(
+            IxDyn(&[3]),
+            vec![
+                Box::new(|x: i32| x) as _,
+                Box::new(|x: i32| x * 10) as _,
+                Box::new(|x: i32| x * 100) as _,
+            ],
+        )
+        .unwrap();
+    let table = d.apply(row);
+    assert_eq!(table.shape(), [2, 3]);
+    assert_eq!(
+        table.iter().copied().collect::<Vec<_>>(),
+        [1, 10, 100, 2, 20, 200]
+    );
+    assert_eq!(ArrayD::<()>::pure(7).ndim(), 0);
+}
+
+#[cfg(feature = "parking_lot")]
+#[test]
+fn test_parking_lot() {
+    use ::parking_lot::{Mutex, RwLock};
+    let mut mutex = Mutex::new(5).fmap(|x| x * 2);
+    mutex.fmap_mut(|x| *x += 1);
+    assert_eq!(*mutex.lock(), 11);
+    let lock = RwLock::new("abc").fmap(str::len);
+    assert_eq!(*lock.read(), 3);
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+fn test_petgraph() {
+    use ::petgraph::graph::{DiGraph, NodeIndex};
+    let mut graph = DiGraph::<&str, u32>::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("bb");
+    let e = graph.add_edge(a, b, 7);
+    let mut lengths = graph.fmap(str::len);
+    lengths.fmap_mut(|n| *n *= 10);
+    assert_eq!(lengths[b], 20);
+    assert_eq!(lengths[e], 7);
+    let mapped = lengths.bimap(|w| w.to_string(), |n| n + 1);
+    assert_eq!(mapped[a], 11);
+    assert_eq!(mapped[e], "7");
+    assert_eq!(mapped.edge_endpoints(e), Some((a, NodeIndex::new(1))));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_fmap() {
+    use crate::par_functor::ParFunctor;
+    let vec: Vec<u32> = (0..100).collect();
+    assert_eq!(
+        vec.clone().par_fmap(|x| x + 1),
+        vec.clone().fmap(|x| x + 1)
+    );
+    let slice: Box<[u32]> = vec.clone().into_boxed_slice();
+    assert_eq!(slice.par_fmap(|x| x * 2)[50], 100);
+    let deque: VecDeque<u32> = vec.into_iter().collect();
+    assert_eq!(deque.par_fmap(|x| x & 1).iter().sum::<u32>(), 50);
+    let map = HashMap::from([("a", 1), ("b", 2)]);
+    assert_eq!(
+        map.par_fmap(|x| x * 10),
+        HashMap::from([("a", 10), ("b", 20)])
+    );
+    let map = BTreeMap::from([(1, "x"), (2, "yy")]);
+    assert_eq!(
+        map.par_fmap(str::len),
+        BTreeMap::from([(1, 1), (2, 2)])
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_json() {
+    use crate::json::{JsonArray, JsonObject};
+    use serde_json::{json, Value};
+    assert_eq!(JsonArray::try_from(json!(1)), Err(json!(1)));
+    let array = JsonArray::try_from(json!([1, 2])).unwrap();
+    let mut doubled = array.try_fmap(|v| v.as_i64().ok_or(())).unwrap();
+    doubled.fmap_mut(|x| *x *= 2);
+    assert_eq!(Value::from(doubled), json!([2, 4]));
+    let object =
+        JsonObject::try_from(json!({"a": "x", "b": 2})).unwrap();
+    let lengths = object.clone().fmap(|v| v.to_string().len());
+    assert_eq!(Value::from(lengths), json!({"a": 3, "b": 1}));
+    let strings =
+        object.try_fmap(|v| v.as_str().map(str::to_owned).ok_or(v));
+    assert_eq!(strings, Err(json!(2)));
+}
+
+#[cfg(feature = "slotmap")]
+#[test]
+fn test_slotmap() {
+    use ::slotmap::{DenseSlotMap, SecondaryMap, SlotMap};
+    let mut map = SlotMap::new();
+    let a = map.insert(1);
+    let b = map.insert(2);
+    map.remove(a);
+    let c = map.insert(3);
+    let mut map = map.fmap(|x| x * 10);
+    map.fmap_mut(|x| *x += 1);
+    assert_eq!(map.get(a), None);
+    assert_eq!(map[b], 21);
+    assert_eq!(map[c], 31);
+    let mut dense = DenseSlotMap::new();
+    let d = dense.insert("x".to_string());
+    let dense = dense.fmap_with_key(|k, v| format!("{v}{}", *k == d));
+    assert_eq!(dense[d], "xtrue");
+    let mut secondary = SecondaryMap::new();
+    secondary.insert(b, "bb");
+    let lengths = secondary.fmap(str::len);
+    assert_eq!(lengths[b], 2);
+    assert!(!lengths.contains_key(c));
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec() {
+    use ::smallvec::{smallvec, SmallVec};
+    let a: SmallVec<[i32; 2]> = smallvec![1, 2];
+    let mut b: SmallVec<[String; 2]> = a.fmap(|x| x.to_string());
+    b.fmap_mut(|s| s.push('!'));
+    assert!(!b.spilled());
+    let c = b.bind(|s| smallvec![s.clone(), s]);
+    assert_eq!(c.as_slice(), ["1!", "1!", "2!", "2!"]);
+    assert!(c.spilled());
+    assert_eq!(SmallVec::<[(); 1]>::pure(3).as_slice(), [3]);
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn test_tinyvec() {
+    use ::tinyvec::{array_vec, tiny_vec, ArrayVec, TinyVec};
+    let a: TinyVec<[i32; 2]> = tiny_vec![1, 2];
+    let mut b: TinyVec<[String; 2]> = a.fmap(|x| x.to_string());
+    b.fmap_mut(|s| s.push('!'));
+    assert!(b.is_inline());
+    let c = b.bind(|s| tiny_vec![s.clone(), s]);
+    assert_eq!(c.as_slice(), ["1!", "1!", "2!", "2!"]);
+    assert!(c.is_heap());
+    assert_eq!(TinyVec::<[(); 1]>::pure(3).as_slice(), [3]);
+    let a: ArrayVec<[i32; 4]> = array_vec![1, 2];
+    let b = a.fmap(|x| x * 10).bind(|x| array_vec![x, x + 1]);
+    assert_eq!(b.as_slice(), [10, 11, 20, 21]);
+    assert_eq!(ArrayVec::<[(); 1]>::pure(3).as_slice(), [3]);
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+#[should_panic]
+fn test_tinyvec_array_vec_bind_overflow() {
+    use ::tinyvec::{array_vec, ArrayVec};
+    let a: ArrayVec<[i32; 2]> = array_vec![1, 2];
+    let _ = a.bind(|x| array_vec![x, x]);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio() {
+    use ::tokio::sync::{mpsc, oneshot};
+    use tokio_receiver::MappedReceiver;
+    let runtime = ::tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let (tx, rx) = oneshot::channel();
+        let mapped = rx.fmap(|x: i32| x * 2).fmap(|x| x.to_string());
+        tx.send(21).unwrap();
+        assert_eq!(mapped.await.unwrap(), "42");
+        let (tx, rx) = oneshot::channel::<i32>();
+        let mapped = rx.fmap(|x| x + 1);
+        drop(tx);
+        assert!(mapped.await.is_err());
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut mapped = MappedReceiver::from(rx).fmap(|x: i32| x * 10);
+        mapped.fmap_mut(|x| *x += 1);
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        drop(tx);
+        assert_eq!(mapped.recv().await, Some(11));
+        assert_eq!(mapped.recv().await, Some(21));
+        assert_eq!(mapped.recv().await, None);
+    });
+    let (tx, rx) = oneshot::channel();
+    let mapped = rx.fmap(|x: i32| x * 3);
+    tx.send(5).unwrap();
+    assert_eq!(mapped.blocking_recv(), Ok(15));
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mapped = rx.fmap(|x| x + 1);
+    drop(tx);
+    assert!(mapped.blocking_recv().is_err());
+}
+
+#[cfg(feature = "tower")]
+#[test]
+fn test_mapped_service() {
+    use futures::executor::block_on;
+    use service::MappedService;
+    use tower::{service_fn, Service, ServiceExt};
+    let checked = service_fn(|x: u8| async move {
+        x.checked_mul(2).ok_or("overflow")
+    });
+    let mut service = MappedService::new(checked)
+        .fmap(u16::from)
+        .fmap(|x| x + 1)
+        .contramap(|s: String| s.len() as u8);
+    let ready = block_on(service.ready()).unwrap();
+    assert_eq!(block_on(ready.call("abc".to_string())), Ok(7));
+    let long = "x".repeat(200);
+    assert_eq!(block_on(service.call(long)), Err("overflow"));
+}
+
+#[cfg(feature = "threads")]
+#[test]
+fn test_join_handle() {
+    let handle = std::thread::spawn(|| 6);
+    let mapped = handle.fmap(|x| x * 7).fmap(|x| x.to_string());
+    assert_eq!(mapped.join().unwrap(), "42");
+    let failing = std::thread::spawn(|| -> i32 { panic!("failed") });
+    let payload = failing.fmap(|x| x + 1).join().unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"failed"));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use random::Gen;
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut digits = Gen::choose(0..10).vec_of(3..4);
+    let digits = digits.generate(&mut rng);
+    assert_eq!(digits.len(), 3);
+    assert!(digits.iter().all(|d| (0..10).contains(d)));
+    let mut never = Gen::frequency(vec![
+        (0, Gen::<&str>::pure("never")),
+        (1, Gen::choose(["always"])),
+    ]);
+    for _ in 0..10 {
+        assert_eq!(never.generate(&mut rng), "always");
+    }
+    let mut heavy = Gen::frequency(vec![
+        (u32::MAX, Gen::<u8>::pure(1)),
+        (u32::MAX, Gen::choose([1])),
+    ]);
+    assert_eq!(heavy.generate(&mut rng), 1);
+    let mut constant =
+        Gen::<()>::pure(1).bind(|x| Gen::<()>::pure(x + 1));
+    assert_eq!(constant.generate(&mut rng), 2);
+}
+
+#[test]
+fn test_memo() {
+    use memo::Memo;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls2 = calls.clone();
+    let mut memo = Memo::new(move |x: i32| {
+        calls2.fetch_add(1, Ordering::Relaxed);
+        x + 1
+    });
+    assert_eq!(*memo.call(1), 2);
+    assert_eq!(*memo.call(1), 2);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    let mut mapped = memo.fmap(|x| x * 10);
+    assert_eq!(*mapped.call(1), 20);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(*mapped.call(2), 30);
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+    mapped.clear_cache();
+    assert!(!mapped.is_cached(&2));
+    assert_eq!(*mapped.call(2), 30);
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn test_supply() {
+    use supply::Supply;
+    let pair =
+        Supply::fresh().bind(|a| Supply::fresh().fmap(move |b| (a, b)));
+    assert_eq!(pair.run_from(10), ((10, 11), 12));
+    let mapper: Supply<Box<dyn Send + FnMut(u64) -> u64>> =
+        Supply::fresh()
+            .map(|a| Box::new(move |b| a * 100 + b) as Box<_>);
+    assert_eq!(Supply::fresh().apply(mapper).run(), 1);
+}
+
+#[test]
+fn test_alternative() {
+    fn choice<T: Alternative>(values: Vec<T>) -> T {
+        values.into_iter().fold(T::empty(), T::or)
+    }
+    assert_eq!(choice::<Option<i32>>(vec![]), None);
+    assert_eq!(choice(vec![None, Some(1), Some(2)]), Some(1));
+    assert_eq!(
+        choice(vec![VecDeque::from([1]), VecDeque::from([2, 3])]),
+        VecDeque::from([1, 2, 3])
+    );
+    assert_eq!(
+        choice(vec![LinkedList::from([1]), LinkedList::new()]),
+        LinkedList::from([1])
+    );
+    fn assert_monad_plus<'a, T: MonadPlus<'a, i32>>(_: T) {}
+    assert_monad_plus(vec![1]);
+    assert_monad_plus(Some(1));
+}
+
+#[test]
+fn test_parser() {
+    use parser::Parser;
+    let mut word = Parser::satisfy(char::is_alphabetic).some();
+    assert_eq!(word.parse("ab1"), Some((vec!['a', 'b'], "1")));
+    assert_eq!(word.parse("1"), None);
+    let mut greeting = Parser::tag("hello")
+        .or(Parser::tag("hi"))
+        .bind(|g| Parser::eof().fmap(move |()| g.clone()));
+    assert_eq!(greeting.parse_all("hi"), Some("hi".to_string()));
+    assert_eq!(greeting.parse_all("hello!"), None);
+    let mut nothing = Parser::<()>::empty().many();
+    assert_eq!(nothing.parse("x"), Some((vec![], "x")));
+    let mut endless = Parser::eof().many();
+    assert_eq!(endless.parse(""), Some((vec![], "")));
+}
+
+#[test]
+fn test_par() {
+    use futures::channel::oneshot;
+    use futures::executor::block_on;
+    use par::{BoxFuture, Par};
+    let (tx, rx) = oneshot::channel::<i32>();
+    let waiting: BoxFuture<i32> =
+        Box::pin(async move { rx.await.unwrap() });
+    let sending: BoxFuture<BoxMapper<Par<i32>, i32>> =
+        Box::pin(async move {
+            tx.send(20).unwrap();
+            Box::new(|x| x + 1) as BoxMapper<Par<i32>, i32>
+        });
+    let applied = waiting.parallel().apply(sending.parallel());
+    let result =
+        block_on(BoxFuture::sequential(applied.fmap(|x| x * 2)));
+    assert_eq!(result, 42);
+    assert_eq!(block_on(Par::<()>::pure(5).0), 5);
+    let (tx, rx) = oneshot::channel::<i32>();
+    let first = Par::new(async move { rx.await.unwrap() });
+    let second = Par::<()>::pure(2);
+    let third = Par::new(async move { tx.send(1).unwrap() });
+    let all = first.map3(second, third, |a, b, ()| a + b);
+    assert_eq!(block_on(all.0), 3);
+}
+
+#[test]
+fn test_ix_state() {
+    use ix_state::IxState;
+    let counter = IxState::get()
+        .bind(|n: i32| IxState::put(n + 1))
+        .bind(|()| IxState::get().fmap(|n| n * 10));
+    assert_eq!(counter.run(1), (20, 2));
+    let to_string = IxState::modify(|n: i32| n.to_string())
+        .and_then(|()| IxState::get())
+        .map(|s: String| s.len());
+    assert_eq!(to_string.eval(123), 3);
+    let mapper: IxState<
+        i32,
+        i32,
+        BoxMapper<IxState<i32, i32, i32>, i32>,
+    > = IxState::get().map(|n| Box::new(move |x| x + n) as Box<_>);
+    assert_eq!(IxState::get().apply(mapper).exec(5), 5);
+}
+
+#[test]
+fn test_star() {
+    use kleisli::Kleisli;
+    use profunctor::{Choice, Profunctor, Strong};
+    use star::Star;
+    let star: Star<i32, i32, Vec<i32>> =
+        Kleisli::new(|x: i32| vec![x, -x]).into();
+    let mut star = star.fmap(|x| x * 10).second();
+    assert_eq!(star.run(('a', 2)), vec![('a', 20), ('a', -20)]);
+    let mut on_err = Star::new(|s: String| vec![s.len()])
+        .dimap(|s: &str| s.to_string(), |n| n + 1)
+        .on_err();
+    assert_eq!(on_err.run(Err("abc")), vec![Err::<(), usize>(4)]);
+    assert_eq!(on_err.run(Ok(())), vec![Ok(())]);
+    let mut kleisli =
+        Star::<_, i32, _>::new(|x: i32| Some(x + 1)).into_kleisli();
+    assert_eq!(kleisli.run(1), Some(2));
+}
+
+#[test]
+fn test_option_t() {
+    use option_t::OptionT;
+    type VecOption = OptionT<Vec<Option<i32>>, i32>;
+    let values: VecOption = OptionT::new(vec![Some(1), None, Some(3)]);
+    let doubled = values.bind(|x| {
+        OptionT::new(vec![
+            Some(x * 2),
+            if x > 1 { None } else { Some(x) },
+        ])
+    });
+    assert_eq!(
+        doubled.run(),
+        vec![Some(2), Some(1), None, Some(6), None]
+    );
+    let lifted: VecOption = MonadTrans::lift(vec![5]);
+    assert_eq!(lifted.fmap(|x| x + 1).run(), vec![Some(6)]);
+    let recovered = VecOption::new(vec![Some(1), None])
+        .catch_error(|()| OptionT::new(vec![Some(0), Some(-1)]));
+    assert_eq!(recovered.run(), vec![Some(1), Some(0), Some(-1)]);
+    assert_eq!(VecOption::throw_error(()).run(), vec![None]);
+    let pure: VecOption = OptionT::<Vec<Option<()>>, ()>::pure(7);
+    assert_eq!(pure.run(), vec![Some(7)]);
+}
+
+#[test]
+fn test_except_t() {
+    use except_t::ExceptT;
+    type VecResult = ExceptT<Vec<Result<i32, String>>, String, i32>;
+    let values: VecResult =
+        ExceptT::new(vec![Ok(1), Err("e".to_string()), Ok(3)]);
+    let checked = values.bind(|x| {
+        ExceptT::new(vec![if x > 1 {
+            Err(format!("{x}"))
+        } else {
+            Ok(x)
+        }])
+    });
+    assert_eq!(
+        checked.run(),
+        vec![Ok(1), Err("e".to_string()), Err("3".to_string())]
+    );
+    let lifted: VecResult = MonadTrans::lift(vec![5]);
+    assert_eq!(lifted.fmap(|x| x + 1).run(), vec![Ok(6)]);
+    let recovered = VecResult::throw("abc".to_string())
+        .catch_error(|e| ExceptT::new(vec![Ok(e.len() as i32)]));
+    assert_eq!(recovered.run(), vec![Ok(3)]);
+    let mapped = VecResult::new(vec![Ok(1), Err("four".to_string())])
+        .map_err(|e| e.len());
+    assert_eq!(mapped.run(), vec![Ok(1), Err(4)]);
+    use futures::{executor::block_on, future::BoxFuture};
+    type AsyncResult =
+        ExceptT<BoxFuture<'static, Result<i32, ()>>, (), i32>;
+    let lifted: AsyncResult =
+        MonadTrans::lift(Box::pin(async { 2 }) as BoxFuture<_>);
+    let failed = lifted.bind(|_| AsyncResult::throw(()));
+    assert_eq!(block_on(failed.run()), Err(()));
+}
+
+#[test]
+fn test_state_t() {
+    use state_t::StateT;
+    type Branching<A> = StateT<'static, i32, Vec<(A, i32)>, A>;
+    let branch: Branching<i32> =
+        StateT::new(|s| vec![(s, s + 1), (s * 10, s + 2)]);
+    let program = branch.bind(|x| {
+        Branching::<()>::put(x).bind(move |()| StateT::get())
+    });
+    assert_eq!(program.run_state_t(1), vec![(1, 1), (10, 10)]);
+    let lifted: Branching<char> = MonadTrans::lift(vec!['a', 'b']);
+    let counted = lifted
+        .bind(|c| Branching::<()>::modify(|s| s + 1).fmap(move |()| c));
+    assert_eq!(counted.run_state_t(0), vec![('a', 1), ('b', 1)]);
+    let pure: Branching<&str> = Branching::<()>::pure("x");
+    assert_eq!(pure.run_state_t(7), vec![("x", 7)]);
+}
+
+#[test]
+fn test_reader_t() {
+    use reader_t::ReaderT;
+    type Branching<A> = ReaderT<'static, i32, Vec<A>, A>;
+    let branch: Branching<i32> = ReaderT::new(|r| vec![r, r * 10]);
+    let program =
+        branch.bind(|x| Branching::<i32>::ask().fmap(move |r| x + r));
+    assert_eq!(program.run_reader_t(1), vec![2, 11]);
+    let local = Branching::<i32>::ask().local(|r| r + 5);
+    assert_eq!(local.run_reader_t(1), vec![6]);
+    let lifted: Branching<char> = MonadTrans::lift(vec!['a', 'b']);
+    assert_eq!(lifted.run_reader_t(0), vec!['a', 'b']);
+    let plain: Branching<String> =
+        ReaderT::reader(|r: i32| r.to_string());
+    assert_eq!(plain.run_reader_t(3), vec!["3".to_string()]);
+    let pure: Branching<&str> = Branching::<()>::pure("x");
+    assert_eq!(pure.run_reader_t(7), vec!["x"]);
+}
+
+#[test]
+fn test_writer_t() {
+    use monoid::Sum;
+    use writer_t::WriterT;
+    type Branching<A> = WriterT<Sum<i32>, Vec<(A, Sum<i32>)>, A>;
+    let branch: Branching<char> =
+        WriterT::new(vec![('a', Sum(1)), ('b', Sum(2))]);
+    let program = branch
+        .bind(|c| Branching::<()>::tell(Sum(10)).fmap(move |()| c));
+    assert_eq!(
+        program.run_writer_t(),
+        vec![('a', Sum(11)), ('b', Sum(12))]
+    );
+    let lifted: Branching<i32> = MonadTrans::lift(vec![1, 2]);
+    assert_eq!(
+        lifted.censor(|Sum(n)| Sum(n + 1)).listen().run_writer_t(),
+        vec![((1, Sum(1)), Sum(1)), ((2, Sum(1)), Sum(1))]
+    );
+    let pure: Branching<&str> = Branching::<()>::pure("x");
+    assert_eq!(pure.run_writer_t(), vec![("x", Sum(0))]);
+}
+
+#[test]
+fn test_cont_t() {
+    use cont_t::{call_cc, ContT};
+    use futures::{executor::block_on, future::BoxFuture};
+    type Branching<A> = ContT<'static, i32, Vec<i32>, A>;
+    let lifted: Branching<i32> = MonadTrans::lift(vec![1, 2]);
+    let program = lifted
+        .bind(|x| Branching::lift(vec![x, x * 10]))
+        .fmap(|x| x + 1);
+    assert_eq!(program.eval(), vec![2, 11, 3, 21]);
+    let escaping: Branching<i32> = call_cc(|exit| {
+        Branching::lift(vec![1, -1, 2]).bind(move |x| {
+            if x < 0 {
+                exit.escape(0)
+            } else {
+                Branching::<()>::pure(x)
+            }
+        })
+    });
+    assert_eq!(escaping.fmap(|x| x * 3).eval(), vec![3, 0, 6]);
+    let future: ContT<'static, i32, BoxFuture<'static, i32>, i32> =
+        MonadTrans::lift(Box::pin(async { 20 }) as BoxFuture<_>);
+    let future =
+        future.bind(|x| ContT::<i32, BoxFuture<i32>, ()>::pure(x + 1));
+    assert_eq!(block_on(future.eval()), 21);
+}
+
+#[test]
+fn test_identity_t() {
+    use identity_t::IdentityT;
+    let lifted: IdentityT<Option<i32>, i32> = MonadTrans::lift(Some(3));
+    assert_eq!(lifted.fmap(|x| x + 1).run(), Some(4));
+    let bound = IdentityT::new(vec![1, 2])
+        .bind(|x| IdentityT::new(vec![x; x as usize]));
+    assert_eq!(bound.run(), vec![1, 2, 2]);
+    let applied =
+        IdentityT::new(vec![1, 2]).apply(IdentityT::new(vec![
+            Box::new(|x| x * 10) as BoxMapper<Vec<i32>, i32>,
+        ]));
+    assert_eq!(applied.run(), vec![10, 20]);
+    let pure = IdentityT::<Vec<()>, ()>::pure("x");
+    assert_eq!(pure.run(), vec!["x"]);
+}
+
+#[test]
+fn test_rws_t() {
+    use monoid::Sum;
+    use rws_t::RWST;
+    type Branching<A> =
+        RWST<'static, i32, Sum<i32>, i32, Vec<(A, i32, Sum<i32>)>, A>;
+    let lifted: Branching<i32> = MonadTrans::lift(vec![1, 2]);
+    let program = lifted.bind(|x| {
+        Branching::<i32>::ask().bind(move |r| {
+            Branching::<()>::tell(Sum(x)).bind(move |()| {
+                Branching::<()>::modify(move |s| s + r * x)
+            })
+        })
+    });
+    assert_eq!(
+        program.run_rws_t(10, 0),
+        vec![((), 10, Sum(1)), ((), 20, Sum(2))]
+    );
+    let state = Branching::<()>::put(3)
+        .bind(|()| Branching::<i32>::get())
+        .local(|r| r + 1);
+    assert_eq!(state.run_rws_t(0, 0), vec![(3, 3, Sum(0))]);
+    let pure = Branching::<()>::pure("x");
+    assert_eq!(pure.run_rws_t(0, 5), vec![("x", 5, Sum(0))]);
+}
+
+#[test]
+fn test_free_t() {
+    use free_t::{fold_free_t, FreeStep, FreeT};
+    use futures::{executor::block_on, future::BoxFuture};
+    use universal::UniversalFunctor;
+    type Ask<A> = Box<dyn Send + FnOnce(i32) -> A>;
+    type AskTyCon =
+        <Ask<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    type VecTyCon =
+        <Vec<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    type FutureTyCon = <BoxFuture<'static, ()> as UniversalFunctor<
+        'static,
+        (),
+    >>::FunctorTyCon;
+    type Branching<A> = FreeT<'static, AskTyCon, VecTyCon, A>;
+    let ask: Branching<i32> =
+        FreeT::lift_f(Box::new(|x| x) as Ask<i32>);
+    let program = ask
+        .bind(|x| Branching::lift(vec![x, x * 10]))
+        .fmap(|x| x + 1);
+    assert_eq!(
+        fold_free_t(program, |layer: Ask<_>| vec![layer(2)]),
+        vec![3, 21]
+    );
+    type Async<A> = FreeT<'static, AskTyCon, FutureTyCon, A>;
+    fn count(n: i32, acc: i32) -> Async<i32> {
+        if n == 0 {
+            Async::<()>::pure(acc)
+        } else {
+            let ask: Async<i32> =
+                FreeT::lift_f(Box::new(|x| x) as Ask<i32>);
+            ask.bind(move |x| {
+                let lifted: Async<i32> = MonadTrans::lift(Box::pin(
+                    async move { x * 2 },
+                )
+                    as BoxFuture<_>);
+                lifted.bind(move |y| count(n - 1, acc + y))
+            })
+        }
+    }
+    let mut steps = 0;
+    let result = count(10000, 0).run(block_on, |layer| {
+        steps += 1;
+        layer(1)
+    });
+    assert_eq!(steps, 10000);
+    assert_eq!(result, 20000);
+    let pure = Async::<()>::pure(5).resume();
+    assert!(matches!(block_on(pure), FreeStep::Pure(5)));
+}
+
+#[test]
+fn test_monad_state() {
+    use except_t::ExceptT;
+    use identity_t::IdentityT;
+    use ix_state::IxState;
+    use monoid::Sum;
+    use reader_t::ReaderT;
+    use rws_t::RWST;
+    use state_t::StateT;
+    use writer_t::WriterT;
+    fn next<'a, M>() -> M
+    where
+        M: MonadState<'a, i32, Value = i32>,
+    {
+        M::state(|n| (n, n + 1))
+    }
+    type Base<A> = StateT<'static, i32, Vec<(A, i32)>, A>;
+    let plain: IxState<i32, i32, i32> = next();
+    assert_eq!(plain.run(3), (3, 4));
+    let base: Base<i32> = next();
+    assert_eq!(base.run_state_t(3), vec![(3, 4)]);
+    type Logged<A> = (A, Sum<i32>);
+    type Rws = RWST<
+        'static,
+        (),
+        Sum<i32>,
+        i32,
+        Vec<(i32, i32, Sum<i32>)>,
+        i32,
+    >;
+    let rws: Rws = next();
+    assert_eq!(rws.run_rws_t((), 3), vec![(3, 4, Sum(0))]);
+    let except: ExceptT<Base<Result<i32, ()>>, (), i32> = next();
+    assert_eq!(except.run().run_state_t(3), vec![(Ok(3), 4)]);
+    let writer: WriterT<Sum<i32>, Base<Logged<i32>>, i32> = next();
+    assert_eq!(
+        writer.run_writer_t().run_state_t(3),
+        vec![((3, Sum(0)), 4)]
+    );
+    let reader: ReaderT<(), Base<i32>, i32> = next();
+    assert_eq!(reader.run_reader_t(()).run_state_t(3), vec![(3, 4)]);
+    let identity: IdentityT<Base<i32>, i32> = next();
+    assert_eq!(identity.run().run_state_t(3), vec![(3, 4)]);
+    let get: Base<i32> = MonadState::get();
+    assert_eq!(get.run_state_t(1), vec![(1, 1)]);
+    let put: Base<()> = MonadState::put(5);
+    assert_eq!(put.run_state_t(1), vec![((), 5)]);
+    let modify: Base<()> = MonadState::modify(|n| n * 2);
+    assert_eq!(modify.run_state_t(4), vec![((), 8)]);
+}
+
+#[test]
+fn test_monad_reader() {
+    use identity_t::IdentityT;
+    use monoid::Sum;
+    use option_t::OptionT;
+    use reader_t::ReaderT;
+    use rws_t::RWST;
+    use state_t::StateT;
+    use writer_t::WriterT;
+    fn doubled<'a, M>() -> M
+    where
+        M: MonadReader<'a, i32, Value = i32>,
+    {
+        M::reader(|r| r * 2).local(|r| r + 1)
+    }
+    type Base<A> = ReaderT<'static, i32, Vec<A>, A>;
+    let base: Base<i32> = doubled();
+    assert_eq!(base.run_reader_t(3), vec![8]);
+    type Rws =
+        RWST<'static, i32, Sum<i32>, (), Vec<(i32, (), Sum<i32>)>, i32>;
+    let rws: Rws = doubled();
+    assert_eq!(rws.run_rws_t(3, ()), vec![(8, (), Sum(0))]);
+    let state: StateT<char, Base<(i32, char)>, i32> = doubled();
+    assert_eq!(state.run_state_t('s').run_reader_t(3), vec![(8, 's')]);
+    let option: OptionT<Base<Option<i32>>, i32> = doubled();
+    assert_eq!(option.run().run_reader_t(3), vec![Some(8)]);
+    type Logged<A> = (A, Sum<i32>);
+    let writer: WriterT<Sum<i32>, Base<Logged<i32>>, i32> = doubled();
+    assert_eq!(
+        writer.run_writer_t().run_reader_t(3),
+        vec![(8, Sum(0))]
+    );
+    let identity: IdentityT<Base<i32>, i32> = doubled();
+    assert_eq!(identity.run().run_reader_t(3), vec![8]);
+    let ask: Base<i32> = MonadReader::ask();
+    assert_eq!(ask.run_reader_t(5), vec![5]);
+}
+
+#[test]
+fn test_monad_writer() {
+    use except_t::ExceptT;
+    use identity_t::IdentityT;
+    use monoid::Sum;
+    use reader_t::ReaderT;
+    use rws_t::RWST;
+    use state_t::StateT;
+    use writer_t::WriterT;
+    fn logged<'a, M>(x: i32) -> M
+    where
+        M: MonadWriter<'a, Sum<i32>, Value = i32>,
+    {
+        M::writer(x, Sum(x)).censor(|Sum(n)| Sum(n * 10))
+    }
+    type Logged<A> = (A, Sum<i32>);
+    type Base<A> = WriterT<Sum<i32>, Vec<Logged<A>>, A>;
+    let base: Base<i32> = logged(2);
+    assert_eq!(
+        base.listen().run_writer_t(),
+        vec![((2, Sum(20)), Sum(20))]
+    );
+    let tell: Base<()> = MonadWriter::tell(Sum(1));
+    assert_eq!(tell.run_writer_t(), vec![((), Sum(1))]);
+    type Rws<A> =
+        RWST<'static, (), Sum<i32>, (), Vec<(A, (), Sum<i32>)>, A>;
+    let rws: Rws<i32> = logged(2);
+    assert_eq!(
+        rws.listen().run_rws_t((), ()),
+        vec![((2, Sum(20)), (), Sum(20))]
+    );
+    let state: StateT<char, Base<(i32, char)>, i32> = logged(2);
+    assert_eq!(
+        state.listen().run_state_t('s').run_writer_t(),
+        vec![(((2, Sum(20)), 's'), Sum(20))]
+    );
+    let reader: ReaderT<(), Base<i32>, i32> = logged(2);
+    assert_eq!(
+        reader.listen().run_reader_t(()).run_writer_t(),
+        vec![((2, Sum(20)), Sum(20))]
+    );
+    let except: ExceptT<Base<Result<i32, ()>>, (), i32> = logged(2);
+    assert_eq!(
+        except.listen().run().run_writer_t(),
+        vec![(Ok((2, Sum(20))), Sum(20))]
+    );
+    let identity: IdentityT<Base<i32>, i32> = logged(2);
+    assert_eq!(identity.run().run_writer_t(), vec![(2, Sum(20))]);
+}
+
+#[test]
+fn test_monad_cont() {
+    use cont::Cont;
+    use cont_t::ContT;
+    use except_t::ExceptT;
+    use identity_t::IdentityT;
+    use monoid::Sum;
+    use option_t::OptionT;
+    use reader_t::ReaderT;
+    use rws_t::RWST;
+    use state_t::StateT;
+    use writer_t::WriterT;
+    fn clamp<'a, M>(x: i32) -> M
+    where
+        M: MonadCont<'a, Value = i32>,
+        M: Monad<'a, i32, Inner = i32, Mapped = M>,
+    {
+        M::call_cc(move |exit| {
+            if x > 10 {
+                M::escape(&exit, 10)
+            } else {
+                M::pure(x)
+            }
+            .bind(|x| M::pure(x * 2))
+        })
+    }
+    type Base<A> = ContT<'static, A, Vec<A>, A>;
+    let cont: Cont<'static, i32, i32> = clamp(3);
+    assert_eq!(cont.run(|x| x), 6);
+    let cont: Cont<'static, i32, i32> = clamp(30);
+    assert_eq!(cont.run(|x| x), 10);
+    let cont_t: Base<i32> = clamp(30);
+    assert_eq!(cont_t.eval(), vec![10]);
+    let option: OptionT<Base<Option<i32>>, i32> = clamp(3);
+    assert_eq!(option.run().eval(), vec![Some(6)]);
+    let except: ExceptT<Base<Result<i32, ()>>, (), i32> = clamp(30);
+    assert_eq!(except.run().eval(), vec![Ok(10)]);
+    type Logged<A> = WriterT<Sum<i32>, Base<(A, Sum<i32>)>, A>;
+    let writer: Logged<i32> = clamp(30);
+    assert_eq!(writer.run_writer_t().eval(), vec![(10, Sum(0))]);
+    let identity: IdentityT<Base<i32>, i32> = clamp(3);
+    assert_eq!(identity.run().eval(), vec![6]);
+    let state: StateT<char, Base<(i32, char)>, i32> = clamp(30);
+    assert_eq!(state.run_state_t('s').eval(), vec![(10, 's')]);
+    let reader: ReaderT<(), Base<i32>, i32> = clamp(3);
+    assert_eq!(reader.run_reader_t(()).eval(), vec![6]);
+    type Rws<A> =
+        RWST<'static, (), Sum<i32>, char, Base<(A, char, Sum<i32>)>, A>;
+    let rws: Rws<i32> = clamp(30);
+    assert_eq!(rws.run_rws_t((), 's').eval(), vec![(10, 's', Sum(0))]);
+}
+
+#[test]
+fn test_monad_io() {
+    use except_t::ExceptT;
+    use futures::{executor::block_on, future::BoxFuture};
+    use identity_t::IdentityT;
+    use io::Io;
+    use monoid::Sum;
+    use option_t::OptionT;
+    use reader_t::ReaderT;
+    use rws_t::RWST;
+    use state_t::StateT;
+    use std::sync::{Arc, Mutex};
+    use writer_t::WriterT;
+    fn record<'a, M>(log: &Arc<Mutex<Vec<i32>>>, x: i32) -> M
+    where
+        M: MonadIo<'a, Value = i32>,
+    {
+        let log = log.clone();
+        M::lift_io(move || {
+            log.lock().unwrap().push(x);
+            x
+        })
+    }
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let io: Io<i32> = record(&log, 1);
+    assert!(log.lock().unwrap().is_empty());
+    assert_eq!(io.run(), 1);
+    let future: BoxFuture<i32> = record(&log, 2);
+    assert_eq!(block_on(future), 2);
+    let thunk: Box<dyn FnOnce() -> i32> = record(&log, 3);
+    assert_eq!(thunk(), 3);
+    let option: OptionT<Io<Option<i32>>, i32> = record(&log, 4);
+    assert_eq!(option.run().run(), Some(4));
+    let except: ExceptT<Io<Result<i32, ()>>, (), i32> = record(&log, 5);
+    assert_eq!(except.run().run(), Ok(5));
+    type Logged<A> = WriterT<Sum<i32>, Io<'static, (A, Sum<i32>)>, A>;
+    let writer: Logged<i32> = record(&log, 6);
+    assert_eq!(writer.run_writer_t().run(), (6, Sum(0)));
+    let identity: IdentityT<Io<i32>, i32> = record(&log, 7);
+    assert_eq!(identity.run().run(), 7);
+    let state: StateT<char, Io<(i32, char)>, i32> = record(&log, 8);
+    assert_eq!(state.run_state_t('s').run(), (8, 's'));
+    let reader: ReaderT<(), BoxFuture<i32>, i32> = record(&log, 9);
+    assert_eq!(block_on(reader.run_reader_t(())), 9);
+    type Rws<A> = RWST<
+        'static,
+        (),
+        Sum<i32>,
+        char,
+        Io<'static, (A, char, Sum<i32>)>,
+        A,
+    >;
+    let rws: Rws<i32> = record(&log, 10);
+    assert_eq!(rws.run_rws_t((), 's').run(), (10, 's', Sum(0)));
+    type Nested<A> =
+        ReaderT<'static, (), OptionT<Io<'static, Option<A>>, A>, A>;
+    let nested: Nested<i32> = record(&log, 11);
+    assert_eq!(nested.run_reader_t(()).run().run(), Some(11));
+    assert_eq!(*log.lock().unwrap(), (1..=11).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_monad_bracket() {
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    use io::Io;
+    use std::future::Future;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    type Log = Arc<Mutex<Vec<&'static str>>>;
+    let log: Log = Default::default();
+    let release = |log: Log| -> Result<(), &str> {
+        log.lock().unwrap().push("release");
+        Ok(())
+    };
+    let ok: Result<i32, &str> =
+        Result::bracket(Ok(log.clone()), |_| Ok(1), release);
+    assert_eq!(ok, Ok(1));
+    let err: Result<i32, &str> =
+        Result::bracket(Ok(log.clone()), |_| Err("body"), release);
+    assert_eq!(err, Err("body"));
+    let err: Result<i32, &str> = Result::bracket(
+        Ok(log.clone()),
+        |_| Ok(1),
+        |log: Log| {
+            log.lock().unwrap().push("failing release");
+            Err("release")
+        },
+    );
+    assert_eq!(err, Err("release"));
+    let not_acquired: Result<i32, &str> =
+        Result::bracket(Err("acquire"), |_| Ok(1), release);
+    assert_eq!(not_acquired, Err("acquire"));
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["release", "release", "failing release"]
+    );
+    log.lock().unwrap().clear();
+    let io = Io::bracket(
+        Io::new({
+            let log = log.clone();
+            move || log
+        }),
+        |_| Io::new(|| -> i32 { panic!("body") }),
+        |log| Io::new(move || log.lock().unwrap().push("io release")),
+    );
+    assert!(catch_unwind(AssertUnwindSafe(|| io.run())).is_err());
+    let io = Io::bracket(
+        Io::new({
+            let log = log.clone();
+            move || log
+        }),
+        |_| -> Io<i32> { panic!("body closure") },
+        |log| Io::new(move || log.lock().unwrap().push("io release")),
+    );
+    assert!(catch_unwind(AssertUnwindSafe(|| io.run())).is_err());
+    assert_eq!(*log.lock().unwrap(), vec!["io release", "io release"]);
+    log.lock().unwrap().clear();
+    let future: BoxFuture<i32> = BoxFuture::bracket(
+        std::future::ready(log.clone()).boxed(),
+        |log: Log| {
+            async move {
+                log.lock().unwrap().push("use");
+                panic!("body")
+            }
+            .boxed()
+        },
+        |log: Log| {
+            async move {
+                log.lock().unwrap().push("async release");
+            }
+            .boxed()
+        },
+    );
+    assert!(
+        catch_unwind(AssertUnwindSafe(|| block_on(future))).is_err()
+    );
+    let future: BoxFuture<i32> = BoxFuture::bracket(
+        std::future::ready(log.clone()).boxed(),
+        |_| -> BoxFuture<i32> { panic!("body closure") },
+        |log: Log| {
+            async move {
+                log.lock().unwrap().push("async release");
+            }
+            .boxed()
+        },
+    );
+    assert!(
+        catch_unwind(AssertUnwindSafe(|| block_on(future))).is_err()
+    );
+    type LocalFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+    let future: LocalFuture<i32> = LocalFuture::bracket(
+        Box::pin(std::future::ready(log.clone())),
+        |_| -> LocalFuture<i32> { panic!("body closure") },
+        |log: Log| -> LocalFuture<()> {
+            Box::pin(async move {
+                log.lock().unwrap().push("local release");
+            })
+        },
+    );
+    assert!(
+        catch_unwind(AssertUnwindSafe(|| block_on(future))).is_err()
+    );
+    let future: BoxFuture<i32> = BoxFuture::bracket(
+        std::future::ready(log.clone()).boxed(),
+        |_| std::future::ready(7).boxed(),
+        |log: Log| {
+            async move {
+                log.lock().unwrap().push("async release");
+            }
+            .boxed()
+        },
+    );
+    assert_eq!(block_on(future), 7);
+    type SyncFuture<T> = Pin<Box<dyn Future<Output = T> + Send + Sync>>;
+    let future: SyncFuture<i32> = SyncFuture::bracket(
+        Box::pin(std::future::ready(log.clone())),
+        |_| -> SyncFuture<i32> { Box::pin(async { panic!("body") }) },
+        |log: Log| -> SyncFuture<()> {
+            Box::pin(async move {
+                log.lock().unwrap().push("sync release");
+            })
+        },
+    );
+    assert!(
+        catch_unwind(AssertUnwindSafe(|| block_on(future))).is_err()
+    );
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "use",
+            "async release",
+            "async release",
+            "local release",
+            "async release",
+            "sync release"
+        ]
+    );
+}
+
+#[test]
+fn test_hoist() {
+    use except_t::ExceptT;
+    use free_t::{fold_free_t, FreeT};
+    use futures::{executor::block_on, future::BoxFuture};
+    use identity_t::IdentityT;
+    use monoid::Sum;
+    use option_t::OptionT;
+    use reader_t::ReaderT;
+    use rws_t::RWST;
+    use state_t::StateT;
+    use universal::UniversalFunctor;
+    use writer_t::WriterT;
+    fn to_future<'a, A: 'a + Send>(x: Option<A>) -> BoxFuture<'a, A> {
+        Box::pin(async move { x.expect("missing value") })
+    }
+    let option: OptionT<Vec<Option<i32>>, i32> =
+        OptionT::new(vec![Some(1), None]);
+    let hoisted = option
+        .hoist(|v: Vec<_>| v.into_iter().rev().collect::<Vec<_>>());
+    assert_eq!(hoisted.run(), vec![None, Some(1)]);
+    let except: ExceptT<Option<Result<i32, ()>>, (), i32> =
+        ExceptT::new(Some(Ok(2)));
+    assert_eq!(block_on(except.hoist(to_future).run()), Ok(2));
+    let identity: IdentityT<Option<i32>, i32> = IdentityT::new(Some(3));
+    assert_eq!(block_on(identity.hoist(to_future).run()), 3);
+    type Logged<A> = WriterT<Sum<i32>, Option<(A, Sum<i32>)>, A>;
+    let writer: Logged<i32> = WriterT::new(Some((4, Sum(1))));
+    assert_eq!(
+        block_on(writer.hoist(to_future).run_writer_t()),
+        (4, Sum(1))
+    );
+    let state: StateT<i32, Option<(i32, i32)>, i32> =
+        StateT::<i32, Option<(i32, i32)>, i32>::get()
+            .bind(|s| StateT::lift(Some(s * 2)));
+    assert_eq!(
+        block_on(state.hoist(to_future).run_state_t(5)),
+        (10, 5)
+    );
+    let reader: ReaderT<i32, Option<i32>, i32> = ReaderT::ask();
+    assert_eq!(block_on(reader.hoist(to_future).run_reader_t(6)), 6);
+    type Rws<A> = RWST<
+        'static,
+        i32,
+        Sum<i32>,
+        char,
+        Option<(A, char, Sum<i32>)>,
+        A,
+    >;
+    let rws: Rws<i32> = Rws::<i32>::ask();
+    assert_eq!(
+        block_on(rws.hoist(to_future).run_rws_t(7, 's')),
+        (7, 's', Sum(0))
+    );
+    type Ask<A> = Box<dyn Send + FnOnce(i32) -> A>;
+    type AskTyCon =
+        <Ask<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    type OptionTyCon =
+        <Option<()> as UniversalFunctor<'static, ()>>::FunctorTyCon;
+    type FutureTyCon = <BoxFuture<'static, ()> as UniversalFunctor<
+        'static,
+        (),
+    >>::FunctorTyCon;
+    type Program<A> = FreeT<'static, AskTyCon, OptionTyCon, A>;
+    let ask = || -> Program<i32> {
+        FreeT::lift_f(Box::new(|x| x) as Ask<i32>)
+    };
+    let program = ask()
+        .bind(move |a| ask().bind(move |b| Program::lift(Some(a * b))));
+    let hoisted: FreeT<'static, AskTyCon, FutureTyCon, i32> =
+        program.hoist(to_future);
+    let result = fold_free_t(hoisted, |layer: Ask<_>| {
+        Box::pin(async move { layer(8) }) as BoxFuture<_>
+    });
+    assert_eq!(block_on(result), 64);
+}
+
+#[test]
+fn test_async_state() {
+    use futures::{executor::block_on, future::BoxFuture};
+    use state_t::AsyncState;
+    fn push(x: i32) -> AsyncState<'static, Vec<i32>, ()> {
+        AsyncState::modify_async(move |mut v: Vec<i32>| async move {
+            v.push(x);
+            v
+        })
+    }
+    let program = push(1)
+        .bind(|()| push(2))
+        .then_async(|(), v| async move { (v.len(), v) })
+        .map_async(|len| async move { len * 10 })
+        .and_then_async(
+            |n| async move { push(n as i32).fmap(move |()| n) },
+        );
+    assert_eq!(
+        block_on(program.run_state_t(vec![0])),
+        (30, vec![0, 1, 2, 30])
+    );
+    let lifted: AsyncState<'static, (), i32> =
+        MonadTrans::lift(Box::pin(async { 5 }) as BoxFuture<i32>);
+    assert_eq!(block_on(lifted.run_state_t(())), (5, ()));
+}
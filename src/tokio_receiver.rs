@@ -0,0 +1,137 @@
+//! Mapping values received from an asynchronous channel (requires feature
+//! `tokio`)
+//!
+//! See [`MappedReceiver`].
+
+use super::*;
+
+use std::future::poll_fn;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc::{Receiver, UnboundedReceiver};
+
+/// Receiving end of a Tokio `mpsc` channel whose received values are
+/// mapped
+///
+/// A `MappedReceiver` wraps a [`Receiver`] or [`UnboundedReceiver`] of the
+/// [`tokio::sync::mpsc`] module. [Mapping] applies a closure to every
+/// received value when it is received, such that no extra task is needed
+/// for forwarding values. This is the asynchronous counterpart of
+/// [`receiver::MappedReceiver`].
+///
+/// [Mapping]: Functor::fmap
+///
+/// # Examples
+///
+/// ```
+/// use fmap::Functor;
+/// use fmap::tokio_receiver::MappedReceiver;
+/// use tokio::sync::mpsc::channel;
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread()
+///     .build()
+///     .unwrap();
+/// runtime.block_on(async {
+///     let (tx, rx) = channel(4);
+///     let mut lengths = MappedReceiver::new(rx).fmap(|s: &str| s.len());
+///     tx.send("hello").await.unwrap();
+///     drop(tx);
+///     assert_eq!(lengths.recv().await, Some(5));
+///     assert_eq!(lengths.recv().await, None);
+/// });
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct MappedReceiver<'a, A> {
+    poll:
+        Box<dyn 'a + Send + FnMut(&mut Context<'_>) -> Poll<Option<A>>>,
+}
+
+impl<'a, A> MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    /// Wrap a [`Receiver`]
+    pub fn new(mut receiver: Receiver<A>) -> Self {
+        MappedReceiver {
+            poll: Box::new(move |cx| receiver.poll_recv(cx)),
+        }
+    }
+
+    /// Wrap an [`UnboundedReceiver`]
+    pub fn unbounded(mut receiver: UnboundedReceiver<A>) -> Self {
+        MappedReceiver {
+            poll: Box::new(move |cx| receiver.poll_recv(cx)),
+        }
+    }
+}
+
+impl<'a, A> MappedReceiver<'a, A> {
+    /// Receive the next (mapped) value
+    ///
+    /// Returns [`None`] if all senders have been dropped.
+    pub async fn recv(&mut self) -> Option<A> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Poll to receive the next (mapped) value
+    pub fn poll_recv(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<A>> {
+        (self.poll)(cx)
+    }
+}
+
+impl<'a, A> From<Receiver<A>> for MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    fn from(receiver: Receiver<A>) -> Self {
+        MappedReceiver::new(receiver)
+    }
+}
+
+impl<'a, A> From<UnboundedReceiver<A>> for MappedReceiver<'a, A>
+where
+    A: 'a + Send,
+{
+    fn from(receiver: UnboundedReceiver<A>) -> Self {
+        MappedReceiver::unbounded(receiver)
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for MappedReceiver<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = MappedReceiver<'a, B>;
+    fn fmap<F>(mut self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        MappedReceiver {
+            poll: Box::new(move |cx| {
+                self.poll_recv(cx).map(|option| option.map(&mut f))
+            }),
+        }
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for MappedReceiver<'a, A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        let this = std::mem::replace(
+            self,
+            MappedReceiver {
+                poll: Box::new(|_| panic!("poisoned FunctorMut")),
+            },
+        );
+        *self = this.fmap_fn_mutref(f);
+    }
+}
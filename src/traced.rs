@@ -0,0 +1,109 @@
+//! Traced comonad
+//!
+//! See [`Traced`].
+
+use super::*;
+
+use monoid::Monoid;
+
+use std::sync::{Arc, Mutex};
+
+/// Traced comonad
+///
+/// A `Traced<'a, M, A>` is a function from a [`Monoid`] `M` (e.g. relative
+/// positions or offsets) to values of type `A`. The value in focus is the
+/// value at [`Monoid::empty`]. When [extending], the function is called with
+/// traces that are relative to the respective position, i.e. offsets are
+/// accumulated using [`Semigroup::combine`].
+///
+/// [extending]: Comonad::extend
+/// [`Semigroup::combine`]: monoid::Semigroup::combine
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Extract};
+/// use fmap::traced::Traced;
+///
+/// // square of the sum of all offsets
+/// let square = Traced::new(|offsets: Vec<i32>| {
+///     offsets.iter().sum::<i32>().pow(2)
+/// });
+/// assert_eq!(square.clone().extract(), 0);
+/// assert_eq!(square.trace(vec![3]), 9);
+///
+/// // difference quotient with step size 1
+/// let derivative = square.extend(|t| t.trace(vec![1]) - t.extract());
+/// assert_eq!(derivative.trace(vec![2, 3]), 11);
+/// ```
+pub struct Traced<'a, M, A>(Arc<dyn 'a + Send + Sync + Fn(M) -> A>);
+
+impl<'a, M, A> Clone for Traced<'a, M, A> {
+    fn clone(&self) -> Self {
+        Traced(self.0.clone())
+    }
+}
+
+impl<'a, M, A> Traced<'a, M, A> {
+    /// Create traced comonad from function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'a + Send + Sync + Fn(M) -> A,
+    {
+        Traced(Arc::new(f))
+    }
+
+    /// Value at the given trace
+    pub fn trace(&self, m: M) -> A {
+        (self.0)(m)
+    }
+}
+
+impl<'a, M, A, B> Functor<'a, B> for Traced<'a, M, A>
+where
+    M: 'a,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Traced<'a, M, B>;
+    fn fmap<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let g = self.0;
+        let f = Mutex::new(f);
+        Traced(Arc::new(move |m| (f.lock().unwrap())(g(m))))
+    }
+}
+
+impl<'a, M, A> Extract<'a, A> for Traced<'a, M, A>
+where
+    M: 'a + Monoid,
+    A: 'a,
+{
+    fn extract(self) -> A {
+        (self.0)(M::empty())
+    }
+}
+
+impl<'a, M, A, B> Comonad<'a, B> for Traced<'a, M, A>
+where
+    M: 'a + Monoid + Clone + Send + Sync,
+    A: 'a,
+    B: 'a,
+{
+    fn extend<F>(self, f: F) -> <Self as Functor<'a, B>>::Mapped
+    where
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        let g = self.0;
+        let f = Mutex::new(f);
+        Traced(Arc::new(move |m: M| {
+            let g = g.clone();
+            (f.lock().unwrap())(Traced(Arc::new(move |m2| {
+                g(m.clone().combine(m2))
+            })))
+        }))
+    }
+}
@@ -0,0 +1,175 @@
+//! Stack-safe evaluation of monadic computations
+//!
+//! See [`Trampoline`].
+
+use super::*;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Single step of a running [`Trampoline`]
+enum Bounce<'a> {
+    Done,
+    More(Box<dyn 'a + FnOnce() -> Bounce<'a>>),
+}
+
+/// Continuation that receives the result of a [`Trampoline`]
+type Continuation<'a, A> = Box<dyn 'a + FnOnce(A) -> Bounce<'a>>;
+
+/// Monad for stack-safe (recursive) computations
+///
+/// A `Trampoline<'a, A>` is a computation that is either [done] or needs
+/// [more] steps, and which can be [flat-mapped]. [`Trampoline::run`]
+/// evaluates the computation in a loop, where each step returns to the loop
+/// before the next step is executed. Thus deeply nested calls of
+/// [`Monad::bind`] (in either direction) don't consume stack space
+/// proportional to their nesting depth.
+///
+/// Using `Trampoline` as target monad of [`fold_free`] makes interpretation
+/// of [`Free`] monads stack-safe.
+///
+/// [done]: Trampoline::done
+/// [more]: Trampoline::more
+/// [flat-mapped]: Trampoline::flat_map
+/// [`fold_free`]: free::fold_free
+/// [`Free`]: free::Free
+///
+/// # Examples
+///
+/// ```
+/// use fmap::trampoline::Trampoline;
+///
+/// fn is_even(n: u64) -> Trampoline<'static, bool> {
+///     if n == 0 {
+///         Trampoline::done(true)
+///     } else {
+///         Trampoline::more(move || is_odd(n - 1))
+///     }
+/// }
+/// fn is_odd(n: u64) -> Trampoline<'static, bool> {
+///     if n == 0 {
+///         Trampoline::done(false)
+///     } else {
+///         Trampoline::more(move || is_even(n - 1))
+///     }
+/// }
+/// assert_eq!(is_even(1_000_001).run(), false);
+///
+/// let mut sum = Trampoline::done(0u64);
+/// for i in 0..100_000 {
+///     sum = sum.flat_map(move |s| Trampoline::done(s + i));
+/// }
+/// assert_eq!(sum.run(), 4_999_950_000);
+/// ```
+pub struct Trampoline<'a, A>(
+    Box<dyn 'a + Send + FnOnce(Continuation<'a, A>) -> Bounce<'a>>,
+);
+
+impl<'a, A> Trampoline<'a, A>
+where
+    A: 'a,
+{
+    /// Finished computation
+    pub fn done(a: A) -> Self
+    where
+        A: Send,
+    {
+        Trampoline(Box::new(move |k| k(a)))
+    }
+
+    /// Computation that continues with the trampoline returned by `thunk`
+    pub fn more<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> Self,
+    {
+        Trampoline(Box::new(move |k| {
+            Bounce::More(Box::new(move || (thunk().0)(k)))
+        }))
+    }
+
+    /// Continue with a computation that depends on the result
+    pub fn flat_map<B, F>(self, f: F) -> Trampoline<'a, B>
+    where
+        B: 'a,
+        F: 'a + Send + FnOnce(A) -> Trampoline<'a, B>,
+    {
+        Trampoline(Box::new(move |k| {
+            Bounce::More(Box::new(move || {
+                (self.0)(Box::new(move |a| {
+                    Bounce::More(Box::new(move || (f(a).0)(k)))
+                }))
+            }))
+        }))
+    }
+
+    /// Run computation in a loop
+    pub fn run(self) -> A {
+        let result = Rc::new(Cell::new(None));
+        let result2 = result.clone();
+        let mut bounce = (self.0)(Box::new(move |a| {
+            result2.set(Some(a));
+            Bounce::Done
+        }));
+        while let Bounce::More(thunk) = bounce {
+            bounce = thunk();
+        }
+        result.take().unwrap()
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Trampoline<'a, A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Trampoline<'a, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        Trampoline(Box::new(move |k| {
+            Bounce::More(Box::new(move || {
+                (self.0)(Box::new(move |a| {
+                    Bounce::More(Box::new(move || k(f(a))))
+                }))
+            }))
+        }))
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Trampoline<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Trampoline::done(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Trampoline<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn bind<F>(self, f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.flat_map(f)
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Trampoline<'a, A>
+where
+    A: 'a,
+    B: 'a + Send,
+{
+    fn apply(
+        self,
+        f: Trampoline<'a, BoxMapper<'a, Self, B>>,
+    ) -> Trampoline<'a, B> {
+        f.flat_map(move |mapper| self.fmap(mapper))
+    }
+}
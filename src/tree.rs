@@ -0,0 +1,243 @@
+//! Rose tree
+//!
+//! See [`Tree`].
+
+use super::*;
+
+use std::sync::{Arc, Mutex};
+
+/// Rose tree, i.e. a value with an arbitrary number of subtrees
+///
+/// Mapping visits the values in pre-order. [Binding] replaces each value
+/// with a tree whose root takes the value's place, where the original
+/// subtrees are appended to the new root's subtrees. [Extending] a function
+/// applies it to each subtree.
+///
+/// [Binding]: Monad::bind
+/// [Extending]: Comonad::extend
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Functor};
+/// use fmap::tree::Tree;
+///
+/// let tree = Tree {
+///     value: 1,
+///     children: vec![Tree::leaf(2), Tree::leaf(3)],
+/// };
+///
+/// // annotate each node with the sum of the values in its subtree
+/// fn sum(tree: &Tree<i32>) -> i32 {
+///     tree.value + tree.children.iter().map(sum).sum::<i32>()
+/// }
+/// let sums = tree.clone().extend(|t| sum(&t));
+/// assert_eq!(sums.value, 6);
+/// assert_eq!(sums.children, vec![Tree::leaf(2), Tree::leaf(3)]);
+///
+/// // fail if any value is zero
+/// let checked = tree.traverse(|x| (x != 0).then_some(x * 10));
+/// assert_eq!(checked.map(|t| t.value), Some(10));
+/// let zero = Tree::leaf(0).traverse(|x| (x != 0).then_some(x));
+/// assert_eq!(zero, None);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Tree<A> {
+    /// Value at the root
+    pub value: A,
+    /// Subtrees
+    pub children: Vec<Tree<A>>,
+}
+
+impl<A> Tree<A> {
+    /// Create tree without subtrees
+    pub fn leaf(value: A) -> Self {
+        Tree {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    fn fmap_rec<B, F>(self, f: &mut F) -> Tree<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        Tree {
+            value: f(self.value),
+            children: self
+                .children
+                .into_iter()
+                .map(|child| child.fmap_rec(f))
+                .collect(),
+        }
+    }
+
+    fn fmap_mut_rec<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut A),
+    {
+        f(&mut self.value);
+        for child in self.children.iter_mut() {
+            child.fmap_mut_rec(f);
+        }
+    }
+
+    fn bind_rec<B, F>(self, f: &mut F) -> Tree<B>
+    where
+        F: FnMut(A) -> Tree<B>,
+    {
+        let mut tree = f(self.value);
+        tree.children.extend(
+            self.children.into_iter().map(|child| child.bind_rec(f)),
+        );
+        tree
+    }
+
+    fn extend_rec<B, F>(self, f: &mut F) -> Tree<B>
+    where
+        A: Clone,
+        F: FnMut(Self) -> B,
+    {
+        let children = self.children.clone();
+        Tree {
+            value: f(self),
+            children: children
+                .into_iter()
+                .map(|child| child.extend_rec(f))
+                .collect(),
+        }
+    }
+
+    /// Apply a function returning a [`Monad`] to each value (in pre-order)
+    /// and collect the results into a tree within the monad
+    ///
+    /// Effects of the monad (e.g. short-circuiting of [`Option`] or
+    /// [`Result`]) are combined using [`Monad::bind`].
+    pub fn traverse<'a, B, M, F>(self, f: F) -> M::Mapped
+    where
+        A: 'a + Clone + Send,
+        B: 'a + Clone + Send,
+        M: Monad<'a, Tree<B>, Inner = B>,
+        M::Mapped:
+            Monad<'a, Tree<B>, Inner = Tree<B>, Mapped = M::Mapped>,
+        F: 'a + Send + FnMut(A) -> M,
+    {
+        self.traverse_shared(Arc::new(Mutex::new(f)))
+    }
+
+    fn traverse_shared<'a, B, M, F>(self, f: Arc<Mutex<F>>) -> M::Mapped
+    where
+        A: 'a + Clone + Send,
+        B: 'a + Clone + Send,
+        M: Monad<'a, Tree<B>, Inner = B>,
+        M::Mapped:
+            Monad<'a, Tree<B>, Inner = Tree<B>, Mapped = M::Mapped>,
+        F: 'a + Send + FnMut(A) -> M,
+    {
+        let root = (f.lock().unwrap())(self.value);
+        let mut result = root.fmap(Tree::leaf);
+        for child in self.children {
+            let f = f.clone();
+            result = result.bind(move |tree| {
+                let child =
+                    child.clone().traverse_shared::<B, M, F>(f.clone());
+                child.fmap(move |child| {
+                    let mut tree = tree.clone();
+                    tree.children.push(child);
+                    tree
+                })
+            });
+        }
+        result
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for Tree<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = Tree<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        self.fmap_rec(&mut f)
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for Tree<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut_rec(&mut f)
+    }
+}
+
+impl<'a, A, B> Pure<'a, B> for Tree<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        Tree::leaf(b)
+    }
+}
+
+impl<'a, A, B> Monad<'a, B> for Tree<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        self.bind_rec(&mut f)
+    }
+}
+
+impl<'a, A, B> Applicative<'a, B> for Tree<A>
+where
+    A: 'a + Clone + Send,
+    B: 'a,
+{
+    fn apply(self, f: Tree<BoxMapper<'a, Self, B>>) -> Tree<B> {
+        monad_apply(self, f)
+    }
+}
+
+impl<'a, A> Extract<'a, A> for Tree<A>
+where
+    A: 'a,
+{
+    fn extract(self) -> A {
+        self.value
+    }
+}
+
+/// Extending clones each subtree
+impl<'a, A, B> Comonad<'a, B> for Tree<A>
+where
+    A: 'a + Clone,
+    B: 'a,
+{
+    fn extend<F>(self, mut f: F) -> Tree<B>
+    where
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        self.extend_rec(&mut f)
+    }
+}
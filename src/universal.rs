@@ -332,13 +332,26 @@ mod impls {
 
     use super::*;
 
+    use std::cmp::Reverse;
     use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
     use std::future::Future;
     use std::hash::Hash;
     use std::marker::PhantomData;
+    use std::num::{Saturating, Wrapping};
+    use std::ops::{Bound, ControlFlow};
     use std::pin::Pin;
+    use std::task::Poll;
 
     impl_universal_functor!(Option_, Option<A>);
+    impl_universal_functor!(Box_, Box<A>);
+    impl_universal_functor!(Bound_, Bound<A>);
+    impl_universal_functor!(BoundPair_, (Bound<A>, Bound<A>));
+    impl_universal_functor!(Poll_, Poll<A>);
+    impl_universal_functor!(PhantomData_, PhantomData<A>);
+    impl_universal_functor!(Wrapping_, Wrapping<A>);
+    impl_universal_functor!(Saturating_, Saturating<A>);
+    impl_universal_functor!(Reverse_, Reverse<A>);
+    impl_universal_functor!(BoxSlice_, Box<[A]>);
     impl_universal_functor!(Vec_, Vec<A>);
     impl_universal_functor!(VecDeque_, VecDeque<A>);
     impl_universal_functor!(LinkedList_, LinkedList<A>);
@@ -358,6 +371,20 @@ mod impls {
         FutureSend_,
         Pin<Box<dyn 'a + Send + Future<Output = A>>>
     );
+    impl_universal_functor!(
+        FutureSendSync_,
+        Pin<Box<dyn 'a + Send + Sync + Future<Output = A>>>
+    );
+    #[cfg(feature = "futures")]
+    impl_universal_functor!(
+        Stream_,
+        Pin<Box<dyn 'a + futures::Stream<Item = A>>>
+    );
+    #[cfg(feature = "futures")]
+    impl_universal_functor!(
+        StreamSend_,
+        Pin<Box<dyn 'a + Send + futures::Stream<Item = A>>>
+    );
     impl_universal_functor!(FnOnce_, Box<dyn 'a + FnOnce() -> A>);
     impl_universal_functor!(
         FnOnceSend_,
@@ -379,12 +406,35 @@ mod impls {
         Box<dyn 'a + Send + FnMut(X) -> A>
     );
 
+    pub struct Array_<const N: usize>;
+    impl<'a, const N: usize> UniversalFunctorTyCon<'a> for Array_<N> {
+        type Functor<A, B>
+            = [A; N]
+        where
+            A: 'a,
+            B: 'a;
+    }
+    impl<'a, A, B, const N: usize> UniversalFunctor<'a, B> for [A; N]
+    where
+        A: 'a,
+        B: 'a,
+    {
+        type FunctorTyCon = Array_<N>;
+        fn change_functor_target<T>(self) -> Self {
+            self
+        }
+        fn from_mapped_functor(this: Self) -> Self {
+            this
+        }
+    }
+
     pub struct Result_<E>(PhantomData<E>);
     impl<'a, E> UniversalFunctorTyCon<'a> for Result_<E>
     where
         E: 'a,
     {
-        type Functor<A, B> = Result<A, E>
+        type Functor<A, B>
+            = Result<A, E>
         where
             A: 'a,
             B: 'a;
@@ -404,12 +454,39 @@ mod impls {
         }
     }
 
+    pub struct ControlFlow_<E>(PhantomData<E>);
+    impl<'a, E> UniversalFunctorTyCon<'a> for ControlFlow_<E>
+    where
+        E: 'a,
+    {
+        type Functor<A, B>
+            = ControlFlow<E, A>
+        where
+            A: 'a,
+            B: 'a;
+    }
+    impl<'a, A, B, E> UniversalFunctor<'a, B> for ControlFlow<E, A>
+    where
+        A: 'a,
+        B: 'a,
+        E: 'a,
+    {
+        type FunctorTyCon = ControlFlow_<E>;
+        fn change_functor_target<T>(self) -> Self {
+            self
+        }
+        fn from_mapped_functor(this: Self) -> Self {
+            this
+        }
+    }
+
     pub struct HashMap_<K>(PhantomData<K>);
     impl<'a, K> UniversalFunctorTyCon<'a> for HashMap_<K>
     where
         K: 'a + Eq + Hash,
     {
-        type Functor<A, B> = HashMap<K, A>
+        type Functor<A, B>
+            = HashMap<K, A>
         where
             A: 'a,
             B: 'a;
@@ -434,7 +511,8 @@ mod impls {
     where
         K: 'a + Ord,
     {
-        type Functor<A, B> = BTreeMap<K, A>
+        type Functor<A, B>
+            = BTreeMap<K, A>
         where
             A: 'a,
             B: 'a;
@@ -489,6 +567,9 @@ mod impls {
     }
 
     impl_universal_monad!(OptionM_, Option<A>);
+    impl_universal_monad!(BoxM_, Box<A>);
+    impl_universal_monad!(PollM_, Poll<A>);
+    impl_universal_monad!(PhantomDataM_, PhantomData<A>);
     impl_universal_monad!(VecM_, Vec<A>);
     impl_universal_monad!(VecDequeM_, VecDeque<A>);
     impl_universal_monad!(LinkedListM_, LinkedList<A>);
@@ -497,6 +578,21 @@ mod impls {
         IteratorSendM_,
         Box<dyn 'a + Send + Iterator<Item = A>>
     );
+    #[cfg(feature = "futures")]
+    impl_universal_monad!(
+        StreamM_,
+        Pin<Box<dyn 'a + futures::Stream<Item = A>>>
+    );
+    #[cfg(feature = "futures")]
+    impl_universal_monad!(
+        StreamSendM_,
+        Pin<Box<dyn 'a + Send + futures::Stream<Item = A>>>
+    );
+    impl_universal_monad!(FnOnceM_, Box<dyn 'a + FnOnce() -> A>);
+    impl_universal_monad!(
+        FnOnceSendM_,
+        Box<dyn 'a + Send + FnOnce() -> A>
+    );
     impl_universal_monad!(
         FutureM_,
         Pin<Box<dyn 'a + Future<Output = A>>>
@@ -505,13 +601,18 @@ mod impls {
         FutureSendM_,
         Pin<Box<dyn 'a + Send + Future<Output = A>>>
     );
+    impl_universal_monad!(
+        FutureSendSyncM_,
+        Pin<Box<dyn 'a + Send + Sync + Future<Output = A>>>
+    );
 
     pub struct ResultM_<E>(PhantomData<E>);
     impl<'a, E> UniversalMonadTyCon<'a> for ResultM_<E>
     where
         E: 'a + Send,
     {
-        type Monad<A, B> = Result<A, E>
+        type Monad<A, B>
+            = Result<A, E>
         where
             A: 'a + Send,
             B: 'a + Send;
@@ -533,4 +634,145 @@ mod impls {
             this
         }
     }
+
+    pub struct ControlFlowM_<E>(PhantomData<E>);
+    impl<'a, E> UniversalMonadTyCon<'a> for ControlFlowM_<E>
+    where
+        E: 'a + Send,
+    {
+        type Monad<A, B>
+            = ControlFlow<E, A>
+        where
+            A: 'a + Send,
+            B: 'a + Send;
+    }
+    impl<'a, A, B, E> UniversalMonad<'a, B> for ControlFlow<E, A>
+    where
+        A: 'a + Send,
+        B: 'a + Send,
+        E: 'a + Send,
+    {
+        type MonadTyCon = ControlFlowM_<E>;
+        fn change_monad_target<T>(self) -> Self
+        where
+            T: 'a + Send,
+        {
+            self
+        }
+        fn from_mapped_monad(this: Self) -> Self {
+            this
+        }
+    }
+
+    #[cfg(feature = "smallvec")]
+    pub struct SmallVec_<const N: usize>;
+    #[cfg(feature = "smallvec")]
+    impl<'a, const N: usize> UniversalFunctorTyCon<'a> for SmallVec_<N> {
+        type Functor<A, B>
+            = ::smallvec::SmallVec<[A; N]>
+        where
+            A: 'a,
+            B: 'a;
+    }
+    #[cfg(feature = "smallvec")]
+    impl<'a, A, B, const N: usize> UniversalFunctor<'a, B>
+        for ::smallvec::SmallVec<[A; N]>
+    where
+        A: 'a,
+        B: 'a,
+    {
+        type FunctorTyCon = SmallVec_<N>;
+        fn change_functor_target<T>(self) -> Self {
+            self
+        }
+        fn from_mapped_functor(this: Self) -> Self {
+            this
+        }
+    }
+
+    #[cfg(feature = "smallvec")]
+    pub struct SmallVecM_<const N: usize>;
+    #[cfg(feature = "smallvec")]
+    impl<'a, const N: usize> UniversalMonadTyCon<'a> for SmallVecM_<N> {
+        type Monad<A, B>
+            = ::smallvec::SmallVec<[A; N]>
+        where
+            A: 'a + Send,
+            B: 'a + Send;
+    }
+    #[cfg(feature = "smallvec")]
+    impl<'a, A, B, const N: usize> UniversalMonad<'a, B>
+        for ::smallvec::SmallVec<[A; N]>
+    where
+        A: 'a + Send,
+        B: 'a + Send,
+    {
+        type MonadTyCon = SmallVecM_<N>;
+        fn change_monad_target<T>(self) -> Self
+        where
+            T: 'a + Send,
+        {
+            self
+        }
+        fn from_mapped_monad(this: Self) -> Self {
+            this
+        }
+    }
+
+    #[cfg(feature = "arrayvec")]
+    pub struct ArrayVec_<const CAP: usize>;
+    #[cfg(feature = "arrayvec")]
+    impl<'a, const CAP: usize> UniversalFunctorTyCon<'a>
+        for ArrayVec_<CAP>
+    {
+        type Functor<A, B>
+            = ::arrayvec::ArrayVec<A, CAP>
+        where
+            A: 'a,
+            B: 'a;
+    }
+    #[cfg(feature = "arrayvec")]
+    impl<'a, A, B, const CAP: usize> UniversalFunctor<'a, B>
+        for ::arrayvec::ArrayVec<A, CAP>
+    where
+        A: 'a,
+        B: 'a,
+    {
+        type FunctorTyCon = ArrayVec_<CAP>;
+        fn change_functor_target<T>(self) -> Self {
+            self
+        }
+        fn from_mapped_functor(this: Self) -> Self {
+            this
+        }
+    }
+
+    #[cfg(feature = "arrayvec")]
+    pub struct ArrayVecM_<const CAP: usize>;
+    #[cfg(feature = "arrayvec")]
+    impl<'a, const CAP: usize> UniversalMonadTyCon<'a> for ArrayVecM_<CAP> {
+        type Monad<A, B>
+            = ::arrayvec::ArrayVec<A, CAP>
+        where
+            A: 'a + Send,
+            B: 'a + Send;
+    }
+    #[cfg(feature = "arrayvec")]
+    impl<'a, A, B, const CAP: usize> UniversalMonad<'a, B>
+        for ::arrayvec::ArrayVec<A, CAP>
+    where
+        A: 'a + Send,
+        B: 'a + Send,
+    {
+        type MonadTyCon = ArrayVecM_<CAP>;
+        fn change_monad_target<T>(self) -> Self
+        where
+            T: 'a + Send,
+        {
+            self
+        }
+        fn from_mapped_monad(this: Self) -> Self {
+            this
+        }
+    }
 }
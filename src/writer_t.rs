@@ -0,0 +1,266 @@
+//! Writer monad transformer
+//!
+//! See [`WriterT`].
+
+use super::*;
+
+use monoid::Monoid;
+
+use std::marker::PhantomData;
+
+/// Monad transformer adding an accumulated log to a base monad
+///
+/// A `WriterT<W, M, A>` wraps a base monad `M` whose [inner type] is
+/// `(A, W)`, where the log `W` is a [`Monoid`]. [Binding] [combines] the
+/// logs of successive computations.
+///
+/// [inner type]: Functor::Inner
+/// [Binding]: Monad::bind
+/// [combines]: monoid::Semigroup::combine
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Functor, Monad, MonadTrans};
+/// use fmap::writer_t::WriterT;
+///
+/// type Logged<A> = WriterT<Vec<String>, Option<(A, Vec<String>)>, A>;
+///
+/// fn checked(x: i32) -> Logged<i32> {
+///     Logged::<()>::tell(vec![format!("checking {x}")])
+///         .bind(move |()| WriterT::lift((x >= 0).then_some(x)))
+/// }
+///
+/// let sum = checked(1).bind(|a| checked(2).fmap(move |b| a + b));
+/// assert_eq!(
+///     sum.run_writer_t(),
+///     Some((3, vec!["checking 1".to_string(), "checking 2".to_string()])),
+/// );
+///
+/// let listened = checked(5).censor(|log| vec![log.join(", ")]).listen();
+/// assert_eq!(
+///     listened.run_writer_t(),
+///     Some((
+///         (5, vec!["checking 5".to_string()]),
+///         vec!["checking 5".to_string()],
+///     )),
+/// );
+/// ```
+pub struct WriterT<W, M, A> {
+    inner: M,
+    phantom: PhantomData<fn() -> (A, W)>,
+}
+
+impl<W, M, A> WriterT<W, M, A> {
+    /// Wrap base monad with [inner type] `(A, W)`
+    ///
+    /// [inner type]: Functor::Inner
+    pub fn new(inner: M) -> Self {
+        WriterT {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unwrap base monad, which contains the result and the log
+    pub fn run_writer_t(self) -> M {
+        self.inner
+    }
+
+    /// Apply a natural transformation to the base monad
+    pub fn hoist<N, F>(self, nt: F) -> WriterT<W, N, A>
+    where
+        F: FnOnce(M) -> N,
+    {
+        WriterT::new(nt(self.inner))
+    }
+
+    /// Add the log to the result
+    #[allow(clippy::type_complexity)]
+    pub fn listen<'a>(
+        self,
+    ) -> WriterT<W, <M as Functor<'a, ((A, W), W)>>::Mapped, (A, W)>
+    where
+        M: Functor<'a, ((A, W), W), Inner = (A, W)>,
+        W: 'a + Clone,
+        A: 'a,
+    {
+        WriterT::new(self.inner.fmap(|(a, w)| ((a, w.clone()), w)))
+    }
+
+    /// Modify the log with a closure
+    pub fn censor<'a, F>(self, mut f: F) -> Self
+    where
+        M: Functor<'a, (A, W), Inner = (A, W), Mapped = M>,
+        W: 'a,
+        A: 'a,
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        WriterT::new(self.inner.fmap(move |(a, w)| (a, f(w))))
+    }
+}
+
+impl<'a, W, M> WriterT<W, M, ()>
+where
+    W: 'a,
+    M: Pure<'a, ((), W), Mapped = M>,
+{
+    /// Append to the log
+    pub fn tell(log: W) -> Self {
+        WriterT::new(M::pure(((), log)))
+    }
+}
+
+impl<'a, W, M, A, B> Functor<'a, B> for WriterT<W, M, A>
+where
+    W: 'a,
+    M: Functor<'a, (B, W), Inner = (A, W)>,
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = WriterT<W, M::Mapped, B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        WriterT::new(self.inner.fmap(move |(a, w)| (f(a), w)))
+    }
+}
+
+impl<'a, W, M, A, B> Pure<'a, B> for WriterT<W, M, A>
+where
+    W: 'a + Monoid,
+    M: Pure<'a, (B, W), Inner = (A, W)>,
+    A: 'a,
+    B: 'a,
+{
+    fn pure(b: B) -> Self::Mapped {
+        WriterT::new(M::pure((b, W::empty())))
+    }
+}
+
+impl<'a, W, M, A, B> Monad<'a, B> for WriterT<W, M, A>
+where
+    W: 'a + Monoid + Clone + Send,
+    M: Monad<'a, (B, W), Inner = (A, W)>,
+    M::Mapped: FunctorSelf<'a, (B, W)>,
+    A: 'a,
+    B: 'a,
+{
+    fn bind<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> Self::Mapped,
+    {
+        WriterT::new(self.inner.bind(move |(a, w1)| {
+            f(a).inner.fmap(move |(b, w2)| (b, w1.clone().combine(w2)))
+        }))
+    }
+}
+
+impl<'a, W, M, N, A> MonadTrans<'a, N> for WriterT<W, M, A>
+where
+    W: 'a + Monoid,
+    N: Functor<'a, (A, W), Inner = A, Mapped = M>,
+    A: 'a,
+{
+    fn lift(base: N) -> Self {
+        WriterT::new(base.fmap(|a| (a, W::empty())))
+    }
+}
+
+impl<'a, S, W, M, A> MonadState<'a, S> for WriterT<W, M, A>
+where
+    W: 'a + Monoid,
+    M: MonadState<'a, S, Value = (A, W)>,
+    A: 'a,
+{
+    type Value = A;
+    fn state<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(S) -> (A, S),
+    {
+        WriterT::new(M::state(move |s| {
+            let (a, s) = f(s);
+            ((a, W::empty()), s)
+        }))
+    }
+}
+
+impl<'a, R, W, M, A> MonadReader<'a, R> for WriterT<W, M, A>
+where
+    W: 'a + Monoid,
+    M: MonadReader<'a, R, Value = (A, W)>,
+    A: 'a,
+{
+    type Value = A;
+    fn reader<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> A,
+    {
+        WriterT::new(M::reader(move |r| (f(r), W::empty())))
+    }
+    fn local<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(R) -> R,
+    {
+        WriterT::new(self.inner.local(f))
+    }
+}
+
+impl<'a, W, M, A> MonadWriter<'a, W> for WriterT<W, M, A>
+where
+    W: 'a + Clone,
+    M: Pure<'a, (A, W), Inner = (A, W), Mapped = M>,
+    M: Functor<'a, ((A, W), W), Inner = (A, W)>,
+    A: 'a,
+{
+    type Value = A;
+    type Listened =
+        WriterT<W, <M as Functor<'a, ((A, W), W)>>::Mapped, (A, W)>;
+    fn writer(value: A, log: W) -> Self {
+        WriterT::new(M::pure((value, log)))
+    }
+    fn listen(self) -> Self::Listened {
+        WriterT::listen(self)
+    }
+    fn censor<F>(self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(W) -> W,
+    {
+        WriterT::censor(self, f)
+    }
+}
+
+impl<'a, W, M, A> MonadCont<'a> for WriterT<W, M, A>
+where
+    W: 'a + Monoid,
+    M: MonadCont<'a, Value = (A, W)>,
+    A: 'a,
+{
+    type Value = A;
+    type Escape = M::Escape;
+    fn call_cc<F>(f: F) -> Self
+    where
+        F: 'a + Send + FnOnce(Self::Escape) -> Self,
+    {
+        WriterT::new(M::call_cc(move |escape| f(escape).inner))
+    }
+    fn escape(escape: &Self::Escape, value: A) -> Self {
+        WriterT::new(M::escape(escape, (value, W::empty())))
+    }
+}
+
+impl<'a, W, M, A> MonadIo<'a> for WriterT<W, M, A>
+where
+    W: Monoid,
+    M: MonadIo<'a, Value = (A, W)>,
+{
+    type Value = A;
+    fn lift_io<F>(thunk: F) -> Self
+    where
+        F: 'a + Send + FnOnce() -> A,
+    {
+        WriterT::new(M::lift_io(move || (thunk(), W::empty())))
+    }
+}
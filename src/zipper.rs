@@ -0,0 +1,202 @@
+//! Zipper over a vector
+//!
+//! See [`VecZipper`].
+
+use super::*;
+
+use std::mem::replace;
+
+/// Non-empty vector with a focused element
+///
+/// A `VecZipper<A>` consists of a left part, a focused element, and a right
+/// part. The focused element can be [extracted], and [extending] a function
+/// applies it at every position (with the respective element in focus),
+/// which allows expressing sliding-window or stencil computations.
+///
+/// [extracted]: Extract::extract
+/// [extending]: Comonad::extend
+///
+/// # Examples
+///
+/// ```
+/// use fmap::{Comonad, Extract};
+/// use fmap::zipper::VecZipper;
+///
+/// let zipper = VecZipper::from_vec(vec![1, 5, 2, 8, 3], 0).unwrap();
+/// let smoothed = zipper.extend(|z| {
+///     let left = z.get(-1).copied().unwrap_or(0);
+///     let right = z.get(1).copied().unwrap_or(0);
+///     left + z.focus() + right
+/// });
+/// assert_eq!(smoothed.clone().extract(), 6);
+/// assert_eq!(smoothed.into_vec(), vec![6, 8, 15, 13, 11]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VecZipper<A> {
+    left: Vec<A>,
+    focus: A,
+    // stored in reverse order, such that the nearest element is last
+    right: Vec<A>,
+}
+
+impl<A> VecZipper<A> {
+    /// Create zipper with a single element
+    pub fn new(focus: A) -> Self {
+        VecZipper {
+            left: Vec::new(),
+            focus,
+            right: Vec::new(),
+        }
+    }
+
+    /// Create zipper from [`Vec`] with focus at index `pos`
+    ///
+    /// Returns [`None`] if `pos` is out of bounds.
+    pub fn from_vec(mut vec: Vec<A>, pos: usize) -> Option<Self> {
+        if pos >= vec.len() {
+            return None;
+        }
+        let mut right = vec.split_off(pos);
+        right.reverse();
+        let focus = right.pop().unwrap();
+        Some(VecZipper {
+            left: vec,
+            focus,
+            right,
+        })
+    }
+
+    /// Convert into [`Vec`] (discarding the focus position)
+    pub fn into_vec(self) -> Vec<A> {
+        let mut vec = self.left;
+        vec.push(self.focus);
+        vec.extend(self.right.into_iter().rev());
+        vec
+    }
+
+    /// Index of the focused element
+    pub fn pos(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Focused element
+    pub fn focus(&self) -> &A {
+        &self.focus
+    }
+
+    /// Mutable reference to focused element
+    pub fn focus_mut(&mut self) -> &mut A {
+        &mut self.focus
+    }
+
+    /// Element at the given offset relative to the focus
+    ///
+    /// Returns [`None`] if the offset is out of bounds.
+    pub fn get(&self, offset: isize) -> Option<&A> {
+        let distance = offset.unsigned_abs();
+        if offset < 0 {
+            self.left.len().checked_sub(distance).map(|i| &self.left[i])
+        } else if offset > 0 {
+            self.right
+                .len()
+                .checked_sub(distance)
+                .map(|i| &self.right[i])
+        } else {
+            Some(&self.focus)
+        }
+    }
+
+    /// Move focus one element to the left
+    ///
+    /// Returns `false` (and leaves the zipper unchanged) if the focus is
+    /// already at the leftmost element.
+    pub fn move_left(&mut self) -> bool {
+        match self.left.pop() {
+            Some(x) => {
+                self.right.push(replace(&mut self.focus, x));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move focus one element to the right
+    ///
+    /// Returns `false` (and leaves the zipper unchanged) if the focus is
+    /// already at the rightmost element.
+    pub fn move_right(&mut self) -> bool {
+        match self.right.pop() {
+            Some(x) => {
+                self.left.push(replace(&mut self.focus, x));
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a, A, B> Functor<'a, B> for VecZipper<A>
+where
+    A: 'a,
+    B: 'a,
+{
+    type Inner = A;
+    type Mapped = VecZipper<B>;
+    fn fmap<F>(self, mut f: F) -> Self::Mapped
+    where
+        F: 'a + Send + FnMut(Self::Inner) -> B,
+    {
+        let pos = self.pos();
+        let vec = self.into_vec().into_iter().map(&mut f).collect();
+        VecZipper::from_vec(vec, pos).unwrap()
+    }
+    fn fmap_fn_mutref<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.fmap_mut(f);
+        self
+    }
+}
+
+impl<'a, A> FunctorMut<'a, A> for VecZipper<A>
+where
+    A: 'a,
+{
+    fn fmap_mut<F>(&mut self, mut f: F)
+    where
+        F: 'a + Send + FnMut(&mut Self::Inner),
+    {
+        self.left.iter_mut().for_each(&mut f);
+        f(&mut self.focus);
+        self.right.iter_mut().rev().for_each(f);
+    }
+}
+
+impl<'a, A> Extract<'a, A> for VecZipper<A>
+where
+    A: 'a,
+{
+    fn extract(self) -> A {
+        self.focus
+    }
+}
+
+/// Extending clones the elements once for each position
+impl<'a, A, B> Comonad<'a, B> for VecZipper<A>
+where
+    A: 'a + Clone,
+    B: 'a,
+{
+    fn extend<F>(self, mut f: F) -> VecZipper<B>
+    where
+        F: 'a + Send + FnMut(Self) -> B,
+    {
+        let pos = self.pos();
+        let vec = self.into_vec();
+        let mapped = (0..vec.len())
+            .map(|i| f(VecZipper::from_vec(vec.clone(), i).unwrap()))
+            .collect();
+        VecZipper::from_vec(mapped, pos).unwrap()
+    }
+}